@@ -0,0 +1,66 @@
+#[cfg(feature = "alloc")]
+mod ability_score_improvement;
+#[cfg(feature = "alloc")]
+pub use ability_score_improvement::AbilityScoreImprovement;
+
+#[cfg(feature = "alloc")]
+mod background;
+#[cfg(feature = "alloc")]
+pub use background::Background;
+
+#[cfg(feature = "alloc")]
+mod build;
+#[cfg(feature = "alloc")]
+pub use build::{BuildLevel, CharacterBuild, Choice, Decision};
+
+mod class;
+pub use class::{Class, ClassLevel, FeatureOptionKind};
+
+#[cfg(feature = "alloc")]
+mod feat;
+#[cfg(feature = "alloc")]
+pub use feat::{Feat, Prerequisite};
+
+#[cfg(feature = "alloc")]
+mod feature_option;
+#[cfg(feature = "alloc")]
+pub use feature_option::FeatureOption;
+
+#[cfg(feature = "alloc")]
+mod grapple;
+#[cfg(feature = "alloc")]
+pub use grapple::Grapple;
+
+mod health;
+pub use health::Health;
+
+#[cfg(feature = "alloc")]
+mod hit_dice;
+#[cfg(feature = "alloc")]
+pub use hit_dice::{HitDice, HitDicePool};
+
+#[cfg(feature = "alloc")]
+mod resource;
+#[cfg(feature = "alloc")]
+pub use resource::{Resource, ResourceMaximum};
+
+#[cfg(feature = "alloc")]
+mod sheet;
+#[cfg(feature = "alloc")]
+pub use sheet::Character;
+
+#[cfg(feature = "alloc")]
+mod species;
+#[cfg(feature = "alloc")]
+pub use species::{AbilityIncreases, InnateSpell, Species};
+
+mod spell_slots;
+pub use spell_slots::SpellSlots;
+
+#[cfg(feature = "alloc")]
+mod subclass;
+#[cfg(feature = "alloc")]
+pub use subclass::Subclass;
+
+mod variant_rules;
+pub use variant_rules::VariantRules;
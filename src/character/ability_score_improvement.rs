@@ -0,0 +1,216 @@
+use crate::{
+    character::{Character, Feat},
+    core::{Ability, AbilityScore},
+};
+
+/// The choice made for an Ability Score Improvement: increase one ability score by 2, two
+/// ability scores by 1 each, or take a feat instead.
+///
+/// An Ability Score Improvement can't increase a score above 20.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::{AbilityScoreImprovement, Character, Class, ClassLevel, Feat};
+/// use dnd::core::{Ability, AbilityScore, Level};
+///
+/// let mut fighter = Character::new("Brakka");
+/// fighter.classes.push(ClassLevel::new(Class::Fighter, Level::new(4)));
+/// fighter.abilities.strength = AbilityScore::new(19);
+///
+/// let too_high = AbilityScoreImprovement::Increase(Ability::Strength);
+/// assert!(too_high.apply(&mut fighter).is_err());
+///
+/// AbilityScoreImprovement::IncreaseTwo(Ability::Strength, Ability::Constitution)
+///     .apply(&mut fighter)
+///     .unwrap();
+/// assert_eq!(fighter.abilities.strength, AbilityScore::new(20));
+/// assert_eq!(fighter.abilities.constitution, AbilityScore::new(11));
+///
+/// AbilityScoreImprovement::Feat(Feat::GRAPPLER, Some(Ability::Dexterity))
+///     .apply(&mut fighter)
+///     .unwrap();
+/// assert_eq!(fighter.abilities.dexterity, AbilityScore::new(11));
+/// assert!(fighter.has_feat("Grappler"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AbilityScoreImprovement {
+    /// Increase one ability score by 2.
+    Increase(Ability),
+
+    /// Increase two different ability scores by 1 each.
+    IncreaseTwo(Ability, Ability),
+
+    /// Take a feat, with the ability it increases if it is a half-feat.
+    Feat(Feat, Option<Ability>),
+}
+
+impl AbilityScoreImprovement {
+    /// The highest score an Ability Score Improvement can increase an ability score to.
+    pub const MAXIMUM: AbilityScore = AbilityScore::new(20);
+
+    /// Returns the increase to each ability score this choice makes.
+    fn increases(&self) -> impl Iterator<Item = (Ability, u8)> {
+        let (first, second) = match self {
+            AbilityScoreImprovement::Increase(ability) => (Some((*ability, 2)), None),
+            AbilityScoreImprovement::IncreaseTwo(first, second) => {
+                (Some((*first, 1)), Some((*second, 1)))
+            }
+            AbilityScoreImprovement::Feat(_, ability) => {
+                (ability.map(|ability| (ability, 1)), None)
+            }
+        };
+        first.into_iter().chain(second)
+    }
+
+    /// Checks that `character` can make this choice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the choice would increase an ability score above 20, names the same
+    /// ability twice, or takes a feat when feats are turned off by the character's
+    /// [`VariantRules`][crate::character::VariantRules], or a feat the character doesn't meet the
+    /// prerequisites for, has already taken, or whose ability increase is missing or not one the
+    /// feat allows.
+    pub fn validate(&self, character: &Character) -> Result<(), &'static str> {
+        match self {
+            AbilityScoreImprovement::Increase(_) => {}
+            AbilityScoreImprovement::IncreaseTwo(first, second) => {
+                if first == second {
+                    return Err("Increase two different abilities");
+                }
+            }
+            AbilityScoreImprovement::Feat(feat, ability) => {
+                if !character.variant_rules.feats {
+                    return Err("Feats are not used");
+                }
+                if !feat.is_available_to(character) {
+                    return Err("Feat prerequisites not met");
+                }
+                if !feat.repeatable && character.has_feat(&feat.name) {
+                    return Err("Feat already taken");
+                }
+                match ability {
+                    Some(ability) if !feat.can_increase(*ability) => {
+                        return Err("Feat cannot increase this ability");
+                    }
+                    None if feat.is_half_feat() => {
+                        return Err("Choose an ability for the feat to increase");
+                    }
+                    _ => {}
+                }
+            }
+        }
+        for (ability, increase) in self.increases() {
+            if character.abilities[ability].value() + increase > Self::MAXIMUM.value() {
+                return Err("Ability score cannot be increased above 20");
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies this choice to `character`, increasing its ability scores or adding the feat,
+    /// and counts it in [`Character::ability_score_improvements`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving `character` unchanged, if the choice is not valid for it. See
+    /// [`Self::validate`].
+    pub fn apply(self, character: &mut Character) -> Result<(), &'static str> {
+        self.validate(character)?;
+        for (ability, increase) in self.increases() {
+            let score = character.abilities[ability].value() + increase;
+            character.abilities[ability] = AbilityScore::new(score);
+        }
+        if let AbilityScoreImprovement::Feat(feat, _) = self {
+            character.feats.push(feat);
+        }
+        character.ability_score_improvements =
+            character.ability_score_improvements.saturating_add(1);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        character::{Class, ClassLevel},
+        core::Level,
+    };
+
+    fn fighter() -> Character {
+        let mut fighter = Character::new("Brakka");
+        fighter
+            .classes
+            .push(ClassLevel::new(Class::Fighter, Level::new(4)));
+        fighter.abilities.strength = AbilityScore::new(16);
+        fighter
+    }
+
+    #[test]
+    fn increases() {
+        let mut fighter = fighter();
+        AbilityScoreImprovement::Increase(Ability::Strength)
+            .apply(&mut fighter)
+            .unwrap();
+        assert_eq!(fighter.abilities.strength, AbilityScore::new(18));
+        assert_eq!(
+            AbilityScoreImprovement::IncreaseTwo(Ability::Wisdom, Ability::Wisdom)
+                .validate(&fighter),
+            Err("Increase two different abilities")
+        );
+
+        fighter.abilities.constitution = AbilityScore::new(20);
+        let choice = AbilityScoreImprovement::IncreaseTwo(Ability::Strength, Ability::Constitution);
+        assert_eq!(
+            choice.clone().apply(&mut fighter),
+            Err("Ability score cannot be increased above 20")
+        );
+        assert_eq!(fighter.abilities.strength, AbilityScore::new(18));
+    }
+
+    #[test]
+    fn feats() {
+        let mut fighter = fighter();
+        assert_eq!(
+            AbilityScoreImprovement::Feat(Feat::GRAPPLER, None).validate(&fighter),
+            Err("Choose an ability for the feat to increase")
+        );
+        assert_eq!(
+            AbilityScoreImprovement::Feat(Feat::GRAPPLER, Some(Ability::Wisdom)).validate(&fighter),
+            Err("Feat cannot increase this ability")
+        );
+        assert_eq!(
+            AbilityScoreImprovement::Feat(Feat::ALERT, Some(Ability::Wisdom)).validate(&fighter),
+            Err("Feat cannot increase this ability")
+        );
+
+        AbilityScoreImprovement::Feat(Feat::GRAPPLER, Some(Ability::Strength))
+            .apply(&mut fighter)
+            .unwrap();
+        assert_eq!(fighter.abilities.strength, AbilityScore::new(17));
+        assert_eq!(
+            AbilityScoreImprovement::Feat(Feat::GRAPPLER, Some(Ability::Strength))
+                .validate(&fighter),
+            Err("Feat already taken")
+        );
+
+        AbilityScoreImprovement::Feat(Feat::SKILLED, None)
+            .apply(&mut fighter)
+            .unwrap();
+        AbilityScoreImprovement::Feat(Feat::SKILLED, None)
+            .apply(&mut fighter)
+            .unwrap();
+        assert_eq!(fighter.feats.len(), 3);
+
+        fighter.classes[0].level = Level::new(3);
+        fighter.feats.clear();
+        assert_eq!(
+            AbilityScoreImprovement::Feat(Feat::GRAPPLER, Some(Ability::Strength))
+                .validate(&fighter),
+            Err("Feat prerequisites not met")
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{
+    character::Feat,
+    core::{Abilities, Ability, AbilityScore, Skill, SkillProficiencies, SkillReplacements, Tool},
+    equipment::{Coin, Currency, Gear, Item, ItemStack, Weapon},
+};
+use alloc::borrow::Cow;
+use core::fmt::Display;
+use enumflags2::{BitFlags, make_bitflags};
+
+/// A character's background: the place and occupation that shaped them before adventuring.
+///
+/// A background grants two skill proficiencies, tool proficiencies, languages, and starting
+/// equipment. Under the 2024 rules, it also grants an origin feat and lets the player increase
+/// three of its abilities, in place of the ability score increases of the character's species.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::Background;
+/// use dnd::core::{Abilities, Ability, AbilityScore, Skill, SkillProficiencies};
+///
+/// let criminal = Background::CRIMINAL;
+/// assert_eq!(criminal.feat.as_ref().unwrap().name, "Alert");
+///
+/// let mut abilities = Abilities::new();
+/// criminal
+///     .increase_abilities(&mut abilities, &[(Ability::Dexterity, 2), (Ability::Intelligence, 1)])
+///     .unwrap();
+/// assert_eq!(abilities.dexterity, AbilityScore::new(12));
+/// assert!(criminal.increase_abilities(&mut abilities, &[(Ability::Strength, 2)]).is_err());
+///
+/// // A rogue already proficient in Stealth from their class chooses another skill instead.
+/// let mut skills = SkillProficiencies::new();
+/// skills.set_proficient(Skill::Stealth);
+/// let mut replacements = criminal.grant_skills(&mut skills);
+/// assert_eq!(replacements.duplicates(), Skill::Stealth);
+/// replacements.replace(Skill::Stealth, Skill::Deception, &mut skills).unwrap();
+/// assert!(skills.is_proficient(Skill::Deception));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::unsafe_derive_deserialize)] // `make_bitflags!` expands to an `unsafe` block.
+pub struct Background {
+    /// The name of the background, such as "Soldier".
+    pub name: Cow<'static, str>,
+
+    /// The abilities the background can increase, or none under the 2014 rules.
+    pub abilities: BitFlags<Ability>,
+
+    /// The skills the background grants proficiency in.
+    pub skills: BitFlags<Skill>,
+
+    /// The tools the background grants proficiency with.
+    pub tools: BitFlags<Tool>,
+
+    /// The languages the background grants.
+    pub languages: Cow<'static, [Cow<'static, str>]>,
+
+    /// The equipment a character with the background starts with.
+    pub equipment: Cow<'static, [ItemStack]>,
+
+    /// The money a character with the background starts with.
+    pub gold: Currency,
+
+    /// The origin feat the background grants, if any.
+    pub feat: Option<Feat>,
+}
+
+impl Background {
+    /// A life of crime, with a knack for sleight of hand and staying hidden.
+    pub const CRIMINAL: Self = Self {
+        name: Cow::Borrowed("Criminal"),
+        abilities: make_bitflags!(Ability::{Dexterity | Constitution | Intelligence}),
+        skills: make_bitflags!(Skill::{SleightOfHand | Stealth}),
+        tools: make_bitflags!(Tool::{ThievesTools}),
+        languages: Cow::Borrowed(&[]),
+        equipment: Cow::Borrowed(&[
+            ItemStack {
+                item: Item::Weapon(Weapon::Dagger),
+                quantity: 2,
+            },
+            ItemStack {
+                item: Item::Gear(Gear::new(
+                    "Thieves' Tools",
+                    Currency::new(25, Coin::Gold),
+                    1,
+                )),
+                quantity: 1,
+            },
+            ItemStack {
+                item: Item::Gear(Gear::new("Crowbar", Currency::new(2, Coin::Gold), 5)),
+                quantity: 1,
+            },
+            ItemStack {
+                item: Item::Gear(Gear::new("Pouch", Currency::new(5, Coin::Silver), 1)),
+                quantity: 2,
+            },
+            ItemStack {
+                item: Item::Gear(Gear::new(
+                    "Traveler's Clothes",
+                    Currency::new(2, Coin::Gold),
+                    4,
+                )),
+                quantity: 1,
+            },
+        ]),
+        gold: Currency::new(16, Coin::Gold),
+        feat: Some(Feat::ALERT),
+    };
+
+    /// A life under arms, trained in athletics and intimidation, with dice as its gaming set.
+    pub const SOLDIER: Self = Self {
+        name: Cow::Borrowed("Soldier"),
+        abilities: make_bitflags!(Ability::{Strength | Dexterity | Constitution}),
+        skills: make_bitflags!(Skill::{Athletics | Intimidation}),
+        tools: make_bitflags!(Tool::{DiceSet}),
+        languages: Cow::Borrowed(&[]),
+        equipment: Cow::Borrowed(&[
+            ItemStack {
+                item: Item::Weapon(Weapon::Spear),
+                quantity: 1,
+            },
+            ItemStack {
+                item: Item::Weapon(Weapon::Shortbow),
+                quantity: 1,
+            },
+            ItemStack {
+                item: Item::Gear(Gear::new("Arrows (20)", Currency::new(1, Coin::Gold), 1)),
+                quantity: 1,
+            },
+            ItemStack {
+                item: Item::Gear(Gear::new("Dice Set", Currency::new(1, Coin::Silver), 0)),
+                quantity: 1,
+            },
+            ItemStack {
+                item: Item::Gear(Gear::new("Healer's Kit", Currency::new(5, Coin::Gold), 3)),
+                quantity: 1,
+            },
+            ItemStack {
+                item: Item::Gear(Gear::new("Quiver", Currency::new(1, Coin::Gold), 1)),
+                quantity: 1,
+            },
+            ItemStack {
+                item: Item::Gear(Gear::new(
+                    "Traveler's Clothes",
+                    Currency::new(2, Coin::Gold),
+                    4,
+                )),
+                quantity: 1,
+            },
+        ]),
+        gold: Currency::new(14, Coin::Gold),
+        feat: Some(Feat::SAVAGE_ATTACKER),
+    };
+
+    /// Creates a new `Background` with the given name and skills, and nothing else.
+    #[must_use]
+    pub const fn new(name: &'static str, skills: BitFlags<Skill>) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            abilities: BitFlags::EMPTY,
+            skills,
+            tools: BitFlags::EMPTY,
+            languages: Cow::Borrowed(&[]),
+            equipment: Cow::Borrowed(&[]),
+            gold: Currency::from_copper(0),
+            feat: None,
+        }
+    }
+
+    /// Adds proficiency in the background's skills to `skills`.
+    ///
+    /// Returns the skills `skills` was already proficient in, which the player can replace with
+    /// skills of their choice.
+    pub fn grant_skills(&self, skills: &mut SkillProficiencies) -> SkillReplacements {
+        skills.grant(self.skills)
+    }
+
+    /// Increases ability scores by the background's ability score increases, as in the 2024
+    /// rules: one of its abilities by 2 and another by 1, or all three by 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving `abilities` unchanged, if an ability is not one the background
+    /// can increase or is increased twice, the increases are not +2/+1 or +1/+1/+1, or a score
+    /// would increase above 20.
+    pub fn increase_abilities(
+        &self,
+        abilities: &mut Abilities,
+        increases: &[(Ability, u8)],
+    ) -> Result<(), &'static str> {
+        if increases
+            .iter()
+            .any(|(_, increase)| !(1..=2).contains(increase))
+            || increases
+                .iter()
+                .map(|&(_, increase)| usize::from(increase))
+                .sum::<usize>()
+                != 3
+        {
+            return Err("Increase one ability by 2 and another by 1, or three by 1");
+        }
+        let mut seen = BitFlags::<Ability>::EMPTY;
+        for &(ability, increase) in increases {
+            if !self.abilities.contains(ability) {
+                return Err("Background cannot increase this ability");
+            }
+            if seen.contains(ability) {
+                return Err("Increase each ability at most once");
+            }
+            if abilities[ability].value() + increase > 20 {
+                return Err("Ability score cannot be increased above 20");
+            }
+            seen |= ability;
+        }
+        for &(ability, increase) in increases {
+            abilities[ability] = AbilityScore::new(abilities[ability].value() + increase);
+        }
+        Ok(())
+    }
+}
+
+impl Display for Background {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn increase_abilities() {
+        let soldier = Background::SOLDIER;
+        let mut abilities = Abilities::new();
+        soldier
+            .increase_abilities(
+                &mut abilities,
+                &[
+                    (Ability::Strength, 1),
+                    (Ability::Dexterity, 1),
+                    (Ability::Constitution, 1),
+                ],
+            )
+            .unwrap();
+        assert_eq!(abilities.constitution, AbilityScore::new(11));
+
+        let invalid: [&[(Ability, u8)]; 5] = [
+            &[(Ability::Strength, 2), (Ability::Dexterity, 2)],
+            &[(Ability::Strength, 255), (Ability::Dexterity, 4)],
+            &[(Ability::Strength, 3)],
+            &[(Ability::Strength, 2)],
+            &[(Ability::Strength, 1), (Ability::Dexterity, 1)],
+        ];
+        for increases in invalid {
+            assert_eq!(
+                soldier.increase_abilities(&mut abilities, increases),
+                Err("Increase one ability by 2 and another by 1, or three by 1")
+            );
+        }
+        assert_eq!(
+            soldier.increase_abilities(
+                &mut abilities,
+                &[(Ability::Strength, 1), (Ability::Strength, 2)]
+            ),
+            Err("Increase each ability at most once")
+        );
+
+        abilities.strength = AbilityScore::new(19);
+        assert!(
+            soldier
+                .increase_abilities(
+                    &mut abilities,
+                    &[(Ability::Strength, 2), (Ability::Dexterity, 1)]
+                )
+                .is_err()
+        );
+        assert_eq!(abilities.dexterity, AbilityScore::new(11));
+    }
+
+    #[test]
+    fn equipment() {
+        let weight: u32 = Background::SOLDIER
+            .equipment
+            .iter()
+            .map(|stack| stack.weight().pounds())
+            .sum();
+        assert_eq!(weight, 3 + 2 + 1 + 3 + 1 + 4);
+        assert!(
+            Background::new("Hermit", Skill::Medicine | Skill::Religion)
+                .feat
+                .is_none()
+        );
+    }
+}
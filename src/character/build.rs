@@ -0,0 +1,541 @@
+use crate::{
+    character::{
+        AbilityScoreImprovement, Background, Character, Class, FeatureOption, FeatureOptionKind,
+        Species, Subclass, VariantRules,
+    },
+    core::{Abilities, Ability, RulesVersion},
+};
+use alloc::{borrow::Cow, vec::Vec};
+
+/// A choice made when gaining a level.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Choice {
+    /// A subclass of the class the level is gained in.
+    Subclass(Subclass),
+
+    /// A feature option, such as a Fighting Style or an Eldritch Invocation.
+    FeatureOption(FeatureOption),
+
+    /// An Ability Score Improvement, or a feat taken in its place.
+    AbilityScoreImprovement(AbilityScoreImprovement),
+}
+
+/// A decision still needed after gaining a level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Decision {
+    /// A subclass of this class.
+    Subclass(Class),
+
+    /// This many feature options of this kind.
+    FeatureOptions(FeatureOptionKind, u8),
+
+    /// An Ability Score Improvement, or a feat taken in its place.
+    AbilityScoreImprovement,
+}
+
+/// One level gained in a [`CharacterBuild`], with the choices made at it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BuildLevel {
+    /// The class the level is gained in.
+    pub class: Class,
+
+    /// The choices made at the level.
+    pub choices: Vec<Choice>,
+}
+
+/// The choices that make up a character, from its creation through each level it has gained.
+///
+/// Each level records the class it was gained in and the choices made at it, such as a
+/// subclass, an Eldritch Invocation, or an Ability Score Improvement. Choices are validated
+/// when a level is added, and the build can be replayed to reconstruct the [`Character`].
+///
+/// A level can also be gained with [`Self::level_up`], which returns the decisions still
+/// needed, and its choices made one at a time with [`Self::choose`]. Each level is a step that
+/// can be undone with [`Self::undo`].
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::{CharacterBuild, Choice, Class, FeatureOption, Subclass};
+/// use dnd::core::{Abilities, Level};
+///
+/// let mut build = CharacterBuild::new("Zariel's Chosen", Abilities::new());
+/// build
+///     .add_level(Class::Warlock, [Choice::Subclass(Subclass::FIEND)])
+///     .unwrap();
+/// build
+///     .add_level(
+///         Class::Warlock,
+///         [
+///             Choice::FeatureOption(FeatureOption::AGONIZING_BLAST),
+///             Choice::FeatureOption(FeatureOption::DEVILS_SIGHT),
+///         ],
+///     )
+///     .unwrap();
+///
+/// // Thirsting Blade requires a warlock level of 5 or more, and Pact of the Blade.
+/// let thirsting_blade = [Choice::FeatureOption(FeatureOption::THIRSTING_BLADE)];
+/// assert!(build.add_level(Class::Warlock, thirsting_blade.clone()).is_err());
+/// let pact_of_the_blade = [Choice::FeatureOption(FeatureOption::PACT_OF_THE_BLADE)];
+/// build.add_level(Class::Warlock, pact_of_the_blade).unwrap();
+/// build.add_level(Class::Warlock, []).unwrap();
+/// build.add_level(Class::Warlock, thirsting_blade).unwrap();
+///
+/// let warlock = build.replay().unwrap();
+/// assert_eq!(warlock.class_level(Class::Warlock), Some(Level::new(5)));
+/// assert_eq!(warlock.feature_options.len(), 4);
+/// assert_eq!(warlock.subclass(Class::Warlock), Some(&Subclass::FIEND));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CharacterBuild {
+    /// The name of the character.
+    pub name: Cow<'static, str>,
+
+    /// The edition of the rules the character is played with.
+    pub rules: RulesVersion,
+
+    /// The optional and house rules the character is played with.
+    pub variant_rules: VariantRules,
+
+    /// The ability scores the character starts with, before any increases.
+    pub abilities: Abilities,
+
+    /// The character's species, if any.
+    pub species: Option<Species>,
+
+    /// The character's background, if any.
+    pub background: Option<Background>,
+
+    /// The ability score increases chosen from the background, under the 2024 rules.
+    ///
+    /// If empty, the background doesn't increase any ability scores.
+    pub background_increases: Vec<(Ability, u8)>,
+
+    levels: Vec<BuildLevel>,
+}
+
+impl CharacterBuild {
+    /// Creates a new `CharacterBuild` with the given name and starting ability scores, and no
+    /// levels.
+    #[must_use]
+    pub fn new(name: impl Into<Cow<'static, str>>, abilities: Abilities) -> Self {
+        Self {
+            name: name.into(),
+            rules: RulesVersion::Phb2014,
+            variant_rules: VariantRules::new(),
+            abilities,
+            species: None,
+            background: None,
+            background_increases: Vec::new(),
+            levels: Vec::new(),
+        }
+    }
+
+    /// Returns the levels gained so far, starting with the first.
+    #[must_use]
+    pub fn levels(&self) -> &[BuildLevel] {
+        &self.levels
+    }
+
+    /// Adds a level in `class`, with the choices made at it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the build unchanged, if the build can't be replayed, the
+    /// character is already level 20, or any of the choices is not valid at the new level. A
+    /// subclass must be for `class`, chosen at or after [`Class::subclass_level`] under the
+    /// build's rules, and only once. A feature option must be one the character has a choice
+    /// of, meet its prerequisites, and not be taken already. An Ability Score Improvement must
+    /// be granted by the character's class levels and not taken already, even at an earlier
+    /// level, and be valid for the character.
+    pub fn add_level(
+        &mut self,
+        class: Class,
+        choices: impl IntoIterator<Item = Choice>,
+    ) -> Result<(), &'static str> {
+        let mut character = self.replay()?;
+        let level = BuildLevel {
+            class,
+            choices: choices.into_iter().collect(),
+        };
+        Self::advance(&mut character, &level)?;
+        self.levels.push(level);
+        Ok(())
+    }
+
+    /// Adds a level in `class` with no choices made yet, as a step that can be undone with
+    /// [`Self::undo`].
+    ///
+    /// Returns the decisions still needed, which are made with [`Self::choose`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the build unchanged, if the build can't be replayed or the
+    /// character is already level 20.
+    pub fn level_up(&mut self, class: Class) -> Result<Vec<Decision>, &'static str> {
+        let mut character = self.replay()?;
+        let level = BuildLevel {
+            class,
+            choices: Vec::new(),
+        };
+        let decisions = Self::advance(&mut character, &level)?;
+        self.levels.push(level);
+        Ok(decisions)
+    }
+
+    /// Makes a choice at the last level gained.
+    ///
+    /// Returns the decisions still needed at that level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the build unchanged, if no level has been gained or the choice
+    /// is not valid at the last level. See [`Self::add_level`].
+    pub fn choose(&mut self, choice: Choice) -> Result<Vec<Decision>, &'static str> {
+        let Some(mut level) = self.levels.pop() else {
+            return Err("No level to make a choice at");
+        };
+        level.choices.push(choice);
+        let result = self
+            .replay()
+            .and_then(|mut character| Self::advance(&mut character, &level));
+        if result.is_err() {
+            level.choices.pop();
+        }
+        self.levels.push(level);
+        result
+    }
+
+    /// Removes the last level gained, along with the choices made at it.
+    ///
+    /// Returns the level removed, or `None` if no level has been gained.
+    pub fn undo(&mut self) -> Option<BuildLevel> {
+        self.levels.pop()
+    }
+
+    /// Returns the decisions still needed, including those left open at earlier levels, or none
+    /// if no level has been gained.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the build can't be replayed. See [`Self::replay`].
+    pub fn pending_decisions(&self) -> Result<Vec<Decision>, &'static str> {
+        let Some((last, levels)) = self.levels.split_last() else {
+            return Ok(Vec::new());
+        };
+        let mut character = self.replay_levels(levels)?;
+        Self::advance(&mut character, last)
+    }
+
+    /// Reconstructs the character by applying its species, background, and each level in
+    /// order.
+    ///
+    /// Background skills the character is already proficient in are not replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the background ability score increases or any choice is not valid,
+    /// such as after the build's fields were changed directly.
+    pub fn replay(&self) -> Result<Character, &'static str> {
+        self.replay_levels(&self.levels)
+    }
+
+    /// Reconstructs the character with only the given levels.
+    fn replay_levels(&self, levels: &[BuildLevel]) -> Result<Character, &'static str> {
+        let mut character = Character::new(self.name.clone());
+        character.rules = self.rules;
+        character.variant_rules = self.variant_rules;
+        character.abilities = self.abilities.clone();
+        if let Some(species) = &self.species {
+            character.set_species(species.clone());
+        }
+        if let Some(background) = &self.background {
+            if !self.background_increases.is_empty() {
+                background
+                    .increase_abilities(&mut character.abilities, &self.background_increases)?;
+            }
+            let _ = character.set_background(background.clone());
+        }
+        for level in levels {
+            Self::advance(&mut character, level)?;
+        }
+        Ok(character)
+    }
+
+    /// Gains `level` in its class and makes its choices, returning the decisions still needed.
+    fn advance(
+        character: &mut Character,
+        level: &BuildLevel,
+    ) -> Result<Vec<Decision>, &'static str> {
+        let class = level.class;
+        character.level_up(class)?;
+        for choice in &level.choices {
+            match choice {
+                Choice::Subclass(subclass) => {
+                    if subclass.class != class {
+                        return Err("Subclass is for a different class");
+                    }
+                    character.choose_subclass(subclass.clone())?;
+                }
+                Choice::FeatureOption(option) => {
+                    character.choose_feature_option(option.clone())?;
+                }
+                Choice::AbilityScoreImprovement(improvement) => {
+                    if character.available_ability_score_improvements() == 0 {
+                        return Err("No Ability Score Improvement to choose");
+                    }
+                    improvement.clone().apply(character)?;
+                }
+            }
+        }
+        Ok(character.pending_decisions())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        character::Feat,
+        core::{AbilityScore, Level},
+    };
+
+    #[test]
+    fn subclass() {
+        let mut build = CharacterBuild::new("Brakka", Abilities::new());
+        let champion = [Choice::Subclass(Subclass::CHAMPION)];
+        assert_eq!(
+            build.add_level(Class::Fighter, champion.clone()),
+            Err("Too early to choose a subclass")
+        );
+        assert!(build.levels().is_empty());
+
+        build
+            .add_level(
+                Class::Fighter,
+                [Choice::FeatureOption(FeatureOption::DEFENSE)],
+            )
+            .unwrap();
+        build.add_level(Class::Fighter, []).unwrap();
+        assert_eq!(
+            build.add_level(Class::Wizard, champion.clone()),
+            Err("Subclass is for a different class")
+        );
+        build.add_level(Class::Fighter, champion).unwrap();
+        assert_eq!(
+            build.add_level(Class::Fighter, [Choice::Subclass(Subclass::BATTLE_MASTER)]),
+            Err("Class already has a subclass")
+        );
+        assert_eq!(
+            build.add_level(
+                Class::Fighter,
+                [Choice::FeatureOption(FeatureOption::ARCHERY)]
+            ),
+            Err("No choices of this kind available")
+        );
+
+        let fighter = build.replay().unwrap();
+        assert_eq!(fighter.saving_throws, Class::Fighter.saving_throws());
+        assert_eq!(fighter.hit_dice.total(), 3);
+        assert_eq!(fighter.granted_options(FeatureOptionKind::FightingStyle), 1);
+    }
+
+    #[test]
+    fn pact_boon() {
+        let mut build = CharacterBuild::new("Zariel's Chosen", Abilities::new());
+        build
+            .add_level(Class::Warlock, [Choice::Subclass(Subclass::FIEND)])
+            .unwrap();
+        build.add_level(Class::Warlock, []).unwrap();
+        assert_eq!(
+            build.level_up(Class::Warlock),
+            Ok([
+                Decision::FeatureOptions(FeatureOptionKind::EldritchInvocation, 2),
+                Decision::FeatureOptions(FeatureOptionKind::PactBoon, 1)
+            ]
+            .into())
+        );
+        build
+            .choose(Choice::FeatureOption(FeatureOption::PACT_OF_THE_CHAIN))
+            .unwrap();
+        build.add_level(Class::Warlock, []).unwrap();
+        let thirsting_blade = [Choice::FeatureOption(FeatureOption::THIRSTING_BLADE)];
+        assert_eq!(
+            build.add_level(Class::Warlock, thirsting_blade.clone()),
+            Err("Option prerequisites not met")
+        );
+
+        build.undo().unwrap();
+        build.undo().unwrap();
+        build
+            .add_level(
+                Class::Warlock,
+                [Choice::FeatureOption(FeatureOption::PACT_OF_THE_BLADE)],
+            )
+            .unwrap();
+        build.add_level(Class::Warlock, []).unwrap();
+        build.add_level(Class::Warlock, thirsting_blade).unwrap();
+    }
+
+    #[test]
+    fn subclass_2024() {
+        let mut build = CharacterBuild::new("Zariel's Chosen", Abilities::new());
+        build.rules = RulesVersion::Phb2024;
+        assert_eq!(
+            build.add_level(Class::Warlock, [Choice::Subclass(Subclass::FIEND)]),
+            Err("Too early to choose a subclass")
+        );
+        assert!(build.level_up(Class::Warlock).unwrap().is_empty());
+    }
+
+    #[test]
+    fn feature_options() {
+        let mut build = CharacterBuild::new("Brakka", Abilities::new());
+        build.add_level(Class::Fighter, []).unwrap();
+        build.add_level(Class::Fighter, []).unwrap();
+        let maneuvers = [
+            FeatureOption::PRECISION_ATTACK,
+            FeatureOption::RIPOSTE,
+            FeatureOption::TRIP_ATTACK,
+        ];
+        build
+            .add_level(
+                Class::Fighter,
+                [Choice::Subclass(Subclass::BATTLE_MASTER)]
+                    .into_iter()
+                    .chain(maneuvers.map(Choice::FeatureOption)),
+            )
+            .unwrap();
+
+        let fighter = build.replay().unwrap();
+        assert_eq!(fighter.available_options(FeatureOptionKind::Maneuver), 0);
+        assert_eq!(
+            fighter.available_options(FeatureOptionKind::FightingStyle),
+            1
+        );
+    }
+
+    #[test]
+    fn ability_score_improvements() {
+        let mut build = CharacterBuild::new("Brakka", Abilities::new());
+        build.abilities.strength = AbilityScore::new(11);
+        build.background = Some(Background::SOLDIER);
+        build.background_increases = [(Ability::Strength, 2), (Ability::Constitution, 1)].into();
+        for _ in 0..3 {
+            build.add_level(Class::Fighter, []).unwrap();
+        }
+        let improvement = Choice::AbilityScoreImprovement(AbilityScoreImprovement::Feat(
+            Feat::GRAPPLER,
+            Some(Ability::Strength),
+        ));
+        assert_eq!(
+            build.add_level(Class::Rogue, [improvement.clone()]),
+            Err("No Ability Score Improvement to choose")
+        );
+        assert_eq!(
+            build.add_level(Class::Fighter, [improvement.clone(), improvement.clone()]),
+            Err("No Ability Score Improvement to choose")
+        );
+        build.add_level(Class::Fighter, [improvement]).unwrap();
+
+        let fighter = build.replay().unwrap();
+        assert_eq!(fighter.abilities.strength, AbilityScore::new(14));
+        assert!(fighter.has_feat("Grappler"));
+        assert!(fighter.has_feat("Savage Attacker"));
+
+        build.background_increases.clear();
+        assert_eq!(build.replay(), Err("Feat prerequisites not met"));
+    }
+
+    #[test]
+    fn skipped_ability_score_improvement() {
+        let mut build = CharacterBuild::new("Brakka", Abilities::new());
+        build.add_level(Class::Rogue, []).unwrap();
+        build.add_level(Class::Rogue, []).unwrap();
+        build
+            .add_level(Class::Rogue, [Choice::Subclass(Subclass::THIEF)])
+            .unwrap();
+        assert_eq!(
+            build.level_up(Class::Rogue),
+            Ok([Decision::AbilityScoreImprovement].into())
+        );
+        assert_eq!(
+            build.level_up(Class::Rogue),
+            Ok([Decision::AbilityScoreImprovement].into())
+        );
+        let improvement = AbilityScoreImprovement::Increase(Ability::Dexterity);
+        assert_eq!(
+            build.choose(Choice::AbilityScoreImprovement(improvement.clone())),
+            Ok(Vec::new())
+        );
+        assert_eq!(
+            build.choose(Choice::AbilityScoreImprovement(improvement)),
+            Err("No Ability Score Improvement to choose")
+        );
+        let rogue = build.replay().unwrap();
+        assert_eq!(rogue.abilities.dexterity, AbilityScore::new(12));
+        assert_eq!(rogue.ability_score_improvements, 1);
+    }
+
+    #[test]
+    fn level_up_and_undo() {
+        let mut build = CharacterBuild::new("Zariel's Chosen", Abilities::new());
+        assert_eq!(
+            build.choose(Choice::Subclass(Subclass::FIEND)),
+            Err("No level to make a choice at")
+        );
+        assert_eq!(
+            build.level_up(Class::Warlock),
+            Ok([Decision::Subclass(Class::Warlock)].into())
+        );
+        assert_eq!(
+            build.choose(Choice::Subclass(Subclass::FIEND)),
+            Ok(Vec::new())
+        );
+        assert_eq!(
+            build.level_up(Class::Warlock),
+            Ok([Decision::FeatureOptions(
+                FeatureOptionKind::EldritchInvocation,
+                2
+            )]
+            .into())
+        );
+        assert_eq!(
+            build.choose(Choice::FeatureOption(FeatureOption::THIRSTING_BLADE)),
+            Err("Option prerequisites not met")
+        );
+        assert_eq!(
+            build.choose(Choice::FeatureOption(FeatureOption::AGONIZING_BLAST)),
+            Ok([Decision::FeatureOptions(
+                FeatureOptionKind::EldritchInvocation,
+                1
+            )]
+            .into())
+        );
+        assert_eq!(build.levels()[1].choices.len(), 1);
+
+        for _ in 0..2 {
+            build.level_up(Class::Warlock).unwrap();
+        }
+        assert_eq!(
+            build.pending_decisions().unwrap().last(),
+            Some(&Decision::AbilityScoreImprovement)
+        );
+        let undone = build.undo().unwrap();
+        assert!(
+            !build
+                .pending_decisions()
+                .unwrap()
+                .contains(&Decision::AbilityScoreImprovement)
+        );
+        assert_eq!(undone.class, Class::Warlock);
+        let warlock = build.replay().unwrap();
+        assert_eq!(warlock.class_level(Class::Warlock), Some(Level::new(3)));
+        assert_eq!(warlock.health.maximum, 8 + 5 + 5);
+        assert_eq!(warlock.pact_slots.maximum(2), 2);
+    }
+}
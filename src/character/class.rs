@@ -0,0 +1,488 @@
+#[cfg(feature = "alloc")]
+use crate::character::Resource;
+use crate::core::{Ability, Die, Level, RulesVersion};
+use core::{fmt::Display, str::FromStr};
+use enumflags2::BitFlags;
+
+/// A character class from the System Reference Document.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::Class;
+/// use dnd::core::Die;
+///
+/// assert_eq!(Class::Fighter.name(), "Fighter");
+/// assert_eq!(Class::Fighter.hit_die(), Die::D10);
+/// assert_eq!("Wizard".parse(), Ok(Class::Wizard));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Class {
+    /// A fierce warrior of primal rage.
+    Barbarian,
+
+    /// An inspiring performer of music, dance, and magic.
+    Bard,
+
+    /// A priestly champion who wields divine magic.
+    Cleric,
+
+    /// A priest of the Old Faith, wielding the powers of nature.
+    Druid,
+
+    /// A master of martial combat.
+    Fighter,
+
+    /// A master of martial arts, harnessing the power of the body.
+    Monk,
+
+    /// A holy warrior bound to a sacred oath.
+    Paladin,
+
+    /// A warrior of the wilderness.
+    Ranger,
+
+    /// A scoundrel who uses stealth and trickery.
+    Rogue,
+
+    /// A spellcaster who draws on inherent magic.
+    Sorcerer,
+
+    /// A wielder of magic granted by a pact with an otherworldly being.
+    Warlock,
+
+    /// A scholarly magic-user of arcane power.
+    Wizard,
+}
+
+impl Class {
+    /// Returns an array containing all possible [`Class`] variants, in alphabetical order.
+    #[must_use]
+    pub const fn all() -> &'static [Class] {
+        &[
+            Class::Barbarian,
+            Class::Bard,
+            Class::Cleric,
+            Class::Druid,
+            Class::Fighter,
+            Class::Monk,
+            Class::Paladin,
+            Class::Ranger,
+            Class::Rogue,
+            Class::Sorcerer,
+            Class::Warlock,
+            Class::Wizard,
+        ]
+    }
+
+    /// Returns the name of the class, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Class::Barbarian => "Barbarian",
+            Class::Bard => "Bard",
+            Class::Cleric => "Cleric",
+            Class::Druid => "Druid",
+            Class::Fighter => "Fighter",
+            Class::Monk => "Monk",
+            Class::Paladin => "Paladin",
+            Class::Ranger => "Ranger",
+            Class::Rogue => "Rogue",
+            Class::Sorcerer => "Sorcerer",
+            Class::Warlock => "Warlock",
+            Class::Wizard => "Wizard",
+        }
+    }
+
+    /// Returns the hit die rolled for each level in this class.
+    #[must_use]
+    pub const fn hit_die(&self) -> Die {
+        match self {
+            Class::Barbarian => Die::D12,
+            Class::Fighter | Class::Paladin | Class::Ranger => Die::D10,
+            Class::Bard
+            | Class::Cleric
+            | Class::Druid
+            | Class::Monk
+            | Class::Rogue
+            | Class::Warlock => Die::D8,
+            Class::Sorcerer | Class::Wizard => Die::D6,
+        }
+    }
+
+    /// Returns the saving throws a character is proficient in when this is their first class.
+    #[must_use]
+    pub fn saving_throws(&self) -> BitFlags<Ability> {
+        match self {
+            Class::Barbarian | Class::Fighter => Ability::Strength | Ability::Constitution,
+            Class::Bard => Ability::Dexterity | Ability::Charisma,
+            Class::Cleric | Class::Paladin | Class::Warlock => Ability::Wisdom | Ability::Charisma,
+            Class::Druid | Class::Wizard => Ability::Intelligence | Ability::Wisdom,
+            Class::Monk | Class::Ranger => Ability::Strength | Ability::Dexterity,
+            Class::Rogue => Ability::Dexterity | Ability::Intelligence,
+            Class::Sorcerer => Ability::Constitution | Ability::Charisma,
+        }
+    }
+
+    /// Returns the ability used to cast spells of this class, or `None` if the class has no
+    /// spellcasting of its own.
+    #[must_use]
+    pub const fn spellcasting_ability(&self) -> Option<Ability> {
+        match self {
+            Class::Barbarian | Class::Fighter | Class::Monk | Class::Rogue => None,
+            Class::Bard | Class::Paladin | Class::Sorcerer | Class::Warlock => {
+                Some(Ability::Charisma)
+            }
+            Class::Cleric | Class::Druid | Class::Ranger => Some(Ability::Wisdom),
+            Class::Wizard => Some(Ability::Intelligence),
+        }
+    }
+
+    /// Returns whether this class has the Weapon Mastery feature (2024 rules).
+    #[must_use]
+    pub const fn has_weapon_mastery(&self) -> bool {
+        matches!(
+            self,
+            Class::Barbarian | Class::Fighter | Class::Paladin | Class::Ranger | Class::Rogue
+        )
+    }
+
+    /// Returns the levels in this class that grant an Ability Score Improvement, in ascending
+    /// order.
+    ///
+    /// Every class grants one at levels 4, 8, 12, 16, and 19, fighters also at levels 6 and 14,
+    /// and rogues also at level 10.
+    #[must_use]
+    pub const fn ability_score_improvement_levels(&self) -> &'static [u8] {
+        match self {
+            Class::Fighter => &[4, 6, 8, 12, 14, 16, 19],
+            Class::Rogue => &[4, 8, 10, 12, 16, 19],
+            _ => &[4, 8, 12, 16, 19],
+        }
+    }
+
+    /// Returns the level in this class at which a character chooses its subclass under `rules`.
+    ///
+    /// Under the 2014 rules, clerics, sorcerers, and warlocks choose at level 1, druids and
+    /// wizards at level 2, and every other class at level 3. Under the 2024 rules, every class
+    /// chooses at level 3.
+    #[must_use]
+    pub const fn subclass_level(&self, rules: RulesVersion) -> Level {
+        if let RulesVersion::Phb2024 = rules {
+            return Level::new(3);
+        }
+        match self {
+            Class::Cleric | Class::Sorcerer | Class::Warlock => Level::new(1),
+            Class::Druid | Class::Wizard => Level::new(2),
+            Class::Barbarian
+            | Class::Bard
+            | Class::Fighter
+            | Class::Monk
+            | Class::Paladin
+            | Class::Ranger
+            | Class::Rogue => Level::new(3),
+        }
+    }
+
+    /// Returns the feature options this class grants, as the level in the class, the kind of
+    /// option, and how many more options of that kind are chosen at that level.
+    #[must_use]
+    pub const fn feature_options(&self) -> &'static [(Level, FeatureOptionKind, u8)] {
+        const FIGHTER: &[(Level, FeatureOptionKind, u8)] =
+            &[(Level::new(1), FeatureOptionKind::FightingStyle, 1)];
+        const HALF_CASTER: &[(Level, FeatureOptionKind, u8)] =
+            &[(Level::new(2), FeatureOptionKind::FightingStyle, 1)];
+        const SORCERER: &[(Level, FeatureOptionKind, u8)] = &[
+            (Level::new(3), FeatureOptionKind::Metamagic, 2),
+            (Level::new(10), FeatureOptionKind::Metamagic, 1),
+            (Level::new(17), FeatureOptionKind::Metamagic, 1),
+        ];
+        const WARLOCK: &[(Level, FeatureOptionKind, u8)] = &[
+            (Level::new(2), FeatureOptionKind::EldritchInvocation, 2),
+            (Level::new(3), FeatureOptionKind::PactBoon, 1),
+            (Level::new(5), FeatureOptionKind::EldritchInvocation, 1),
+            (Level::new(7), FeatureOptionKind::EldritchInvocation, 1),
+            (Level::new(9), FeatureOptionKind::EldritchInvocation, 1),
+            (Level::new(12), FeatureOptionKind::EldritchInvocation, 1),
+            (Level::new(15), FeatureOptionKind::EldritchInvocation, 1),
+            (Level::new(18), FeatureOptionKind::EldritchInvocation, 1),
+        ];
+        match self {
+            Class::Fighter => FIGHTER,
+            Class::Paladin | Class::Ranger => HALF_CASTER,
+            Class::Sorcerer => SORCERER,
+            Class::Warlock => WARLOCK,
+            Class::Barbarian
+            | Class::Bard
+            | Class::Cleric
+            | Class::Druid
+            | Class::Monk
+            | Class::Rogue
+            | Class::Wizard => &[],
+        }
+    }
+
+    /// Returns the limited-use features this class grants, as the level in the class at which
+    /// each is gained.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub const fn resources(&self) -> &'static [(Level, Resource)] {
+        const BARBARIAN: &[(Level, Resource)] = &[(Level::new(1), Resource::RAGE)];
+        const BARD: &[(Level, Resource)] = &[(Level::new(1), Resource::BARDIC_INSPIRATION)];
+        const CLERIC: &[(Level, Resource)] = &[(Level::new(2), Resource::CHANNEL_DIVINITY)];
+        const MONK: &[(Level, Resource)] = &[(Level::new(2), Resource::KI)];
+        const PALADIN: &[(Level, Resource)] = &[(Level::new(1), Resource::LAY_ON_HANDS)];
+        match self {
+            Class::Barbarian => BARBARIAN,
+            Class::Bard => BARD,
+            Class::Cleric => CLERIC,
+            Class::Monk => MONK,
+            Class::Paladin => PALADIN,
+            Class::Druid
+            | Class::Fighter
+            | Class::Ranger
+            | Class::Rogue
+            | Class::Sorcerer
+            | Class::Warlock
+            | Class::Wizard => &[],
+        }
+    }
+
+    /// Returns how many options of `kind` this class grants by `level` in it.
+    #[must_use]
+    pub fn feature_options_at(&self, kind: FeatureOptionKind, level: Level) -> u8 {
+        options_at(self.feature_options(), kind, level)
+    }
+
+    /// Returns `true` if reaching `level` in this class grants an Ability Score Improvement.
+    #[must_use]
+    pub fn grants_ability_score_improvement(&self, level: Level) -> bool {
+        self.ability_score_improvement_levels()
+            .contains(&level.value())
+    }
+}
+
+/// A kind of option chosen from a list when a class or subclass feature is gained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum FeatureOptionKind {
+    /// A Fighting Style, such as Archery or Defense.
+    FightingStyle,
+
+    /// A Metamagic option, used by sorcerers to alter their spells.
+    Metamagic,
+
+    /// An Eldritch Invocation, a fragment of forbidden knowledge learned by warlocks.
+    EldritchInvocation,
+
+    /// A Pact Boon, a gift from a warlock's patron, such as Pact of the Blade.
+    PactBoon,
+
+    /// A maneuver, a special combat technique fueled by superiority dice.
+    Maneuver,
+}
+
+impl FeatureOptionKind {
+    /// Returns an array containing all possible [`FeatureOptionKind`] variants.
+    #[must_use]
+    pub const fn all() -> &'static [FeatureOptionKind] {
+        &[
+            FeatureOptionKind::FightingStyle,
+            FeatureOptionKind::Metamagic,
+            FeatureOptionKind::EldritchInvocation,
+            FeatureOptionKind::PactBoon,
+            FeatureOptionKind::Maneuver,
+        ]
+    }
+
+    /// Returns the name of the kind of option, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            FeatureOptionKind::FightingStyle => "Fighting Style",
+            FeatureOptionKind::Metamagic => "Metamagic",
+            FeatureOptionKind::EldritchInvocation => "Eldritch Invocation",
+            FeatureOptionKind::PactBoon => "Pact Boon",
+            FeatureOptionKind::Maneuver => "Maneuver",
+        }
+    }
+}
+
+impl Display for FeatureOptionKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Returns how many options of `kind` a table of feature options grants by `level`.
+pub(super) fn options_at(
+    table: &[(Level, FeatureOptionKind, u8)],
+    kind: FeatureOptionKind,
+    level: Level,
+) -> u8 {
+    table
+        .iter()
+        .filter(|(from, granted, _)| *granted == kind && *from <= level)
+        .map(|(_, _, count)| count)
+        .sum()
+}
+
+/// How many levels a character has in one class.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::{Class, ClassLevel};
+/// use dnd::core::Level;
+///
+/// let class_level = ClassLevel::new(Class::Rogue, Level::new(3));
+/// assert_eq!(class_level.to_string(), "Rogue 3");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClassLevel {
+    /// The class.
+    pub class: Class,
+
+    /// The number of levels in the class.
+    pub level: Level,
+}
+
+impl ClassLevel {
+    /// Creates a new `ClassLevel`.
+    #[must_use]
+    pub const fn new(class: Class, level: Level) -> Self {
+        Self { class, level }
+    }
+}
+
+impl Display for ClassLevel {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} {}", self.class, self.level.value())
+    }
+}
+
+impl Display for Class {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl AsRef<str> for Class {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl FromStr for Class {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Class::all()
+            .iter()
+            .find(|class| class.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or("Unknown class")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        for class in Class::all() {
+            assert_eq!(class.to_string().parse(), Ok(*class));
+        }
+        assert!("Artificer".parse::<Class>().is_err());
+    }
+
+    #[test]
+    fn hit_die() {
+        assert_eq!(Class::Barbarian.hit_die(), Die::D12);
+        assert_eq!(Class::Rogue.hit_die(), Die::D8);
+        assert_eq!(Class::Wizard.hit_die(), Die::D6);
+    }
+
+    #[test]
+    fn saving_throws() {
+        for class in Class::all() {
+            assert_eq!(class.saving_throws().len(), 2);
+        }
+        assert!(Class::Rogue.saving_throws().contains(Ability::Intelligence));
+    }
+
+    #[test]
+    fn spellcasting_ability() {
+        assert_eq!(Class::Fighter.spellcasting_ability(), None);
+        assert_eq!(
+            Class::Wizard.spellcasting_ability(),
+            Some(Ability::Intelligence)
+        );
+        assert_eq!(Class::Ranger.spellcasting_ability(), Some(Ability::Wisdom));
+    }
+
+    #[test]
+    fn ability_score_improvements() {
+        assert!(Class::Wizard.grants_ability_score_improvement(Level::new(4)));
+        assert!(!Class::Wizard.grants_ability_score_improvement(Level::new(6)));
+        assert!(Class::Fighter.grants_ability_score_improvement(Level::new(6)));
+        assert!(Class::Rogue.grants_ability_score_improvement(Level::new(10)));
+        assert!(!Class::Fighter.grants_ability_score_improvement(Level::new(10)));
+    }
+
+    #[test]
+    fn subclass_level() {
+        let rules = RulesVersion::Phb2014;
+        assert_eq!(Class::Warlock.subclass_level(rules), Level::new(1));
+        assert_eq!(Class::Wizard.subclass_level(rules), Level::new(2));
+        assert_eq!(Class::Fighter.subclass_level(rules), Level::new(3));
+        let rules = RulesVersion::Phb2024;
+        assert_eq!(Class::Warlock.subclass_level(rules), Level::new(3));
+        assert_eq!(Class::Wizard.subclass_level(rules), Level::new(3));
+    }
+
+    #[test]
+    fn feature_options() {
+        let invocations = FeatureOptionKind::EldritchInvocation;
+        assert_eq!(
+            Class::Warlock.feature_options_at(invocations, Level::new(1)),
+            0
+        );
+        assert_eq!(
+            Class::Warlock.feature_options_at(invocations, Level::new(5)),
+            3
+        );
+        assert_eq!(
+            Class::Warlock.feature_options_at(invocations, Level::new(20)),
+            8
+        );
+        assert!(Class::Wizard.feature_options().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn resources() {
+        assert_eq!(
+            Class::Barbarian.resources(),
+            [(Level::new(1), Resource::RAGE)]
+        );
+        assert!(Class::Wizard.resources().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let serialized = serde_json::to_string(&Class::Warlock).unwrap();
+        assert_eq!(serialized, "\"Warlock\"");
+
+        let deserialized: Class = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, Class::Warlock);
+    }
+}
@@ -0,0 +1,345 @@
+use crate::{
+    character::{Character, Class},
+    core::{Ability, AbilityScore, Level, RulesVersion, Skill},
+    effect::{Modifier, Target},
+    equipment::{ArmorCategory, WeaponCategory},
+};
+use alloc::borrow::Cow;
+use core::fmt::Display;
+use enumflags2::{BitFlags, make_bitflags};
+
+/// A requirement a character must meet to take a [`Feat`] or other option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Prerequisite {
+    /// An ability score of at least this value.
+    AbilityScore(Ability, AbilityScore),
+
+    /// At least one of these ability scores of at least this value, such as "Strength or
+    /// Dexterity 13 or higher".
+    AnyAbilityScore(BitFlags<Ability>, AbilityScore),
+
+    /// Proficiency with a category of armor.
+    ArmorProficiency(ArmorCategory),
+
+    /// Proficiency with a category of weapons.
+    WeaponProficiency(WeaponCategory),
+
+    /// Proficiency in a skill.
+    SkillProficiency(Skill),
+
+    /// The Spellcasting or Pact Magic feature.
+    Spellcasting,
+
+    /// A character level of at least this value.
+    Level(Level),
+
+    /// A level in a class of at least this value, such as "Warlock level 5+".
+    ClassLevel(Class, Level),
+
+    /// The feature option with this name, such as "Pact of the Blade".
+    FeatureOption(Cow<'static, str>),
+}
+
+impl Prerequisite {
+    /// Returns `true` if `character` meets this prerequisite.
+    ///
+    /// Ability scores are compared before any effects are applied, so a magic item that sets
+    /// or increases a score doesn't count toward a prerequisite.
+    #[must_use]
+    pub fn is_met_by(&self, character: &Character) -> bool {
+        match self {
+            Prerequisite::AbilityScore(ability, minimum) => {
+                character.abilities[*ability] >= *minimum
+            }
+            Prerequisite::AnyAbilityScore(abilities, minimum) => abilities
+                .iter()
+                .any(|ability| character.abilities[ability] >= *minimum),
+            Prerequisite::ArmorProficiency(category) => {
+                character.armor_proficiencies.contains(*category)
+            }
+            Prerequisite::WeaponProficiency(category) => {
+                character.weapon_proficiencies.contains(*category)
+            }
+            Prerequisite::SkillProficiency(skill) => {
+                character.skills.get_proficiency(*skill).is_some()
+            }
+            Prerequisite::Spellcasting => character
+                .classes
+                .iter()
+                .any(|class_level| class_level.class.spellcasting_ability().is_some()),
+            Prerequisite::Level(level) => character.level() >= *level,
+            Prerequisite::ClassLevel(class, level) => character
+                .class_level(*class)
+                .is_some_and(|class_level| class_level >= *level),
+            Prerequisite::FeatureOption(name) => character
+                .feature_options
+                .iter()
+                .any(|option| option.name == *name),
+        }
+    }
+}
+
+impl Display for Prerequisite {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Prerequisite::AbilityScore(ability, minimum) => {
+                write!(f, "{ability} {} or higher", minimum.value())
+            }
+            Prerequisite::AnyAbilityScore(abilities, minimum) => {
+                for (i, ability) in abilities.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " or ")?;
+                    }
+                    write!(f, "{ability}")?;
+                }
+                write!(f, " {} or higher", minimum.value())
+            }
+            Prerequisite::ArmorProficiency(category) => {
+                write!(f, "{} Armor Proficiency", category.name())
+            }
+            Prerequisite::WeaponProficiency(category) => match category {
+                WeaponCategory::Simple => write!(f, "Simple Weapon Proficiency"),
+                WeaponCategory::Martial => write!(f, "Martial Weapon Proficiency"),
+            },
+            Prerequisite::SkillProficiency(skill) => write!(f, "Proficiency in {skill}"),
+            Prerequisite::Spellcasting => write!(f, "Spellcasting or Pact Magic Feature"),
+            Prerequisite::Level(level) => write!(f, "Level {}+", level.value()),
+            Prerequisite::ClassLevel(class, level) => {
+                write!(f, "{class} Level {}+", level.value())
+            }
+            Prerequisite::FeatureOption(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// A feat: a special talent taken in place of an Ability Score Improvement, or granted by a
+/// background.
+///
+/// A half-feat also increases one ability score by 1, chosen from [`Feat::ability_increase`]
+/// when the feat is taken. A few feats from the System Reference Document are provided as
+/// constants.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::{Character, Class, ClassLevel, Feat};
+/// use dnd::core::{Ability, AbilityScore, Level};
+///
+/// let mut fighter = Character::new("Brakka");
+/// fighter.classes.push(ClassLevel::new(Class::Fighter, Level::new(4)));
+/// fighter.abilities.dexterity = AbilityScore::new(13);
+///
+/// assert!(Feat::GRAPPLER.is_half_feat());
+/// assert!(Feat::GRAPPLER.can_increase(Ability::Strength));
+/// assert_eq!(Feat::GRAPPLER.unmet_prerequisites(&fighter).count(), 0);
+///
+/// fighter.abilities.dexterity = AbilityScore::new(12);
+/// let unmet = Feat::GRAPPLER.unmet_prerequisites(&fighter).next().unwrap();
+/// assert_eq!(unmet.to_string(), "Strength or Dexterity 13 or higher");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::unsafe_derive_deserialize)] // `make_bitflags!` expands to an `unsafe` block.
+pub struct Feat {
+    /// The name of the feat, such as "Grappler".
+    pub name: Cow<'static, str>,
+
+    /// The requirements a character must meet to take the feat.
+    pub prerequisites: Cow<'static, [Prerequisite]>,
+
+    /// The abilities a half-feat can increase by 1, or none if the feat isn't a half-feat.
+    pub ability_increase: BitFlags<Ability>,
+
+    /// Whether the feat can be taken more than once.
+    pub repeatable: bool,
+
+    /// The modifiers the feat grants, which are applied to a [`Character`] that has it.
+    pub effects: Cow<'static, [(Target, Modifier)]>,
+
+    /// The modifiers the feat grants under the 2024 rules instead, if they differ.
+    pub effects_2024: Option<Cow<'static, [(Target, Modifier)]>>,
+}
+
+impl Feat {
+    /// Gain a +5 bonus to initiative, or a bonus equal to your proficiency bonus under the 2024
+    /// rules, and swap initiative with a willing ally.
+    pub const ALERT: Self = Self {
+        name: Cow::Borrowed("Alert"),
+        prerequisites: Cow::Borrowed(&[]),
+        ability_increase: BitFlags::EMPTY,
+        repeatable: false,
+        effects: Cow::Borrowed(&[(Target::Initiative, Modifier::Flat(5))]),
+        effects_2024: Some(Cow::Borrowed(&[(
+            Target::Initiative,
+            Modifier::ProficiencyBonus,
+        )])),
+    };
+
+    /// Punch and grapple in the same turn, with advantage on attacks against creatures you
+    /// grapple.
+    pub const GRAPPLER: Self = Self {
+        name: Cow::Borrowed("Grappler"),
+        prerequisites: Cow::Borrowed(&[
+            Prerequisite::Level(Level::new(4)),
+            Prerequisite::AnyAbilityScore(
+                make_bitflags!(Ability::{Strength | Dexterity}),
+                AbilityScore::new(13),
+            ),
+        ]),
+        ability_increase: make_bitflags!(Ability::{Strength | Dexterity}),
+        repeatable: false,
+        effects: Cow::Borrowed(&[]),
+        effects_2024: None,
+    };
+
+    /// Roll weapon damage dice twice once per turn, and use either roll.
+    pub const SAVAGE_ATTACKER: Self = Self::new("Savage Attacker");
+
+    /// Gain proficiency in any combination of three skills or tools.
+    pub const SKILLED: Self = Self::new("Skilled").repeatable();
+
+    /// Creates a new `Feat` with the given name and no prerequisites or other benefits.
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            prerequisites: Cow::Borrowed(&[]),
+            ability_increase: BitFlags::EMPTY,
+            repeatable: false,
+            effects: Cow::Borrowed(&[]),
+            effects_2024: None,
+        }
+    }
+
+    /// Sets the requirements a character must meet to take the feat.
+    #[must_use]
+    pub fn with_prerequisites(
+        mut self,
+        prerequisites: impl Into<Cow<'static, [Prerequisite]>>,
+    ) -> Self {
+        self.prerequisites = prerequisites.into();
+        self
+    }
+
+    /// Makes the feat a half-feat that increases one of `abilities` by 1.
+    #[must_use]
+    pub const fn with_ability_increase(mut self, abilities: BitFlags<Ability>) -> Self {
+        self.ability_increase = abilities;
+        self
+    }
+
+    /// Sets the modifiers the feat grants.
+    #[must_use]
+    pub fn with_effects(mut self, effects: impl Into<Cow<'static, [(Target, Modifier)]>>) -> Self {
+        self.effects = effects.into();
+        self
+    }
+
+    /// Sets the modifiers the feat grants under the 2024 rules instead.
+    #[must_use]
+    pub fn with_effects_2024(
+        mut self,
+        effects: impl Into<Cow<'static, [(Target, Modifier)]>>,
+    ) -> Self {
+        self.effects_2024 = Some(effects.into());
+        self
+    }
+
+    /// Allows the feat to be taken more than once.
+    #[must_use]
+    pub const fn repeatable(mut self) -> Self {
+        self.repeatable = true;
+        self
+    }
+
+    /// Returns `true` if the feat increases an ability score by 1.
+    #[must_use]
+    pub fn is_half_feat(&self) -> bool {
+        !self.ability_increase.is_empty()
+    }
+
+    /// Returns `true` if the feat can increase `ability`.
+    #[must_use]
+    pub fn can_increase(&self, ability: Ability) -> bool {
+        self.ability_increase.contains(ability)
+    }
+
+    /// Returns the modifiers the feat grants under `rules`: [`Self::effects_2024`] under the
+    /// 2024 rules if set, and [`Self::effects`] otherwise.
+    #[must_use]
+    pub fn effects_for(&self, rules: RulesVersion) -> &[(Target, Modifier)] {
+        match (rules, &self.effects_2024) {
+            (RulesVersion::Phb2024, Some(effects)) => effects,
+            _ => &self.effects,
+        }
+    }
+
+    /// Returns the prerequisites of the feat that `character` doesn't meet.
+    pub fn unmet_prerequisites<'a>(
+        &'a self,
+        character: &'a Character,
+    ) -> impl Iterator<Item = &'a Prerequisite> {
+        self.prerequisites
+            .iter()
+            .filter(|prerequisite| !prerequisite.is_met_by(character))
+    }
+
+    /// Returns `true` if `character` meets every prerequisite of the feat.
+    #[must_use]
+    pub fn is_available_to(&self, character: &Character) -> bool {
+        self.unmet_prerequisites(character).next().is_none()
+    }
+}
+
+impl Display for Feat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::character::ClassLevel;
+    use alloc::string::ToString;
+
+    #[test]
+    fn prerequisites() {
+        let mut wizard = Character::new("Ilsa");
+        assert!(!Prerequisite::Spellcasting.is_met_by(&wizard));
+        assert!(!Feat::GRAPPLER.is_available_to(&wizard));
+        assert_eq!(Feat::GRAPPLER.unmet_prerequisites(&wizard).count(), 2);
+        assert!(Feat::ALERT.is_available_to(&wizard));
+
+        wizard
+            .classes
+            .push(ClassLevel::new(Class::Wizard, Level::new(4)));
+        wizard.abilities.strength = AbilityScore::new(13);
+        assert!(Prerequisite::Spellcasting.is_met_by(&wizard));
+        assert!(Feat::GRAPPLER.is_available_to(&wizard));
+
+        let prerequisite = Prerequisite::SkillProficiency(Skill::Arcana);
+        assert!(!prerequisite.is_met_by(&wizard));
+        wizard.skills.set_expertise(Skill::Arcana);
+        assert!(prerequisite.is_met_by(&wizard));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Prerequisite::AbilityScore(Ability::Dexterity, AbilityScore::new(13)).to_string(),
+            "Dexterity 13 or higher"
+        );
+        assert_eq!(
+            Prerequisite::ArmorProficiency(ArmorCategory::Medium).to_string(),
+            "Medium Armor Proficiency"
+        );
+        assert_eq!(Prerequisite::Level(Level::new(4)).to_string(), "Level 4+");
+        assert_eq!(
+            Prerequisite::ClassLevel(Class::Warlock, Level::new(5)).to_string(),
+            "Warlock Level 5+"
+        );
+        assert_eq!(Feat::SAVAGE_ATTACKER.to_string(), "Savage Attacker");
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    character::{Character, Class, FeatureOptionKind, Prerequisite},
+    core::Level,
+};
+use alloc::borrow::Cow;
+use core::fmt::Display;
+
+/// An option chosen from a list when a class or subclass feature is gained, such as a Fighting
+/// Style or an Eldritch Invocation.
+///
+/// Some options have prerequisites, such as a minimum warlock level for an invocation. A few
+/// options of each [`FeatureOptionKind`] are provided as constants.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::{Character, Class, ClassLevel, FeatureOption};
+/// use dnd::core::Level;
+///
+/// let mut warlock = Character::new("Zariel's Chosen");
+/// warlock.classes.push(ClassLevel::new(Class::Warlock, Level::new(5)));
+/// assert!(FeatureOption::AGONIZING_BLAST.is_available_to(&warlock));
+/// assert!(!FeatureOption::THIRSTING_BLADE.is_available_to(&warlock));
+///
+/// warlock.choose_feature_option(FeatureOption::PACT_OF_THE_BLADE).unwrap();
+/// assert!(FeatureOption::THIRSTING_BLADE.is_available_to(&warlock));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureOption {
+    /// The name of the option, such as "Archery".
+    pub name: Cow<'static, str>,
+
+    /// The kind of option.
+    pub kind: FeatureOptionKind,
+
+    /// The requirements a character must meet to choose the option.
+    pub prerequisites: Cow<'static, [Prerequisite]>,
+}
+
+impl FeatureOption {
+    /// +2 to attack rolls with ranged weapons.
+    pub const ARCHERY: Self = Self::new("Archery", FeatureOptionKind::FightingStyle);
+
+    /// +1 to Armor Class while wearing armor.
+    pub const DEFENSE: Self = Self::new("Defense", FeatureOptionKind::FightingStyle);
+
+    /// +2 to damage rolls with a melee weapon in one hand and no other weapons.
+    pub const DUELING: Self = Self::new("Dueling", FeatureOptionKind::FightingStyle);
+
+    /// Reroll 1s and 2s on damage dice of two-handed melee weapons.
+    pub const GREAT_WEAPON_FIGHTING: Self =
+        Self::new("Great Weapon Fighting", FeatureOptionKind::FightingStyle);
+
+    /// Impose disadvantage on an attack against an ally nearby while wielding a shield.
+    pub const PROTECTION: Self = Self::new("Protection", FeatureOptionKind::FightingStyle);
+
+    /// Add the ability modifier to the damage of the second attack when fighting with two
+    /// weapons.
+    pub const TWO_WEAPON_FIGHTING: Self =
+        Self::new("Two-Weapon Fighting", FeatureOptionKind::FightingStyle);
+
+    /// Protect creatures from the full force of a spell with a saving throw.
+    pub const CAREFUL_SPELL: Self = Self::new("Careful Spell", FeatureOptionKind::Metamagic);
+
+    /// Double the range of a spell.
+    pub const DISTANT_SPELL: Self = Self::new("Distant Spell", FeatureOptionKind::Metamagic);
+
+    /// Reroll damage dice of a spell.
+    pub const EMPOWERED_SPELL: Self = Self::new("Empowered Spell", FeatureOptionKind::Metamagic);
+
+    /// Double the duration of a spell.
+    pub const EXTENDED_SPELL: Self = Self::new("Extended Spell", FeatureOptionKind::Metamagic);
+
+    /// Give a target disadvantage on its first saving throw against a spell.
+    pub const HEIGHTENED_SPELL: Self = Self::new("Heightened Spell", FeatureOptionKind::Metamagic);
+
+    /// Cast a spell as a bonus action.
+    pub const QUICKENED_SPELL: Self = Self::new("Quickened Spell", FeatureOptionKind::Metamagic);
+
+    /// Cast a spell without somatic or verbal components.
+    pub const SUBTLE_SPELL: Self = Self::new("Subtle Spell", FeatureOptionKind::Metamagic);
+
+    /// Target a second creature with a spell that targets only one.
+    pub const TWINNED_SPELL: Self = Self::new("Twinned Spell", FeatureOptionKind::Metamagic);
+
+    /// Add the Charisma modifier to the damage of eldritch blast.
+    pub const AGONIZING_BLAST: Self =
+        Self::new("Agonizing Blast", FeatureOptionKind::EldritchInvocation);
+
+    /// Cast mage armor on yourself at will.
+    pub const ARMOR_OF_SHADOWS: Self =
+        Self::new("Armor of Shadows", FeatureOptionKind::EldritchInvocation);
+
+    /// See normally in darkness, both magical and nonmagical.
+    pub const DEVILS_SIGHT: Self =
+        Self::new("Devil's Sight", FeatureOptionKind::EldritchInvocation);
+
+    /// Attack twice with your pact weapon.
+    pub const THIRSTING_BLADE: Self = Self {
+        name: Cow::Borrowed("Thirsting Blade"),
+        kind: FeatureOptionKind::EldritchInvocation,
+        prerequisites: Cow::Borrowed(&[
+            Prerequisite::ClassLevel(Class::Warlock, Level::new(5)),
+            Prerequisite::FeatureOption(Cow::Borrowed("Pact of the Blade")),
+        ]),
+    };
+
+    /// Deal extra necrotic damage with your pact weapon.
+    pub const LIFEDRINKER: Self = Self {
+        name: Cow::Borrowed("Lifedrinker"),
+        kind: FeatureOptionKind::EldritchInvocation,
+        prerequisites: Cow::Borrowed(&[
+            Prerequisite::ClassLevel(Class::Warlock, Level::new(12)),
+            Prerequisite::FeatureOption(Cow::Borrowed("Pact of the Blade")),
+        ]),
+    };
+
+    /// Summon a pact weapon in your hand.
+    pub const PACT_OF_THE_BLADE: Self = Self::new("Pact of the Blade", FeatureOptionKind::PactBoon);
+
+    /// Learn the find familiar spell, and summon special forms of familiar.
+    pub const PACT_OF_THE_CHAIN: Self = Self::new("Pact of the Chain", FeatureOptionKind::PactBoon);
+
+    /// Gain a Book of Shadows with three cantrips from any class's spell list.
+    pub const PACT_OF_THE_TOME: Self = Self::new("Pact of the Tome", FeatureOptionKind::PactBoon);
+
+    /// Add a superiority die to an attack roll.
+    pub const PRECISION_ATTACK: Self = Self::new("Precision Attack", FeatureOptionKind::Maneuver);
+
+    /// Make a melee attack against a creature that misses you.
+    pub const RIPOSTE: Self = Self::new("Riposte", FeatureOptionKind::Maneuver);
+
+    /// Knock a creature prone when you hit it.
+    pub const TRIP_ATTACK: Self = Self::new("Trip Attack", FeatureOptionKind::Maneuver);
+
+    /// Creates a new `FeatureOption` with no prerequisites.
+    #[must_use]
+    pub const fn new(name: &'static str, kind: FeatureOptionKind) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            kind,
+            prerequisites: Cow::Borrowed(&[]),
+        }
+    }
+
+    /// Returns the prerequisites of the option that `character` doesn't meet.
+    pub fn unmet_prerequisites<'a>(
+        &'a self,
+        character: &'a Character,
+    ) -> impl Iterator<Item = &'a Prerequisite> {
+        self.prerequisites
+            .iter()
+            .filter(|prerequisite| !prerequisite.is_met_by(character))
+    }
+
+    /// Returns `true` if `character` meets every prerequisite of the option.
+    #[must_use]
+    pub fn is_available_to(&self, character: &Character) -> bool {
+        self.unmet_prerequisites(character).next().is_none()
+    }
+}
+
+impl Display for FeatureOption {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
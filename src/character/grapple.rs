@@ -0,0 +1,16 @@
+use crate::effect::Breakdown;
+
+/// How a character attempts to grapple a creature, which depends on the [`RulesVersion`].
+///
+/// [`RulesVersion`]: crate::core::RulesVersion
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Grapple {
+    /// A Strength (Athletics) check, contested by the target's Strength (Athletics) or
+    /// Dexterity (Acrobatics) check (2014 rules).
+    Contest(Breakdown),
+
+    /// An Unarmed Strike that the target resists with a Strength or Dexterity saving throw
+    /// against this DC (2024 rules).
+    SavingThrow(Breakdown),
+}
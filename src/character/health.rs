@@ -0,0 +1,108 @@
+/// A character's current, maximum, and temporary hit points.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::Health;
+///
+/// let mut health = Health::new(20);
+/// health.grant_temporary(5);
+/// health.damage(8);
+/// assert_eq!((health.current, health.temporary), (17, 0));
+///
+/// health.heal(10);
+/// assert_eq!(health.current, 20);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Health {
+    /// The most hit points the character can have, not counting temporary hit points.
+    pub maximum: u16,
+
+    /// The hit points the character currently has.
+    pub current: u16,
+
+    /// A buffer of hit points that is lost before any current hit points.
+    pub temporary: u16,
+}
+
+impl Health {
+    /// Creates a new `Health` at its maximum hit points.
+    #[must_use]
+    pub const fn new(maximum: u16) -> Self {
+        Self {
+            maximum,
+            current: maximum,
+            temporary: 0,
+        }
+    }
+
+    /// Takes `amount` damage, which is subtracted from temporary hit points first.
+    ///
+    /// Returns the damage left over after hit points reach 0, which kills the character
+    /// outright if it equals or exceeds its hit point maximum.
+    pub const fn damage(&mut self, amount: u16) -> u16 {
+        let absorbed = if amount < self.temporary {
+            amount
+        } else {
+            self.temporary
+        };
+        self.temporary -= absorbed;
+        let amount = amount - absorbed;
+        if amount > self.current {
+            let remaining = amount - self.current;
+            self.current = 0;
+            remaining
+        } else {
+            self.current -= amount;
+            0
+        }
+    }
+
+    /// Regains `amount` hit points, up to the hit point maximum.
+    pub const fn heal(&mut self, amount: u16) {
+        let current = self.current.saturating_add(amount);
+        self.current = if current < self.maximum {
+            current
+        } else {
+            self.maximum
+        };
+    }
+
+    /// Gains `amount` temporary hit points, which replace any existing temporary hit points
+    /// only if higher, since they don't stack.
+    pub const fn grant_temporary(&mut self, amount: u16) {
+        if amount > self.temporary {
+            self.temporary = amount;
+        }
+    }
+
+    /// Returns whether the character has 0 hit points.
+    #[must_use]
+    pub const fn is_down(&self) -> bool {
+        self.current == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn damage() {
+        let mut health = Health::new(12);
+        health.grant_temporary(4);
+        assert_eq!(health.damage(3), 0);
+        assert_eq!((health.current, health.temporary), (12, 1));
+        assert_eq!(health.damage(20), 7);
+        assert!(health.is_down());
+    }
+
+    #[test]
+    fn temporary_hit_points_do_not_stack() {
+        let mut health = Health::new(10);
+        health.grant_temporary(5);
+        health.grant_temporary(3);
+        assert_eq!(health.temporary, 5);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{character::ClassLevel, core::Die};
+use alloc::vec::Vec;
+
+/// The hit dice of one size a character has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HitDicePool {
+    /// The size of the dice.
+    pub die: Die,
+
+    /// The number of dice, one for each level in a class with this hit die.
+    pub total: u8,
+
+    /// The number of dice that have not been spent.
+    pub remaining: u8,
+}
+
+/// The hit dice a character can spend during a short rest to regain hit points.
+///
+/// A multiclass character has a pool of dice for each size of hit die among its classes.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::{Class, ClassLevel, HitDice};
+/// use dnd::core::{Die, Level};
+///
+/// let mut hit_dice = HitDice::from_classes(&[
+///     ClassLevel::new(Class::Fighter, Level::new(3)),
+///     ClassLevel::new(Class::Wizard, Level::new(2)),
+/// ]);
+/// hit_dice.spend(Die::D10).unwrap();
+/// hit_dice.spend(Die::D10).unwrap();
+/// hit_dice.spend(Die::D6).unwrap();
+/// assert_eq!(hit_dice.remaining(), 2);
+///
+/// // Larger dice are regained first.
+/// hit_dice.regain(2);
+/// assert_eq!(hit_dice.remaining_of(Die::D10), 3);
+/// assert_eq!(hit_dice.remaining_of(Die::D6), 1);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HitDice {
+    pools: Vec<HitDicePool>,
+}
+
+impl HitDice {
+    /// Creates a new `HitDice` with no dice.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { pools: Vec::new() }
+    }
+
+    /// Creates a new `HitDice` with one unspent die for each class level.
+    #[must_use]
+    pub fn from_classes(classes: &[ClassLevel]) -> Self {
+        let mut hit_dice = Self::new();
+        for class_level in classes {
+            for _ in 0..class_level.level.value() {
+                hit_dice.add(class_level.class.hit_die());
+            }
+        }
+        hit_dice
+    }
+
+    /// Adds an unspent die, such as when gaining a level.
+    pub fn add(&mut self, die: Die) -> &mut Self {
+        if let Some(pool) = self.pools.iter_mut().find(|pool| pool.die == die) {
+            pool.total = pool.total.saturating_add(1);
+            pool.remaining = pool.remaining.saturating_add(1);
+        } else {
+            self.pools.push(HitDicePool {
+                die,
+                total: 1,
+                remaining: 1,
+            });
+            self.pools.sort_by_key(|pool| core::cmp::Reverse(pool.die));
+        }
+        self
+    }
+
+    /// Returns the pools of each size of die, from largest to smallest.
+    #[must_use]
+    pub fn pools(&self) -> &[HitDicePool] {
+        &self.pools
+    }
+
+    /// Returns the total number of dice.
+    #[must_use]
+    pub fn total(&self) -> u8 {
+        self.pools.iter().map(|pool| pool.total).sum()
+    }
+
+    /// Returns the number of dice that have not been spent.
+    #[must_use]
+    pub fn remaining(&self) -> u8 {
+        self.pools.iter().map(|pool| pool.remaining).sum()
+    }
+
+    /// Returns the number of dice of the given size that have not been spent.
+    #[must_use]
+    pub fn remaining_of(&self, die: Die) -> u8 {
+        self.pools
+            .iter()
+            .find(|pool| pool.die == die)
+            .map_or(0, |pool| pool.remaining)
+    }
+
+    /// Spends a die of the given size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no dice of that size remain.
+    pub fn spend(&mut self, die: Die) -> Result<&mut Self, &'static str> {
+        let pool = self
+            .pools
+            .iter_mut()
+            .find(|pool| pool.die == die && pool.remaining > 0)
+            .ok_or("No hit dice of this size remaining")?;
+        pool.remaining -= 1;
+        Ok(self)
+    }
+
+    /// Regains up to `count` spent dice, largest first.
+    pub fn regain(&mut self, mut count: u8) -> &mut Self {
+        for pool in &mut self.pools {
+            let regained = count.min(pool.total - pool.remaining);
+            pool.remaining += regained;
+            count -= regained;
+        }
+        self
+    }
+
+    /// Regains every spent die.
+    pub fn regain_all(&mut self) -> &mut Self {
+        for pool in &mut self.pools {
+            pool.remaining = pool.total;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{character::Class, core::Level};
+
+    #[test]
+    fn spend() {
+        let mut hit_dice = HitDice::from_classes(&[ClassLevel::new(Class::Rogue, Level::new(2))]);
+        assert_eq!(hit_dice.total(), 2);
+        hit_dice.spend(Die::D8).unwrap().spend(Die::D8).unwrap();
+        assert_eq!(
+            hit_dice.spend(Die::D8).unwrap_err(),
+            "No hit dice of this size remaining"
+        );
+        assert!(hit_dice.spend(Die::D12).is_err());
+
+        hit_dice.regain_all();
+        assert_eq!((hit_dice.total(), hit_dice.remaining()), (2, 2));
+    }
+}
@@ -0,0 +1,313 @@
+use crate::{
+    character::Class,
+    core::{Abilities, Ability, Level, ProficiencyBonus, Recharge, Rest},
+};
+use alloc::borrow::Cow;
+
+/// How the maximum number of uses of a [`Resource`] is determined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResourceMaximum {
+    /// A fixed number of uses.
+    Fixed(u8),
+
+    /// A number of uses equal to the proficiency bonus.
+    ProficiencyBonus,
+
+    /// A number of uses equal to an ability modifier, but never fewer than `minimum`.
+    AbilityModifier {
+        /// The ability whose modifier is used.
+        ability: Ability,
+
+        /// The fewest uses, even with a lower modifier.
+        minimum: u8,
+    },
+
+    /// A number of uses equal to the level multiplied by this amount, such as 5 hit points of
+    /// healing per paladin level for Lay On Hands.
+    PerLevel(u8),
+
+    /// A number of uses that increases at certain levels, listed as pairs of the level and the
+    /// uses from that level on, in ascending order of level.
+    ByLevel(Cow<'static, [(Level, u8)]>),
+}
+
+impl ResourceMaximum {
+    /// Returns the maximum number of uses for a creature of the given `level`.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn evaluate(
+        &self,
+        level: Level,
+        proficiency_bonus: ProficiencyBonus,
+        abilities: &Abilities,
+    ) -> u8 {
+        match self {
+            ResourceMaximum::Fixed(uses) => *uses,
+            ResourceMaximum::ProficiencyBonus => proficiency_bonus.value(),
+            ResourceMaximum::AbilityModifier { ability, minimum } => {
+                (abilities[*ability].modifier().value().max(0) as u8).max(*minimum)
+            }
+            ResourceMaximum::PerLevel(multiplier) => level.value().saturating_mul(*multiplier),
+            ResourceMaximum::ByLevel(table) => table
+                .iter()
+                .take_while(|(from, _)| *from <= level)
+                .last()
+                .map_or(0, |(_, uses)| *uses),
+        }
+    }
+}
+
+/// A limited-use class feature or other pool, such as Rage or Ki, regained on a rest.
+///
+/// The number of expended uses is tracked, rather than the number remaining, so the maximum can
+/// grow as the character does. A few SRD resources are provided as constants.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::Resource;
+/// use dnd::core::{Abilities, Level, Rest};
+///
+/// let mut rage = Resource::RAGE;
+/// let level = Level::new(3);
+/// let maximum = rage.maximum.evaluate(level, level.proficiency_bonus(), &Abilities::new());
+/// assert_eq!(maximum, 3);
+///
+/// rage.spend(2, maximum).unwrap();
+/// assert_eq!(rage.remaining(maximum), 1);
+/// assert!(rage.spend(2, maximum).is_err());
+///
+/// rage.rest(Rest::Short);
+/// assert_eq!(rage.remaining(maximum), 1);
+/// rage.rest(Rest::Long);
+/// assert_eq!(rage.remaining(maximum), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Resource {
+    /// The name of the resource, such as "Channel Divinity".
+    pub name: Cow<'static, str>,
+
+    /// How the maximum number of uses is determined.
+    pub maximum: ResourceMaximum,
+
+    /// When expended uses are regained.
+    pub recharge: Recharge,
+
+    /// The class whose level the maximum scales with, or `None` for the total character level.
+    pub class: Option<Class>,
+
+    /// The level from which the resource is regained by a different recharge, and that
+    /// recharge, such as Bardic Inspiration on a short rest from bard level 5.
+    pub improved_recharge: Option<(Level, Recharge)>,
+
+    expended: u8,
+}
+
+impl Resource {
+    /// Inspire others with a Bardic Inspiration die, a number of times equal to your Charisma
+    /// modifier (minimum 1), regained on a short rest from bard level 5 (Font of Inspiration).
+    pub const BARDIC_INSPIRATION: Self = Self::new(
+        "Bardic Inspiration",
+        ResourceMaximum::AbilityModifier {
+            ability: Ability::Charisma,
+            minimum: 1,
+        },
+        Recharge::LongRest,
+    )
+    .for_class(Class::Bard)
+    .recharges_from(Level::new(5), Recharge::ShortRest);
+
+    /// Channel divine energy to fuel magical effects.
+    pub const CHANNEL_DIVINITY: Self = Self::new(
+        "Channel Divinity",
+        ResourceMaximum::ByLevel(Cow::Borrowed(&[
+            (Level::new(2), 1),
+            (Level::new(6), 2),
+            (Level::new(18), 3),
+        ])),
+        Recharge::ShortRest,
+    )
+    .for_class(Class::Cleric);
+
+    /// Harness the mystic energy of ki, with points equal to your monk level.
+    pub const KI: Self =
+        Self::new("Ki", ResourceMaximum::PerLevel(1), Recharge::ShortRest).for_class(Class::Monk);
+
+    /// A pool of healing power equal to 5 times your paladin level.
+    pub const LAY_ON_HANDS: Self = Self::new(
+        "Lay On Hands",
+        ResourceMaximum::PerLevel(5),
+        Recharge::LongRest,
+    )
+    .for_class(Class::Paladin);
+
+    /// Fight with primal ferocity a number of times that increases with your barbarian level.
+    ///
+    /// Under the 2014 rules, a level 20 barbarian can rage an unlimited number of times, which
+    /// isn't represented here; the maximum stays at 6.
+    pub const RAGE: Self = Self::new(
+        "Rage",
+        ResourceMaximum::ByLevel(Cow::Borrowed(&[
+            (Level::new(1), 2),
+            (Level::new(3), 3),
+            (Level::new(6), 4),
+            (Level::new(12), 5),
+            (Level::new(17), 6),
+        ])),
+        Recharge::LongRest,
+    )
+    .for_class(Class::Barbarian);
+
+    /// Superiority dice, expended to fuel Battle Master maneuvers.
+    pub const SUPERIORITY_DICE: Self = Self::new(
+        "Superiority Dice",
+        ResourceMaximum::ByLevel(Cow::Borrowed(&[
+            (Level::new(3), 4),
+            (Level::new(7), 5),
+            (Level::new(15), 6),
+        ])),
+        Recharge::ShortRest,
+    )
+    .for_class(Class::Fighter);
+
+    /// Creates a new `Resource` with no uses expended, scaling with the total character level.
+    #[must_use]
+    pub const fn new(name: &'static str, maximum: ResourceMaximum, recharge: Recharge) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            maximum,
+            recharge,
+            class: None,
+            improved_recharge: None,
+            expended: 0,
+        }
+    }
+
+    /// Returns this resource, scaling with the level of the given class.
+    #[must_use]
+    pub const fn for_class(mut self, class: Class) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    /// Returns this resource, regained by `recharge` instead from `level` on.
+    #[must_use]
+    pub const fn recharges_from(mut self, level: Level, recharge: Recharge) -> Self {
+        self.improved_recharge = Some((level, recharge));
+        self
+    }
+
+    /// Returns when expended uses are regained for a creature of the given `level`.
+    #[must_use]
+    pub fn recharge_at(&self, level: Level) -> Recharge {
+        match self.improved_recharge {
+            Some((from, recharge)) if level >= from => recharge,
+            _ => self.recharge,
+        }
+    }
+
+    /// Returns the number of expended uses.
+    #[must_use]
+    pub const fn expended(&self) -> u8 {
+        self.expended
+    }
+
+    /// Returns the number of uses remaining, given the current `maximum`.
+    #[must_use]
+    pub const fn remaining(&self, maximum: u8) -> u8 {
+        maximum.saturating_sub(self.expended)
+    }
+
+    /// Expends `amount` uses, given the current `maximum`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fewer than `amount` uses remain.
+    pub fn spend(&mut self, amount: u8, maximum: u8) -> Result<&mut Self, &'static str> {
+        if amount > self.remaining(maximum) {
+            return Err("Not enough uses remaining");
+        }
+        self.expended += amount;
+        Ok(self)
+    }
+
+    /// Regains up to `amount` expended uses.
+    pub const fn restore(&mut self, amount: u8) -> &mut Self {
+        self.expended = self.expended.saturating_sub(amount);
+        self
+    }
+
+    /// Regains every expended use.
+    pub const fn restore_all(&mut self) -> &mut Self {
+        self.expended = 0;
+        self
+    }
+
+    /// Regains every expended use, if the resource recharges on the given rest.
+    ///
+    /// This ignores [`Self::improved_recharge`]; [`Character::rest`] takes it into account.
+    ///
+    /// [`Character::rest`]: crate::character::Character::rest
+    pub fn rest(&mut self, rest: Rest) -> &mut Self {
+        if rest.recharges(self.recharge) {
+            self.restore_all();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::AbilityScore;
+
+    fn maximum(resource: &Resource, level: u8, abilities: &Abilities) -> u8 {
+        let level = Level::new(level);
+        resource
+            .maximum
+            .evaluate(level, level.proficiency_bonus(), abilities)
+    }
+
+    #[test]
+    fn maximums() {
+        let mut abilities = Abilities::new();
+        assert_eq!(maximum(&Resource::BARDIC_INSPIRATION, 1, &abilities), 1);
+        abilities.charisma = AbilityScore::new(17);
+        assert_eq!(maximum(&Resource::BARDIC_INSPIRATION, 1, &abilities), 3);
+
+        assert_eq!(maximum(&Resource::CHANNEL_DIVINITY, 1, &abilities), 0);
+        assert_eq!(maximum(&Resource::CHANNEL_DIVINITY, 6, &abilities), 2);
+        assert_eq!(maximum(&Resource::RAGE, 20, &abilities), 6);
+        assert_eq!(maximum(&Resource::LAY_ON_HANDS, 4, &abilities), 20);
+
+        let second_wind = Resource::new(
+            "Second Wind",
+            ResourceMaximum::ProficiencyBonus,
+            Recharge::LongRest,
+        );
+        assert_eq!(maximum(&second_wind, 9, &abilities), 4);
+    }
+
+    #[test]
+    fn restore() {
+        let mut ki = Resource::KI;
+        ki.spend(4, 5).unwrap().restore(1);
+        assert_eq!(ki.expended(), 3);
+        assert_eq!(ki.remaining(2), 0);
+        ki.rest(Rest::Short);
+        assert_eq!(ki.remaining(5), 5);
+    }
+
+    #[test]
+    fn recharge_at() {
+        let inspiration = Resource::BARDIC_INSPIRATION;
+        assert_eq!(inspiration.recharge_at(Level::new(4)), Recharge::LongRest);
+        assert_eq!(inspiration.recharge_at(Level::new(5)), Recharge::ShortRest);
+        assert_eq!(
+            Resource::KI.recharge_at(Level::new(20)),
+            Recharge::ShortRest
+        );
+    }
+}
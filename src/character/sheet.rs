@@ -0,0 +1,1717 @@
+use crate::{
+    character::{
+        AbilityIncreases, Background, Class, ClassLevel, Decision, Feat, FeatureOption,
+        FeatureOptionKind, Grapple, Health, HitDice, Resource, Species, SpellSlots, Subclass,
+        VariantRules,
+    },
+    core::{
+        Abilities, Ability, AbilityScore, Condition, DamageType, Dice, Die, Level, PassiveScores,
+        ProficiencyBonus, Recharge, Rest, RollMode, RulesVersion, Skill, SkillLevel,
+        SkillProficiencies, SkillRegistry, SkillReplacements, Tool, ToolProficiencies,
+    },
+    creature::{Senses, Size, Speeds},
+    effect::{Breakdown, Effects, Modifier, Source, Target},
+    equipment::{
+        Armor, ArmorCategory, Attunements, CarryingCapacity, Coin, Encumbrance, Inventory, Item,
+        MagicItem, Wallet, Weapon, WeaponCategory, WeaponKind, WeaponMastery,
+    },
+};
+use alloc::{borrow::Cow, vec::Vec};
+use enumflags2::BitFlags;
+
+/// A player character, and every value derived from its choices and equipment.
+///
+/// Derived values are computed each time they are asked for, so they always reflect the
+/// character's current state. Each is returned as a [`Breakdown`] of the sources that
+/// contribute to it, with every bonus and penalty beyond the base formula applied through
+/// [`Effects`]: those registered directly, and those from feats, magic items, armor, and
+/// conditions.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::{Character, Class, ClassLevel};
+/// use dnd::core::{Ability, AbilityScore, Dice, Die, Level, Skill};
+/// use dnd::effect::{Modifier, Source, Target};
+///
+/// let mut rogue = Character::new("Vex");
+/// rogue.classes.push(ClassLevel::new(Class::Rogue, Level::new(5)));
+/// rogue.abilities.dexterity = AbilityScore::new(18);
+/// rogue.skills.set_proficient(Skill::Stealth);
+///
+/// let stealth = rogue.skill(Skill::Stealth);
+/// assert_eq!(stealth.total(), 7);
+/// assert_eq!(stealth.to_string(), "Dexterity +4, Proficiency +3");
+///
+/// assert_eq!(rogue.armor_class().total(), 14);
+/// assert_eq!(rogue.initiative().total(), 4);
+///
+/// let bless = Modifier::Dice(Dice::new(1, Die::D4));
+/// rogue.effects.add(Source::Spell("Bless".into()), Target::AllSavingThrows, bless);
+/// assert_eq!(rogue.saving_throw(Ability::Wisdom).to_string(), "Wisdom +0, Bless +1d4");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Character {
+    /// The name of the character.
+    pub name: Cow<'static, str>,
+
+    /// The edition of the rules the character is played with.
+    pub rules: RulesVersion,
+
+    /// The optional and house rules the character is played with.
+    pub variant_rules: VariantRules,
+
+    /// The ability scores of the character, before any effects are applied.
+    pub abilities: Abilities,
+
+    /// The skills the character is proficient in.
+    pub skills: SkillProficiencies,
+
+    /// The tools the character is proficient with.
+    pub tools: ToolProficiencies,
+
+    /// The saving throws the character is proficient in.
+    pub saving_throws: BitFlags<Ability>,
+
+    /// The categories of armor the character is proficient with.
+    pub armor_proficiencies: BitFlags<ArmorCategory>,
+
+    /// The categories of weapons the character is proficient with.
+    pub weapon_proficiencies: BitFlags<WeaponCategory>,
+
+    /// The levels the character has in each class, starting with the first class taken.
+    pub classes: Vec<ClassLevel>,
+
+    /// The subclass the character has chosen in each class, if any.
+    pub subclasses: Vec<Subclass>,
+
+    /// The feature options the character has chosen, such as Fighting Styles and Eldritch
+    /// Invocations.
+    pub feature_options: Vec<FeatureOption>,
+
+    /// The character's species, if any.
+    pub species: Option<Species>,
+
+    /// The character's background, if any.
+    pub background: Option<Background>,
+
+    /// The feats the character has taken.
+    pub feats: Vec<Feat>,
+
+    /// The number of Ability Score Improvements the character has taken, including feats taken
+    /// in their place.
+    pub ability_score_improvements: u8,
+
+    /// The size of the character.
+    pub size: Size,
+
+    /// The speeds of the character, before any effects are applied.
+    pub speeds: Speeds,
+
+    /// The special senses of the character.
+    pub senses: Senses,
+
+    /// The languages the character knows.
+    pub languages: Vec<Cow<'static, str>>,
+
+    /// The damage types the character resists.
+    pub resistances: BitFlags<DamageType>,
+
+    /// Everything the character carries, wields, and wears.
+    pub inventory: Inventory,
+
+    /// The coins the character carries.
+    pub wallet: Wallet,
+
+    /// The magic items the character is attuned to.
+    pub attunements: Attunements,
+
+    /// The charges spent from each of the character's magic items, by item name.
+    pub expended_charges: Vec<(Cow<'static, str>, u8)>,
+
+    /// The hit points of the character.
+    pub health: Health,
+
+    /// The hit dice of the character, which should have one die for each class level.
+    pub hit_dice: HitDice,
+
+    /// The spell slots of the character's Spellcasting feature.
+    pub spell_slots: SpellSlots,
+
+    /// The spell slots of the character's Pact Magic feature.
+    pub pact_slots: SpellSlots,
+
+    /// The limited-use features of the character, such as Rage or Ki.
+    pub resources: Vec<Resource>,
+
+    /// The character's level of exhaustion, from 0 to 6.
+    pub exhaustion: u8,
+
+    /// The conditions currently affecting the character.
+    pub conditions: BitFlags<Condition>,
+
+    /// Effects from spells, features, and other sources currently applied to the character.
+    ///
+    /// Effects of feats, magic items, armor, and conditions are applied automatically, and don't need
+    /// to be registered here.
+    pub effects: Effects,
+}
+
+impl Character {
+    /// Creates a new `Character` with the given name, average ability scores, a walking speed
+    /// of 30 feet, and nothing else.
+    #[must_use]
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            rules: RulesVersion::Phb2014,
+            variant_rules: VariantRules::new(),
+            abilities: Abilities::new(),
+            skills: SkillProficiencies::new(),
+            tools: ToolProficiencies::new(),
+            saving_throws: BitFlags::EMPTY,
+            armor_proficiencies: BitFlags::EMPTY,
+            weapon_proficiencies: BitFlags::EMPTY,
+            classes: Vec::new(),
+            subclasses: Vec::new(),
+            feature_options: Vec::new(),
+            species: None,
+            background: None,
+            feats: Vec::new(),
+            ability_score_improvements: 0,
+            size: Size::Medium,
+            speeds: Speeds::walking(30),
+            senses: Senses::default(),
+            languages: Vec::new(),
+            resistances: BitFlags::EMPTY,
+            inventory: Inventory::new(),
+            wallet: Wallet::new(),
+            attunements: Attunements::new(),
+            expended_charges: Vec::new(),
+            health: Health::default(),
+            hit_dice: HitDice::new(),
+            spell_slots: SpellSlots::default(),
+            pact_slots: SpellSlots::default(),
+            resources: Vec::new(),
+            exhaustion: 0,
+            conditions: BitFlags::EMPTY,
+            effects: Effects::new(),
+        }
+    }
+
+    /// Returns the character's total level across all classes, which is at least 1.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn level(&self) -> Level {
+        let total: u32 = self
+            .classes
+            .iter()
+            .map(|class_level| u32::from(class_level.level.value()))
+            .sum();
+        Level::new_clamped(total.min(u32::from(u8::MAX)) as u8)
+    }
+
+    /// Returns the character's level in `class`, or `None` if it has no levels in it.
+    #[must_use]
+    pub fn class_level(&self, class: Class) -> Option<Level> {
+        self.classes
+            .iter()
+            .find(|class_level| class_level.class == class)
+            .map(|class_level| class_level.level)
+    }
+
+    /// Returns the subclass the character has chosen in `class`, if any.
+    #[must_use]
+    pub fn subclass(&self, class: Class) -> Option<&Subclass> {
+        self.subclasses
+            .iter()
+            .find(|subclass| subclass.class == class)
+    }
+
+    /// Returns how many options of `kind` the character's classes and subclasses grant at their
+    /// current levels.
+    #[must_use]
+    pub fn granted_options(&self, kind: FeatureOptionKind) -> u8 {
+        self.classes
+            .iter()
+            .map(|class_level| {
+                let from_subclass = self.subclass(class_level.class).map_or(0, |subclass| {
+                    subclass.feature_options_at(kind, class_level.level)
+                });
+                class_level
+                    .class
+                    .feature_options_at(kind, class_level.level)
+                    .saturating_add(from_subclass)
+            })
+            .fold(0, u8::saturating_add)
+    }
+
+    /// Returns how many more options of `kind` the character can choose.
+    #[must_use]
+    pub fn available_options(&self, kind: FeatureOptionKind) -> u8 {
+        let chosen = self
+            .feature_options
+            .iter()
+            .filter(|option| option.kind == kind)
+            .count();
+        let chosen = u8::try_from(chosen).unwrap_or(u8::MAX);
+        self.granted_options(kind).saturating_sub(chosen)
+    }
+
+    /// Chooses `subclass` for its class.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the character unchanged, if the character's level in the
+    /// subclass's class is below [`Class::subclass_level`] under its rules, or it already has a
+    /// subclass in that class.
+    pub fn choose_subclass(&mut self, subclass: Subclass) -> Result<(), &'static str> {
+        let level = self.class_level(subclass.class);
+        if level.is_none_or(|level| level < subclass.class.subclass_level(self.rules)) {
+            return Err("Too early to choose a subclass");
+        }
+        if self.subclass(subclass.class).is_some() {
+            return Err("Class already has a subclass");
+        }
+        self.subclasses.push(subclass);
+        self.grant_resources();
+        Ok(())
+    }
+
+    /// Adds the resources the character's classes and subclasses grant at their current levels,
+    /// such as Rage or Superiority Dice, that it doesn't have yet.
+    fn grant_resources(&mut self) {
+        for class_level in &self.classes {
+            let subclass = self
+                .subclasses
+                .iter()
+                .filter(|subclass| subclass.class == class_level.class)
+                .flat_map(|subclass| subclass.resources.iter());
+            for (level, resource) in class_level.class.resources().iter().chain(subclass) {
+                let granted = self
+                    .resources
+                    .iter()
+                    .any(|existing| existing.name.eq_ignore_ascii_case(&resource.name));
+                if *level <= class_level.level && !granted {
+                    self.resources.push(resource.clone());
+                }
+            }
+        }
+    }
+
+    /// Chooses a feature option, such as a Fighting Style or an Eldritch Invocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the character unchanged, if the character has no options of
+    /// its kind left to choose, doesn't meet its prerequisites, or has already chosen it.
+    pub fn choose_feature_option(&mut self, option: FeatureOption) -> Result<(), &'static str> {
+        if self.available_options(option.kind) == 0 {
+            return Err("No choices of this kind available");
+        }
+        if !option.is_available_to(self) {
+            return Err("Option prerequisites not met");
+        }
+        if self.feature_options.contains(&option) {
+            return Err("Option already taken");
+        }
+        self.feature_options.push(option);
+        Ok(())
+    }
+
+    /// Returns how many Ability Score Improvements the character's class levels grant.
+    #[must_use]
+    pub fn granted_ability_score_improvements(&self) -> u8 {
+        self.classes
+            .iter()
+            .map(|class_level| {
+                let levels = class_level.class.ability_score_improvement_levels();
+                let granted = levels
+                    .iter()
+                    .filter(|&&level| level <= class_level.level.value())
+                    .count();
+                u8::try_from(granted).unwrap_or(u8::MAX)
+            })
+            .fold(0, u8::saturating_add)
+    }
+
+    /// Returns how many Ability Score Improvements the character's class levels grant that it
+    /// has yet to take.
+    #[must_use]
+    pub fn available_ability_score_improvements(&self) -> u8 {
+        self.granted_ability_score_improvements()
+            .saturating_sub(self.ability_score_improvements)
+    }
+
+    /// Returns the decisions the character has yet to make: a subclass for each class at or
+    /// past its [`Class::subclass_level`] under its rules without one, the feature options it has yet to
+    /// choose, and an [`Decision::AbilityScoreImprovement`] for each one not yet taken, even
+    /// if it was granted by an earlier level.
+    #[must_use]
+    pub fn pending_decisions(&self) -> Vec<Decision> {
+        let mut decisions: Vec<_> = self
+            .classes
+            .iter()
+            .filter(|class_level| {
+                class_level.level >= class_level.class.subclass_level(self.rules)
+                    && self.subclass(class_level.class).is_none()
+            })
+            .map(|class_level| Decision::Subclass(class_level.class))
+            .collect();
+        for &kind in FeatureOptionKind::all() {
+            let available = self.available_options(kind);
+            if available > 0 {
+                decisions.push(Decision::FeatureOptions(kind, available));
+            }
+        }
+        for _ in 0..self.available_ability_score_improvements() {
+            decisions.push(Decision::AbilityScoreImprovement);
+        }
+        decisions
+    }
+
+    /// Advances the character by one level in `class`.
+    ///
+    /// The character gains a hit die and hit points: the maximum of the hit die for its first
+    /// level, and the fixed average for each level after, plus its Constitution modifier. Its
+    /// first class sets its saving throw proficiencies, and its spell slots and Pact Magic slots
+    /// grow to those of its new level. It gains the resources its class grants at the new level,
+    /// such as Rage or Ki, and those of its subclass once chosen. The proficiency bonus and
+    /// resources that scale with level follow from the new level.
+    ///
+    /// Returns the decisions still needed, such as a subclass, feature options, or an Ability
+    /// Score Improvement granted by the new level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the character unchanged, if it is already level 20.
+    pub fn level_up(&mut self, class: Class) -> Result<Vec<Decision>, &'static str> {
+        if !self.classes.is_empty() && self.level() >= Level::new(20) {
+            return Err("Character is already level 20");
+        }
+        let die = class.hit_die();
+        let roll = if self.classes.is_empty() {
+            self.saving_throws = class.saving_throws();
+            die.sides()
+        } else {
+            die.sides() / 2 + 1
+        };
+        let constitution = self.ability_scores().constitution.modifier().value();
+        let hit_points = (i16::from(roll) + i16::from(constitution))
+            .max(1)
+            .unsigned_abs();
+        self.health.maximum = self.health.maximum.saturating_add(hit_points);
+        self.health.current = self.health.current.saturating_add(hit_points);
+        self.hit_dice.add(die);
+
+        if let Some(class_level) = self
+            .classes
+            .iter_mut()
+            .find(|class_level| class_level.class == class)
+        {
+            class_level.level = Level::new_clamped(class_level.level.value() + 1);
+        } else {
+            self.classes.push(ClassLevel::new(class, Level::new(1)));
+        }
+
+        let spellcaster_level = self.spellcaster_level();
+        if spellcaster_level > 0 {
+            let slots = SpellSlots::full_caster(Level::new(spellcaster_level));
+            self.spell_slots.set_maximum(&slots);
+        }
+        if let Some(level) = self.class_level(Class::Warlock) {
+            self.pact_slots.set_maximum(&SpellSlots::pact_magic(level));
+        }
+        self.grant_resources();
+
+        Ok(self.pending_decisions())
+    }
+
+    /// Returns the level used to find the character's spell slots, from the levels in its
+    /// classes with the Spellcasting feature.
+    ///
+    /// A character with one such class uses its own table. A multiclass spellcaster adds its
+    /// levels in full casters, such as wizards, and half its levels in paladins and rangers,
+    /// rounded down. Warlock levels grant Pact Magic slots instead.
+    #[must_use]
+    pub fn spellcaster_level(&self) -> u8 {
+        let spellcasters = || {
+            self.classes.iter().filter(|class_level| {
+                class_level.class != Class::Warlock
+                    && class_level.class.spellcasting_ability().is_some()
+            })
+        };
+        let multiclass = spellcasters().count() > 1;
+        spellcasters()
+            .map(|class_level| {
+                let level = class_level.level.value();
+                match class_level.class {
+                    Class::Paladin | Class::Ranger if multiclass => level / 2,
+                    Class::Paladin | Class::Ranger if level >= 2 => level.div_ceil(2),
+                    Class::Paladin | Class::Ranger => 0,
+                    _ => level,
+                }
+            })
+            .fold(0, u8::saturating_add)
+            .min(20)
+    }
+
+    /// Returns the proficiency bonus, which is determined by the character's total level.
+    #[must_use]
+    pub fn proficiency_bonus(&self) -> ProficiencyBonus {
+        self.level().proficiency_bonus()
+    }
+
+    /// Sets the character's species, and gains its traits: its size, speed, darkvision,
+    /// languages, resistances, skill and tool proficiencies, and any fixed ability score
+    /// increases, which only apply under the 2014 rules.
+    ///
+    /// The traits of a previous species are not removed, so this should be called once, when
+    /// the character is created. A species with a speed of 0, such as a subrace on its own
+    /// rather than from [`Species::subrace`], keeps the character's walking speed.
+    pub fn set_species(&mut self, species: Species) {
+        self.size = species.size;
+        if species.speed > 0 {
+            self.speeds.walk = species.speed;
+        }
+        self.senses.darkvision = self.senses.darkvision.max(species.darkvision);
+        add_missing(&mut self.languages, &species.languages);
+        self.resistances |= species.resistances;
+        self.skills.add_proficiencies(species.skills);
+        self.tools.add_proficiencies(species.tools);
+        if let AbilityIncreases::Fixed(increases) = &species.ability_increases
+            && self.rules.species_increase_abilities()
+        {
+            for &(ability, increase) in increases.iter() {
+                let score = self.abilities[ability].value().saturating_add(increase);
+                self.abilities[ability] = AbilityScore::new_clamped(score);
+            }
+        }
+        self.species = Some(species);
+    }
+
+    /// Sets the character's background, and gains its skill and tool proficiencies, languages,
+    /// starting equipment and money, and origin feat, unless feats are turned off by its
+    /// [`VariantRules`].
+    ///
+    /// Returns the background skills the character was already proficient in, such as from
+    /// their class, which the player can replace with other skills. The background's ability
+    /// score increases are chosen separately, with [`Background::increase_abilities`].
+    pub fn set_background(&mut self, background: Background) -> SkillReplacements {
+        let replacements = background.grant_skills(&mut self.skills);
+        self.tools.add_proficiencies(background.tools);
+        add_missing(&mut self.languages, &background.languages);
+        for stack in background.equipment.iter() {
+            self.inventory.add(stack.item.clone(), stack.quantity);
+        }
+        let (gold, rest) = background.gold.convert(Coin::Gold);
+        self.wallet.gold = self
+            .wallet
+            .gold
+            .saturating_add(u32::try_from(gold).unwrap_or(u32::MAX));
+        // The rest is worth less than a gold piece, so it always fits in a wallet.
+        self.wallet
+            .deposit(&Wallet::fewest_coins(rest).unwrap_or_default());
+        if let Some(feat) = &background.feat
+            && self.variant_rules.feats
+            && !self.has_feat(&feat.name)
+        {
+            self.feats.push(feat.clone());
+        }
+        self.background = Some(background);
+        replacements
+    }
+
+    /// Returns `true` if the character has taken the feat with the given name, ignoring case.
+    #[must_use]
+    pub fn has_feat(&self, name: &str) -> bool {
+        self.feats
+            .iter()
+            .any(|feat| feat.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns every magic item whose effects apply: those attuned to, and those worn that
+    /// don't require attunement.
+    pub fn magic_items(&self) -> impl Iterator<Item = &MagicItem> {
+        let worn = self
+            .inventory
+            .equipped
+            .worn()
+            .iter()
+            .filter_map(|item| match item {
+                Item::Magic(item) if !item.attunement.is_required() => Some(item),
+                _ => None,
+            });
+        self.attunements.iter().chain(worn)
+    }
+
+    /// Returns every effect currently applied to the character, including those of feats, magic
+    /// items, armor, and conditions.
+    #[must_use]
+    pub fn active_effects(&self) -> Effects {
+        let mut effects = self.effects.clone();
+        for feat in &self.feats {
+            for &(target, modifier) in feat.effects_for(self.rules) {
+                effects.add(Source::Feature(feat.name.clone()), target, modifier);
+            }
+        }
+        effects.resolve_proficiency_bonus(self.proficiency_bonus());
+        for item in self.magic_items() {
+            effects.add_magic_item(item, &self.abilities);
+        }
+        effects.add_conditions(self.conditions);
+        effects.add_exhaustion(self.exhaustion, self.speeds.walk, self.rules);
+        let strength = self.ability_scores_with(&effects).strength;
+        let encumbrance = CarryingCapacity::new(strength, self.size)
+            .encumbrance(self.inventory.weight(), self.variant_rules.encumbrance);
+        effects.add_encumbrance(encumbrance, self.speeds.walk);
+
+        let equipped = &self.inventory.equipped;
+        if equipped.has_shield() {
+            effects.add(
+                Source::Armor(Armor::Shield),
+                Target::ArmorClass,
+                Modifier::Flat(Armor::Shield.base_armor_class().into()),
+            );
+        }
+        let armor = equipped.armor().into_iter();
+        let shield = equipped.has_shield().then_some(Armor::Shield);
+        for armor in armor.chain(shield) {
+            let source = Source::Armor(armor);
+            if armor.stealth_disadvantage() {
+                effects.add(
+                    source.clone(),
+                    Target::Skill(Skill::Stealth),
+                    Modifier::Disadvantage,
+                );
+            }
+            if armor
+                .strength_requirement()
+                .is_some_and(|requirement| self.abilities.strength < requirement)
+            {
+                effects.add(source.clone(), Target::Speed, Modifier::Flat(-10));
+            }
+            if !self.armor_proficiencies.contains(armor.category()) {
+                for ability in [Ability::Strength, Ability::Dexterity] {
+                    effects
+                        .add(
+                            source.clone(),
+                            Target::AbilityCheck(ability),
+                            Modifier::Disadvantage,
+                        )
+                        .add(
+                            source.clone(),
+                            Target::SavingThrow(ability),
+                            Modifier::Disadvantage,
+                        );
+                }
+                effects.add(source, Target::AttackRolls, Modifier::Disadvantage);
+            }
+        }
+        effects
+    }
+
+    /// Returns the score of `ability`, after any effects are applied.
+    #[must_use]
+    pub fn ability_score(&self, ability: Ability) -> Breakdown {
+        self.ability_score_with(&self.active_effects(), ability)
+    }
+
+    fn ability_score_with(&self, effects: &Effects, ability: Ability) -> Breakdown {
+        let mut breakdown = Breakdown::new();
+        breakdown.add(Source::Base, self.abilities[ability].value().into());
+        effects.apply(&[Target::AbilityScore(ability)], &mut breakdown);
+        breakdown
+    }
+
+    /// Returns the character's ability scores, after any effects are applied.
+    #[must_use]
+    pub fn ability_scores(&self) -> Abilities {
+        self.ability_scores_with(&self.active_effects())
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn ability_scores_with(&self, effects: &Effects) -> Abilities {
+        let mut abilities = Abilities::new();
+        for &ability in Ability::all() {
+            let score = self.ability_score_with(effects, ability).total();
+            abilities[ability] = AbilityScore::new_clamped(score.clamp(0, 30) as u8);
+        }
+        abilities
+    }
+
+    /// Adds the modifier of `ability` to `breakdown`.
+    fn add_modifier(&self, effects: &Effects, ability: Ability, breakdown: &mut Breakdown) {
+        let score = self.ability_scores_with(effects)[ability];
+        breakdown.add(Source::Ability(ability), score.modifier().value().into());
+    }
+
+    /// Adds the proficiency bonus to `breakdown`, or twice it with expertise.
+    ///
+    /// With the proficiency dice variant rule, a roll adds the proficiency die instead, rolled
+    /// twice with expertise.
+    fn add_proficiency(&self, level: SkillLevel, roll: bool, breakdown: &mut Breakdown) {
+        let proficiency_bonus = self.proficiency_bonus();
+        let (source, count) = match level {
+            SkillLevel::Proficient => (Source::Proficiency, 1),
+            SkillLevel::Expertise => (Source::Expertise, 2),
+        };
+        if roll && self.variant_rules.proficiency_dice {
+            breakdown.add_dice(source, Dice::new(count, proficiency_bonus.die()));
+        } else {
+            breakdown.add(source, i16::from(proficiency_bonus.value() * count));
+        }
+    }
+
+    /// Returns the bonus to ability checks with `skill`.
+    #[must_use]
+    pub fn skill(&self, skill: Skill) -> Breakdown {
+        self.skill_check(skill.ability(), skill, true)
+    }
+
+    /// Returns the bonus to ability checks with `skill` that use `ability` in place of the
+    /// skill's usual ability, such as a Strength (Intimidation) check.
+    ///
+    /// The character's proficiency in `skill` applies, along with effects on checks with
+    /// `ability` or with `skill`.
+    #[must_use]
+    pub fn skill_with(&self, ability: Ability, skill: Skill) -> Breakdown {
+        self.skill_check(ability, skill, true)
+    }
+
+    fn skill_check(&self, ability: Ability, skill: Skill, roll: bool) -> Breakdown {
+        let effects = self.active_effects();
+        let mut breakdown = Breakdown::new();
+        self.add_modifier(&effects, ability, &mut breakdown);
+        if let Some(level) = self.skills.get_proficiency(skill) {
+            self.add_proficiency(level, roll, &mut breakdown);
+        }
+        effects.apply(&Target::skill_check_with(ability, skill), &mut breakdown);
+        breakdown
+    }
+
+    /// Returns the bonus to ability checks with the homebrew skill with the given name, or
+    /// `None` if `registry` has no such custom skill.
+    ///
+    /// Effects on checks with the skill's ability apply, as there are no effects that target
+    /// custom skills.
+    #[must_use]
+    pub fn custom_skill(&self, registry: &SkillRegistry, name: &str) -> Option<Breakdown> {
+        let skill = registry.get(name)?;
+        let effects = self.active_effects();
+        let mut breakdown = Breakdown::new();
+        self.add_modifier(&effects, skill.ability, &mut breakdown);
+        if let Some(level) = self.skills.get_custom_proficiency(name) {
+            self.add_proficiency(level, true, &mut breakdown);
+        }
+        effects.apply(&Target::ability_check(skill.ability), &mut breakdown);
+        Some(breakdown)
+    }
+
+    /// Returns the bonus to ability checks with `ability` that don't use a skill.
+    #[must_use]
+    pub fn ability_check(&self, ability: Ability) -> Breakdown {
+        let effects = self.active_effects();
+        let mut breakdown = Breakdown::new();
+        self.add_modifier(&effects, ability, &mut breakdown);
+        effects.apply(&Target::ability_check(ability), &mut breakdown);
+        breakdown
+    }
+
+    /// Returns the bonus to ability checks with `tool`, using `ability`.
+    ///
+    /// If `skill` also applies to the check, and the character is proficient with both the tool
+    /// and the skill, the check has advantage, following the optional rule from Xanathar's
+    /// Guide to Everything.
+    #[must_use]
+    pub fn tool_check(&self, tool: Tool, ability: Ability, skill: Option<Skill>) -> Breakdown {
+        let effects = self.active_effects();
+        let mut breakdown = Breakdown::new();
+        self.add_modifier(&effects, ability, &mut breakdown);
+        if let Some(level) = self.tools.get_proficiency(tool) {
+            self.add_proficiency(level, true, &mut breakdown);
+        }
+        if let Some(skill) = skill
+            && self.tools.mode_with_skill(tool, skill, &self.skills) == RollMode::Advantage
+        {
+            breakdown.grant_advantage(Source::Proficiency);
+        }
+        effects.apply(&Target::ability_check(ability), &mut breakdown);
+        breakdown
+    }
+
+    /// Returns the bonus to saving throws with `ability`.
+    #[must_use]
+    pub fn saving_throw(&self, ability: Ability) -> Breakdown {
+        let effects = self.active_effects();
+        let mut breakdown = Breakdown::new();
+        self.add_modifier(&effects, ability, &mut breakdown);
+        if self.saving_throws.contains(ability) {
+            self.add_proficiency(SkillLevel::Proficient, true, &mut breakdown);
+        }
+        effects.apply(&Target::saving_throw(ability), &mut breakdown);
+        breakdown
+    }
+
+    /// Returns the character's Armor Class.
+    ///
+    /// Without armor, this is 10 plus the Dexterity modifier. With armor, it is the armor's
+    /// base Armor Class plus the Dexterity modifier, up to the armor's limit.
+    #[must_use]
+    pub fn armor_class(&self) -> Breakdown {
+        let effects = self.active_effects();
+        let mut breakdown = Breakdown::new();
+        let dexterity = self
+            .ability_scores_with(&effects)
+            .dexterity
+            .modifier()
+            .value();
+        match self.inventory.equipped.armor() {
+            Some(armor) => {
+                breakdown.add(Source::Armor(armor), armor.base_armor_class().into());
+                let dexterity = match armor.category() {
+                    ArmorCategory::Light => Some(dexterity),
+                    ArmorCategory::Medium => Some(dexterity.min(2)),
+                    ArmorCategory::Heavy | ArmorCategory::Shield => None,
+                };
+                if let Some(dexterity) = dexterity {
+                    breakdown.add(Source::Ability(Ability::Dexterity), dexterity.into());
+                }
+            }
+            None => {
+                breakdown
+                    .add(Source::Base, 10)
+                    .add(Source::Ability(Ability::Dexterity), dexterity.into());
+            }
+        }
+        effects.apply(&[Target::ArmorClass], &mut breakdown);
+        breakdown
+    }
+
+    /// Returns the bonus to initiative, which is a Dexterity check.
+    #[must_use]
+    pub fn initiative(&self) -> Breakdown {
+        let effects = self.active_effects();
+        let mut breakdown = Breakdown::new();
+        self.add_modifier(&effects, Ability::Dexterity, &mut breakdown);
+        effects.apply(
+            &[
+                Target::AllAbilityChecks,
+                Target::AbilityCheck(Ability::Dexterity),
+                Target::Initiative,
+            ],
+            &mut breakdown,
+        );
+        breakdown
+    }
+
+    /// Returns the initiative of the character when it is surprised.
+    ///
+    /// Under the 2024 rules, a surprised creature has disadvantage on initiative. Under the 2014
+    /// rules, its initiative is unaffected, but it can't move or act on its first turn.
+    #[must_use]
+    pub fn surprised_initiative(&self) -> Breakdown {
+        let mut breakdown = self.initiative();
+        if !self.rules.surprise_skips_first_turn() {
+            breakdown.impose_disadvantage(Source::Surprise);
+        }
+        breakdown
+    }
+
+    /// Returns how the character attempts to grapple a creature.
+    ///
+    /// Under the 2014 rules, it makes a Strength (Athletics) check contested by the target.
+    /// Under the 2024 rules, the target makes a saving throw against a DC of 8 plus the
+    /// character's Strength modifier and proficiency bonus.
+    #[must_use]
+    pub fn grapple(&self) -> Grapple {
+        match self.rules {
+            RulesVersion::Phb2014 => Grapple::Contest(self.skill(Skill::Athletics)),
+            RulesVersion::Phb2024 => {
+                let effects = self.active_effects();
+                let mut breakdown = Breakdown::new();
+                breakdown.add(Source::Base, 8);
+                self.add_modifier(&effects, Ability::Strength, &mut breakdown);
+                breakdown.add(Source::Proficiency, self.proficiency_bonus().value().into());
+                Grapple::SavingThrow(breakdown)
+            }
+        }
+    }
+
+    /// Returns the mastery property of `weapon` the character can use, if any.
+    ///
+    /// Weapon mastery only exists under the 2024 rules, for characters with a class that has the
+    /// Weapon Mastery feature and proficiency with the weapon.
+    #[must_use]
+    pub fn weapon_mastery(&self, weapon: Weapon) -> Option<WeaponMastery> {
+        let has_feature = self
+            .classes
+            .iter()
+            .any(|class_level| class_level.class.has_weapon_mastery());
+        if !self.rules.weapon_mastery()
+            || !has_feature
+            || !self.weapon_proficiencies.contains(weapon.category())
+        {
+            return None;
+        }
+        weapon.mastery()
+    }
+
+    /// Returns the bonus to attack rolls with `weapon`.
+    ///
+    /// The proficiency bonus is added if the character is proficient with the weapon's
+    /// category.
+    #[must_use]
+    pub fn attack_bonus(&self, weapon: Weapon) -> Breakdown {
+        let effects = self.active_effects();
+        let mut breakdown = Breakdown::new();
+        let ability = weapon.attack_ability(&self.ability_scores_with(&effects));
+        self.add_modifier(&effects, ability, &mut breakdown);
+        if self.weapon_proficiencies.contains(weapon.category()) {
+            self.add_proficiency(SkillLevel::Proficient, true, &mut breakdown);
+        }
+        effects.apply(&[Target::AttackRolls], &mut breakdown);
+        breakdown
+    }
+
+    /// Returns the bonus to attack rolls with `weapon` against a creature the character flanks
+    /// with an ally.
+    ///
+    /// With the flanking variant rule, melee attacks have advantage. Otherwise, this is the same
+    /// as [`Self::attack_bonus`].
+    #[must_use]
+    pub fn flanking_attack_bonus(&self, weapon: Weapon) -> Breakdown {
+        let mut breakdown = self.attack_bonus(weapon);
+        if self.variant_rules.flanking && weapon.kind() == WeaponKind::Melee {
+            breakdown.grant_advantage(Source::Flanking);
+        }
+        breakdown
+    }
+
+    /// Returns the character's walking speed, in feet.
+    #[must_use]
+    pub fn speed(&self) -> Breakdown {
+        let mut breakdown = Breakdown::new();
+        breakdown.add(
+            Source::Base,
+            i16::try_from(self.speeds.walk).unwrap_or(i16::MAX),
+        );
+        self.active_effects()
+            .apply(&[Target::Speed], &mut breakdown);
+        breakdown
+    }
+
+    /// Returns the passive score for `skill`: 10 plus the skill's bonus, plus 5 with advantage
+    /// or minus 5 with disadvantage.
+    #[must_use]
+    pub fn passive(&self, skill: Skill) -> Breakdown {
+        let mut breakdown = Breakdown::new();
+        breakdown.add(Source::Base, 10);
+        let skill = self.skill_check(skill.ability(), skill, false);
+        for contribution in skill.contributions() {
+            breakdown.add(contribution.source.clone(), contribution.value);
+        }
+        match skill.mode() {
+            RollMode::Normal => {}
+            RollMode::Advantage => {
+                breakdown.add(
+                    Source::Advantage,
+                    RollMode::Advantage.passive_modifier().into(),
+                );
+            }
+            RollMode::Disadvantage => {
+                breakdown.add(
+                    Source::Disadvantage,
+                    RollMode::Disadvantage.passive_modifier().into(),
+                );
+            }
+        }
+        breakdown
+    }
+
+    /// Returns the character's passive Perception, Investigation, and Insight.
+    #[must_use]
+    pub fn passive_scores(&self) -> PassiveScores {
+        let score = |skill| u8::try_from(self.passive(skill).total().max(0)).unwrap_or(u8::MAX);
+        PassiveScores {
+            perception: score(Skill::Perception),
+            investigation: score(Skill::Investigation),
+            insight: score(Skill::Insight),
+        }
+    }
+
+    /// Returns the bonus to spell attack rolls for spells of `class`, or `None` if the
+    /// character has no levels in it or it has no spellcasting.
+    #[must_use]
+    pub fn spell_attack_bonus(&self, class: Class) -> Option<Breakdown> {
+        let ability = class.spellcasting_ability()?;
+        self.class_level(class)?;
+        let effects = self.active_effects();
+        let mut breakdown = Breakdown::new();
+        self.add_modifier(&effects, ability, &mut breakdown);
+        self.add_proficiency(SkillLevel::Proficient, true, &mut breakdown);
+        effects.apply(
+            &[Target::AttackRolls, Target::SpellAttackRolls],
+            &mut breakdown,
+        );
+        Some(breakdown)
+    }
+
+    /// Returns the saving throw DC for spells of `class`, or `None` if the character has no
+    /// levels in it or it has no spellcasting.
+    #[must_use]
+    pub fn spell_save_dc(&self, class: Class) -> Option<Breakdown> {
+        let ability = class.spellcasting_ability()?;
+        self.class_level(class)?;
+        let effects = self.active_effects();
+        let mut breakdown = Breakdown::new();
+        breakdown.add(Source::Base, 8);
+        self.add_modifier(&effects, ability, &mut breakdown);
+        breakdown.add(Source::Proficiency, self.proficiency_bonus().value().into());
+        effects.apply(&[Target::SpellSaveDc], &mut breakdown);
+        Some(breakdown)
+    }
+
+    /// Returns how much the character can carry, push, drag, or lift.
+    #[must_use]
+    pub fn carrying_capacity(&self) -> CarryingCapacity {
+        CarryingCapacity::new(self.ability_scores().strength, self.size)
+    }
+
+    /// Returns how encumbered the character is by everything in its inventory, under the
+    /// encumbrance rule of its [`VariantRules`].
+    #[must_use]
+    pub fn encumbrance(&self) -> Encumbrance {
+        self.carrying_capacity()
+            .encumbrance(self.inventory.weight(), self.variant_rules.encumbrance)
+    }
+
+    /// Returns the resource with the given name, ignoring case.
+    #[must_use]
+    pub fn resource(&self, name: &str) -> Option<&Resource> {
+        self.resources
+            .iter()
+            .find(|resource| resource.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the maximum uses of `resource` for this character.
+    ///
+    /// A resource tied to a class scales with the character's level in that class, and has no
+    /// uses without any levels in it.
+    #[must_use]
+    pub fn resource_maximum(&self, resource: &Resource) -> u8 {
+        let Some(level) = self.resource_level(resource) else {
+            return 0;
+        };
+        resource
+            .maximum
+            .evaluate(level, self.proficiency_bonus(), &self.ability_scores())
+    }
+
+    /// Returns when this character regains expended uses of `resource`, which may change with
+    /// its level, as with [`Resource::improved_recharge`].
+    #[must_use]
+    pub fn resource_recharge(&self, resource: &Resource) -> Recharge {
+        self.resource_level(resource)
+            .map_or(resource.recharge, |level| resource.recharge_at(level))
+    }
+
+    /// Returns the level `resource` scales with: the character's level in its class, or `None`
+    /// without any, or the total character level if it isn't tied to a class.
+    fn resource_level(&self, resource: &Resource) -> Option<Level> {
+        match resource.class {
+            Some(class) => self.class_level(class),
+            None => Some(self.level()),
+        }
+    }
+
+    /// Returns the uses remaining of the resource with the given name, or `None` if the
+    /// character has no such resource.
+    #[must_use]
+    pub fn remaining_uses(&self, name: &str) -> Option<u8> {
+        let resource = self.resource(name)?;
+        Some(resource.remaining(self.resource_maximum(resource)))
+    }
+
+    /// Expends `amount` uses of the resource with the given name, returning the uses remaining.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the character has no such resource, or fewer than `amount` uses
+    /// remain.
+    pub fn spend_resource(&mut self, name: &str, amount: u8) -> Result<u8, &'static str> {
+        let resource = self.resource(name).ok_or("Unknown resource")?;
+        let maximum = self.resource_maximum(resource);
+        let resource = self
+            .resources
+            .iter_mut()
+            .find(|resource| resource.name.eq_ignore_ascii_case(name))
+            .ok_or("Unknown resource")?;
+        Ok(resource.spend(amount, maximum)?.remaining(maximum))
+    }
+
+    /// Spends a hit die of the given size during a short rest, regaining hit points equal to
+    /// the `roll` plus the Constitution modifier (minimum 0).
+    ///
+    /// Returns the number of hit points regained.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no hit dice of that size remain.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn spend_hit_die(&mut self, die: Die, roll: u8) -> Result<u16, &'static str> {
+        self.hit_dice.spend(die)?;
+        let constitution = self.ability_scores().constitution.modifier().value();
+        let roll = i16::from(roll.clamp(1, die.sides()));
+        let regained = (roll + i16::from(constitution)).max(0) as u16;
+        self.health.heal(regained);
+        Ok(regained)
+    }
+
+    /// Returns the charges remaining in the magic item with the given name, ignoring case, or
+    /// `None` if the character has no such item in use or it has no charges.
+    ///
+    /// Only the items of [`Self::magic_items`] can be used.
+    #[must_use]
+    pub fn charges(&self, name: &str) -> Option<u8> {
+        let item = self
+            .magic_items()
+            .find(|item| item.name.eq_ignore_ascii_case(name))?;
+        Some(
+            item.charges?
+                .maximum
+                .saturating_sub(self.charges_expended(&item.name)),
+        )
+    }
+
+    fn charges_expended(&self, name: &str) -> u8 {
+        self.expended_charges
+            .iter()
+            .find(|(item, _)| item.eq_ignore_ascii_case(name))
+            .map_or(0, |&(_, expended)| expended)
+    }
+
+    /// Spends `amount` charges of the magic item with the given name, ignoring case.
+    ///
+    /// Returns the number of charges remaining.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the character has no such item in use, or fewer than `amount`
+    /// charges remain.
+    pub fn spend_charges(&mut self, name: &str, amount: u8) -> Result<u8, &'static str> {
+        let remaining = self.charges(name).ok_or("Unknown item")?;
+        if amount > remaining {
+            return Err("Not enough charges remaining");
+        }
+        let item = self
+            .magic_items()
+            .find(|item| item.name.eq_ignore_ascii_case(name))
+            .map(|item| item.name.clone())
+            .ok_or("Unknown item")?;
+        match self
+            .expended_charges
+            .iter_mut()
+            .find(|(expended, _)| expended.eq_ignore_ascii_case(name))
+        {
+            Some((_, expended)) => *expended += amount,
+            None => self.expended_charges.push((item, amount)),
+        }
+        Ok(remaining - amount)
+    }
+
+    /// Regains the charges of each magic item in use that recharges when `recharges` returns
+    /// `true`, rolling any dice with `roll`.
+    fn recharge_items(
+        &mut self,
+        recharges: impl Fn(Recharge) -> bool,
+        mut roll: impl FnMut(Die) -> u8,
+    ) {
+        let items: Vec<_> = self
+            .magic_items()
+            .filter_map(|item| Some((item.name.clone(), item.charges?)))
+            .filter(|(_, charges)| charges.regain.is_some_and(|regain| recharges(regain.when)))
+            .collect();
+        for (name, charges) in items {
+            let current = charges.maximum.saturating_sub(self.charges_expended(&name));
+            let expended = charges.maximum - charges.recharged(current, &mut roll);
+            self.expended_charges
+                .retain(|(item, _)| !item.eq_ignore_ascii_case(&name));
+            if expended > 0 {
+                self.expended_charges.push((name, expended));
+            }
+        }
+    }
+
+    /// Finishes a rest, regaining every resource and magic item charge that recharges on it,
+    /// rolling any dice for regained charges with `roll`.
+    ///
+    /// Hit dice are spent during a short rest with [`Self::spend_hit_die`]. A long rest also
+    /// restores all hit points, regains half of the character's total hit dice (minimum 1), and
+    /// reduces exhaustion by 1, which assumes the character has had food and water.
+    ///
+    /// A long rest is assumed to last through the night, so it also regains everything that
+    /// recharges at dawn, as with [`Self::dawn`].
+    pub fn rest(&mut self, rest: Rest, mut roll: impl FnMut(Die) -> u8) {
+        self.spell_slots.rest(rest);
+        self.pact_slots.rest(rest);
+        self.recharge_resources(|when| rest.recharges(when));
+        self.recharge_items(|when| rest.recharges(when), &mut roll);
+        if rest == Rest::Long {
+            self.dawn(roll);
+            self.health.current = self.health.maximum;
+            if self.variant_rules.regain_all_hit_dice {
+                self.hit_dice.regain_all();
+            } else {
+                self.hit_dice.regain((self.hit_dice.total() / 2).max(1));
+            }
+            self.exhaustion = self.exhaustion.saturating_sub(1);
+            if self.exhaustion == 0 {
+                self.conditions.remove(Condition::Exhaustion);
+            }
+        }
+    }
+
+    /// Regains every resource and magic item charge that recharges at dawn, rolling any dice
+    /// for regained charges with `roll`.
+    pub fn dawn(&mut self, roll: impl FnMut(Die) -> u8) {
+        self.recharge(Recharge::Dawn, roll);
+    }
+
+    /// Regains every resource and magic item charge that recharges at dusk, rolling any dice
+    /// for regained charges with `roll`.
+    pub fn dusk(&mut self, roll: impl FnMut(Die) -> u8) {
+        self.recharge(Recharge::Dusk, roll);
+    }
+
+    fn recharge(&mut self, recharge: Recharge, roll: impl FnMut(Die) -> u8) {
+        self.recharge_resources(|when| when == recharge);
+        self.recharge_items(|when| when == recharge, roll);
+    }
+
+    /// Regains every use of each resource whose recharge passes `recharges`.
+    fn recharge_resources(&mut self, recharges: impl Fn(Recharge) -> bool) {
+        let regained: Vec<bool> = self
+            .resources
+            .iter()
+            .map(|resource| recharges(self.resource_recharge(resource)))
+            .collect();
+        for (resource, regained) in self.resources.iter_mut().zip(regained) {
+            if regained {
+                resource.restore_all();
+            }
+        }
+    }
+}
+
+/// Adds each of `names` to `list`, skipping those already in it.
+fn add_missing(list: &mut Vec<Cow<'static, str>>, names: &[Cow<'static, str>]) {
+    for name in names {
+        if !list.contains(name) {
+            list.push(name.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        character::ResourceMaximum,
+        core::{CustomSkill, Dice, Die},
+        equipment::{Attunee, EncumbranceRule},
+    };
+    use alloc::string::ToString;
+
+    fn cleric() -> Character {
+        let mut cleric = Character::new("Brother Alaric");
+        cleric
+            .classes
+            .push(ClassLevel::new(Class::Cleric, Level::new(4)));
+        cleric
+            .classes
+            .push(ClassLevel::new(Class::Fighter, Level::new(1)));
+        cleric.abilities.dexterity = AbilityScore::new(14);
+        cleric.abilities.wisdom = AbilityScore::new(16);
+        cleric.saving_throws = Class::Cleric.saving_throws();
+        cleric.armor_proficiencies = ArmorCategory::Light | ArmorCategory::Medium;
+        cleric
+    }
+
+    #[test]
+    fn level() {
+        let cleric = cleric();
+        assert_eq!(cleric.level(), Level::new(5));
+        assert_eq!(cleric.class_level(Class::Fighter), Some(Level::new(1)));
+        assert_eq!(cleric.class_level(Class::Wizard), None);
+        assert_eq!(cleric.proficiency_bonus(), ProficiencyBonus::new(3));
+    }
+
+    #[test]
+    fn armor_class() {
+        let mut cleric = cleric();
+        cleric.inventory.equipped.don(Armor::HalfPlate).unwrap();
+        cleric.inventory.equipped.don(Armor::Shield).unwrap();
+        let armor_class = cleric.armor_class();
+        assert_eq!(armor_class.total(), 19);
+        assert_eq!(
+            i16::from(cleric.inventory.equipped.armor_class(&cleric.abilities)),
+            armor_class.total()
+        );
+        assert_eq!(
+            armor_class.to_string(),
+            "Half Plate Armor +15, Dexterity +2, Shield +2"
+        );
+
+        // Not proficient with shields.
+        let dexterity = cleric.saving_throw(Ability::Dexterity);
+        assert_eq!(dexterity.disadvantage(), &[Source::Armor(Armor::Shield)]);
+        assert_eq!(dexterity.mode(), RollMode::Disadvantage);
+        assert_eq!(cleric.skill(Skill::Stealth).disadvantage().len(), 2);
+    }
+
+    #[test]
+    fn magic_items() {
+        let mut cleric = cleric();
+        let attunee = Attunee {
+            classes: &[Class::Cleric],
+            ..Attunee::default()
+        };
+        cleric
+            .attunements
+            .attune(MagicItem::RING_OF_PROTECTION, &attunee)
+            .unwrap();
+        assert_eq!(cleric.armor_class().total(), 13);
+        let wisdom = cleric.saving_throw(Ability::Wisdom);
+        assert_eq!(wisdom.total(), 7);
+        assert_eq!(
+            wisdom.to_string(),
+            "Wisdom +3, Proficiency +3, Ring of Protection +1"
+        );
+    }
+
+    #[test]
+    fn passive_and_spellcasting() {
+        let mut cleric = cleric();
+        cleric.skills.set_proficient(Skill::Perception);
+        cleric.inventory.equipped.don(Armor::HalfPlate).unwrap();
+
+        assert_eq!(cleric.passive(Skill::Perception).total(), 16);
+        assert_eq!(cleric.passive_scores().insight, 13);
+        // Half plate imposes disadvantage on Stealth.
+        assert_eq!(cleric.passive(Skill::Stealth).total(), 7);
+
+        assert_eq!(cleric.spell_save_dc(Class::Cleric).unwrap().total(), 14);
+        assert_eq!(cleric.spell_attack_bonus(Class::Cleric).unwrap().total(), 6);
+        assert!(cleric.spell_save_dc(Class::Fighter).is_none());
+        assert!(cleric.spell_save_dc(Class::Wizard).is_none());
+    }
+
+    #[test]
+    fn effects() {
+        let mut cleric = cleric();
+        cleric.abilities.strength = AbilityScore::new(12);
+        cleric.weapon_proficiencies = WeaponCategory::Simple.into();
+        cleric.inventory.equipped.don(Armor::Plate).unwrap();
+        assert_eq!(cleric.speed().total(), 20);
+        assert_eq!(
+            cleric.attack_bonus(Weapon::Mace).mode(),
+            RollMode::Disadvantage
+        );
+
+        cleric.inventory.equipped.doff_armor();
+        cleric.effects.add(
+            Source::Spell("Bless".into()),
+            Target::AttackRolls,
+            Modifier::Dice(Dice::new(1, Die::D4)),
+        );
+        let attack = cleric.attack_bonus(Weapon::Mace);
+        assert_eq!(attack.total(), 4);
+        assert_eq!(
+            attack.to_string(),
+            "Strength +1, Proficiency +3, Bless +1d4"
+        );
+        assert_eq!(cleric.attack_bonus(Weapon::Longsword).total(), 1);
+
+        cleric.conditions = Condition::Grappled | Condition::Poisoned;
+        assert_eq!(cleric.speed().total(), 0);
+        assert_eq!(cleric.initiative().mode(), RollMode::Disadvantage);
+        assert_eq!(
+            cleric.saving_throw(Ability::Wisdom).mode(),
+            RollMode::Normal
+        );
+    }
+
+    #[test]
+    fn species() {
+        let mut character = Character::new("Merric");
+        character.skills.set_expertise(Skill::Perception);
+        character.set_species(Species::ELF.subrace("High Elf").unwrap());
+        assert_eq!(character.abilities.dexterity, AbilityScore::new(12));
+        assert_eq!(character.abilities.intelligence, AbilityScore::new(11));
+        assert_eq!(character.senses.darkvision, 60);
+        assert_eq!(character.languages, ["Common", "Elvish"]);
+        assert!(character.skills.has_expertise(Skill::Perception));
+
+        let mut character = Character::new("Krusk");
+        character.set_species(Species::HALF_ORC);
+        assert!(character.skills.is_proficient(Skill::Intimidation));
+        character.set_species(Species::HALFLING);
+        assert_eq!(character.size, Size::Small);
+        assert_eq!(character.speed().total(), 25);
+        assert_eq!(character.species.unwrap().name, "Halfling");
+
+        let mut character = Character::new("Grond");
+        character.set_species(Species::ORC);
+        assert_eq!(character.abilities, Abilities::new());
+        assert_eq!(character.senses.darkvision, 120);
+
+        let mut character = Character::new("Eldon");
+        character.set_species(Species::GNOME.subraces[0].clone());
+        assert_eq!(character.speeds.walk, 30);
+    }
+
+    #[test]
+    fn background() {
+        let mut rogue = Character::new("Vex");
+        rogue
+            .skills
+            .add_proficiencies(Skill::Stealth | Skill::Acrobatics);
+        let mut replacements = rogue.set_background(Background::CRIMINAL);
+        assert_eq!(replacements.duplicates(), Skill::Stealth);
+        replacements
+            .replace(Skill::Stealth, Skill::Perception, &mut rogue.skills)
+            .unwrap();
+        assert!(rogue.skills.is_proficient(Skill::SleightOfHand));
+        assert!(rogue.skills.is_proficient(Skill::Perception));
+
+        assert!(rogue.has_feat("Alert"));
+        assert!(rogue.tools.is_proficient(Tool::ThievesTools));
+        assert_eq!(rogue.inventory.quantity(&Weapon::Dagger.into()), 2);
+        assert_eq!(rogue.wallet.gold, 16);
+        assert_eq!(rogue.wallet.count(), 16);
+    }
+
+    #[test]
+    fn tool_check() {
+        let mut rogue = Character::new("Vex");
+        rogue.abilities.dexterity = AbilityScore::new(16);
+        rogue.tools.set_expertise(Tool::ThievesTools);
+        let check = rogue.tool_check(Tool::ThievesTools, Ability::Dexterity, None);
+        assert_eq!(check.to_string(), "Dexterity +3, Expertise +4");
+        assert_eq!(check.mode(), RollMode::Normal);
+
+        let check = rogue.tool_check(
+            Tool::ThievesTools,
+            Ability::Dexterity,
+            Some(Skill::SleightOfHand),
+        );
+        assert_eq!(check.mode(), RollMode::Normal);
+        rogue.skills.set_proficient(Skill::SleightOfHand);
+        let check = rogue.tool_check(
+            Tool::ThievesTools,
+            Ability::Dexterity,
+            Some(Skill::SleightOfHand),
+        );
+        assert_eq!(check.mode(), RollMode::Advantage);
+        assert_eq!(check.total(), 7);
+    }
+
+    #[test]
+    fn skill_with() {
+        let mut fighter = Character::new("Brakka");
+        fighter.abilities.strength = AbilityScore::new(16);
+        fighter.abilities.charisma = AbilityScore::new(8);
+        fighter.skills.set_proficient(Skill::Intimidation);
+        assert_eq!(fighter.skill(Skill::Intimidation).total(), 1);
+
+        let check = fighter.skill_with(Ability::Strength, Skill::Intimidation);
+        assert_eq!(check.to_string(), "Strength +3, Proficiency +2");
+    }
+
+    #[test]
+    fn custom_skill() {
+        let mut registry = SkillRegistry::new();
+        registry
+            .register(CustomSkill::new("Engineering", Ability::Intelligence))
+            .unwrap();
+        let mut artificer = Character::new("Tinker");
+        artificer.abilities.intelligence = AbilityScore::new(16);
+        let check = artificer.custom_skill(&registry, "Engineering").unwrap();
+        assert_eq!(check.total(), 3);
+        assert!(artificer.custom_skill(&registry, "Stealth").is_none());
+
+        artificer
+            .skills
+            .set_custom_proficiency(&registry, "Engineering", SkillLevel::Proficient)
+            .unwrap();
+        let check = artificer.custom_skill(&registry, "Engineering").unwrap();
+        assert_eq!(check.to_string(), "Intelligence +3, Proficiency +2");
+    }
+
+    #[test]
+    fn level_up() {
+        let mut paladin = Character::new("Sir Aldric");
+        paladin.abilities.constitution = AbilityScore::new(14);
+        assert_eq!(paladin.level_up(Class::Paladin), Ok(Vec::new()));
+        assert_eq!(paladin.health.maximum, 12);
+        assert_eq!(paladin.saving_throws, Class::Paladin.saving_throws());
+        assert_eq!(paladin.spellcaster_level(), 0);
+        assert_eq!(
+            paladin.level_up(Class::Paladin),
+            Ok([Decision::FeatureOptions(
+                FeatureOptionKind::FightingStyle,
+                1
+            )]
+            .into())
+        );
+        assert_eq!(paladin.health.maximum, 20);
+        assert_eq!(paladin.spell_slots.maximum(1), 2);
+        paladin.level_up(Class::Paladin).unwrap();
+        assert_eq!(paladin.spellcaster_level(), 2);
+
+        paladin.spell_slots.expend(1).unwrap();
+        paladin.level_up(Class::Wizard).unwrap();
+        assert_eq!(paladin.saving_throws, Class::Paladin.saving_throws());
+        assert_eq!(paladin.hit_dice.total(), 4);
+        assert_eq!(paladin.spellcaster_level(), 2);
+        assert_eq!(paladin.spell_slots.available(1), 2);
+
+        for _ in 0..16 {
+            paladin.level_up(Class::Wizard).unwrap();
+        }
+        assert_eq!(
+            paladin.level_up(Class::Wizard),
+            Err("Character is already level 20")
+        );
+        assert_eq!(paladin.level(), Level::new(20));
+    }
+
+    #[test]
+    fn granted_resources() {
+        let mut monk = Character::new("Li");
+        monk.level_up(Class::Monk).unwrap();
+        assert_eq!(monk.resource("Ki"), None);
+        monk.level_up(Class::Monk).unwrap();
+        assert_eq!(monk.resource("Ki"), Some(&Resource::KI));
+        assert_eq!(monk.remaining_uses("Ki"), Some(2));
+
+        let mut fighter = Character::new("Brakka");
+        for _ in 0..3 {
+            fighter.level_up(Class::Fighter).unwrap();
+        }
+        fighter.level_up(Class::Barbarian).unwrap();
+        assert_eq!(fighter.resource("Superiority Dice"), None);
+        assert_eq!(fighter.remaining_uses("Rage"), Some(2));
+        fighter.choose_subclass(Subclass::BATTLE_MASTER).unwrap();
+        assert_eq!(fighter.remaining_uses("Superiority Dice"), Some(4));
+        fighter.level_up(Class::Fighter).unwrap();
+        assert_eq!(fighter.resources.len(), 2);
+    }
+
+    #[test]
+    fn rules_version() {
+        let mut fighter = Character::new("Brakka");
+        fighter.rules = RulesVersion::Phb2024;
+        fighter.set_species(Species::HALF_ORC);
+        assert_eq!(fighter.abilities, Abilities::new());
+
+        fighter
+            .classes
+            .push(ClassLevel::new(Class::Fighter, Level::new(1)));
+        fighter.weapon_proficiencies = WeaponCategory::Simple | WeaponCategory::Martial;
+        fighter.abilities.strength = AbilityScore::new(16);
+        assert_eq!(
+            fighter.weapon_mastery(Weapon::Longsword),
+            Some(WeaponMastery::Sap)
+        );
+        assert_eq!(
+            fighter.surprised_initiative().mode(),
+            RollMode::Disadvantage
+        );
+        let Grapple::SavingThrow(dc) = fighter.grapple() else {
+            panic!("expected a saving throw");
+        };
+        assert_eq!(dc.to_string(), "Base +8, Strength +3, Proficiency +2");
+
+        fighter.exhaustion = 1;
+        assert_eq!(fighter.speed().total(), 25);
+        assert_eq!(fighter.ability_check(Ability::Wisdom).total(), -2);
+
+        fighter.rules = RulesVersion::Phb2014;
+        assert_eq!(fighter.weapon_mastery(Weapon::Longsword), None);
+        assert_eq!(fighter.surprised_initiative(), fighter.initiative());
+        assert!(matches!(fighter.grapple(), Grapple::Contest(_)));
+        assert_eq!(fighter.speed().total(), 30);
+        assert_eq!(
+            fighter.ability_check(Ability::Wisdom).mode(),
+            RollMode::Disadvantage
+        );
+    }
+
+    #[test]
+    fn variant_rules() {
+        let mut rogue = Character::new("Vex");
+        rogue
+            .classes
+            .push(ClassLevel::new(Class::Rogue, Level::new(5)));
+        rogue.abilities.dexterity = AbilityScore::new(16);
+        rogue.skills.set_expertise(Skill::Stealth);
+        rogue.weapon_proficiencies = WeaponCategory::Simple.into();
+        rogue.variant_rules.proficiency_dice = true;
+        rogue.variant_rules.flanking = true;
+        assert_eq!(
+            rogue.skill(Skill::Stealth).to_string(),
+            "Dexterity +3, Expertise +2d6"
+        );
+        assert_eq!(rogue.passive(Skill::Stealth).total(), 19);
+        assert_eq!(
+            rogue.flanking_attack_bonus(Weapon::Dagger).mode(),
+            RollMode::Advantage
+        );
+        assert_eq!(
+            rogue.flanking_attack_bonus(Weapon::Shortbow).mode(),
+            RollMode::Normal
+        );
+
+        rogue.variant_rules.feats = false;
+        let _ = rogue.set_background(Background::CRIMINAL);
+        assert!(!rogue.has_feat("Alert"));
+
+        rogue.variant_rules.encumbrance = EncumbranceRule::Variant;
+        rogue.inventory.add(Item::Armor(Armor::Plate), 1);
+        assert_eq!(rogue.encumbrance(), Encumbrance::Encumbered);
+        assert_eq!(rogue.speed().to_string(), "Base +30, Encumbrance -10");
+
+        rogue.hit_dice = HitDice::from_classes(&rogue.classes);
+        for _ in 0..4 {
+            rogue.spend_hit_die(Die::D8, 4).unwrap();
+        }
+        rogue.variant_rules.regain_all_hit_dice = true;
+        rogue.rest(Rest::Long, |_| 1);
+        assert_eq!(rogue.hit_dice.remaining(), 5);
+    }
+
+    #[test]
+    fn feats() {
+        let mut cleric = cleric();
+        cleric.feats.push(Feat::ALERT);
+        assert!(cleric.has_feat("alert"));
+        assert_eq!(cleric.initiative().to_string(), "Dexterity +2, Alert +5");
+
+        cleric.rules = RulesVersion::Phb2024;
+        assert_eq!(cleric.initiative().to_string(), "Dexterity +2, Alert +3");
+    }
+
+    #[test]
+    fn rests() {
+        let mut cleric = cleric();
+        cleric.abilities.constitution = AbilityScore::new(8);
+        cleric.hit_dice = HitDice::from_classes(&cleric.classes);
+        cleric.health = Health::new(30);
+        cleric.spell_slots = SpellSlots::full_caster(Level::new(4));
+        cleric.exhaustion = 1;
+        cleric.conditions = Condition::Exhaustion.into();
+
+        cleric.health.damage(25);
+        assert_eq!(cleric.spend_hit_die(Die::D8, 1), Ok(0));
+        assert_eq!(cleric.spend_hit_die(Die::D10, 9), Ok(8));
+        assert!(cleric.spend_hit_die(Die::D10, 9).is_err());
+        cleric.spell_slots.expend(1).unwrap();
+
+        cleric.rest(Rest::Short, |_| 1);
+        assert_eq!(cleric.health.current, 13);
+        assert_eq!(cleric.spell_slots.available(1), 3);
+
+        cleric.rest(Rest::Long, |_| 1);
+        assert_eq!(cleric.health.current, 30);
+        assert_eq!(cleric.spell_slots.available(1), 4);
+        assert_eq!(cleric.hit_dice.remaining(), 5);
+        assert_eq!(cleric.exhaustion, 0);
+        assert!(cleric.conditions.is_empty());
+    }
+
+    #[test]
+    fn resources() {
+        let mut cleric = cleric();
+        cleric.abilities.wisdom = AbilityScore::new(18);
+        cleric.resources.push(Resource::CHANNEL_DIVINITY);
+        cleric.resources.push(Resource::new(
+            "Harness Divine Power",
+            ResourceMaximum::AbilityModifier {
+                ability: Ability::Wisdom,
+                minimum: 1,
+            },
+            Recharge::Dawn,
+        ));
+        cleric.resources.push(Resource::SUPERIORITY_DICE);
+
+        assert_eq!(cleric.remaining_uses("channel divinity"), Some(1));
+        assert_eq!(cleric.remaining_uses("Superiority Dice"), Some(0));
+        assert_eq!(cleric.remaining_uses("Rage"), None);
+
+        assert_eq!(cleric.spend_resource("Channel Divinity", 1), Ok(0));
+        assert!(cleric.spend_resource("Channel Divinity", 1).is_err());
+        assert_eq!(cleric.spend_resource("Ki", 1), Err("Unknown resource"));
+        assert_eq!(cleric.spend_resource("Harness Divine Power", 4), Ok(0));
+
+        cleric.rest(Rest::Short, |_| 1);
+        assert_eq!(cleric.remaining_uses("Channel Divinity"), Some(1));
+        assert_eq!(cleric.remaining_uses("Harness Divine Power"), Some(0));
+        cleric.dawn(|_| 1);
+        assert_eq!(cleric.remaining_uses("Harness Divine Power"), Some(4));
+
+        assert_eq!(cleric.spend_resource("Harness Divine Power", 4), Ok(0));
+        cleric.rest(Rest::Long, |_| 1);
+        assert_eq!(cleric.remaining_uses("Harness Divine Power"), Some(4));
+    }
+
+    #[test]
+    fn improved_recharge() {
+        let mut bard = Character::new("Viola");
+        for _ in 0..4 {
+            bard.level_up(Class::Bard).unwrap();
+        }
+        bard.spend_resource("Bardic Inspiration", 1).unwrap();
+        bard.rest(Rest::Short, |_| 1);
+        assert_eq!(bard.remaining_uses("Bardic Inspiration"), Some(0));
+
+        bard.level_up(Class::Bard).unwrap();
+        assert_eq!(
+            bard.resource_recharge(&Resource::BARDIC_INSPIRATION),
+            Recharge::ShortRest
+        );
+        bard.rest(Rest::Short, |_| 1);
+        assert_eq!(bard.remaining_uses("Bardic Inspiration"), Some(1));
+    }
+
+    #[test]
+    fn item_charges() {
+        let mut wizard = Character::new("Elminster");
+        let wand = MagicItem::WAND_OF_MAGIC_MISSILES;
+        assert_eq!(wizard.charges(&wand.name), None);
+
+        wizard
+            .inventory
+            .equipped
+            .wear(Item::Magic(wand.clone()))
+            .unwrap();
+        assert_eq!(wizard.charges("wand of magic missiles"), Some(7));
+        assert_eq!(wizard.spend_charges(&wand.name, 5), Ok(2));
+        assert_eq!(
+            wizard.spend_charges(&wand.name, 3),
+            Err("Not enough charges remaining")
+        );
+
+        wizard.rest(Rest::Short, |_| 6);
+        assert_eq!(wizard.charges(&wand.name), Some(2));
+        wizard.rest(Rest::Long, |_| 3);
+        assert_eq!(wizard.charges(&wand.name), Some(6));
+        wizard.dusk(|_| 6);
+        assert_eq!(wizard.charges(&wand.name), Some(6));
+        wizard.dawn(|_| 6);
+        assert_eq!(wizard.charges(&wand.name), Some(7));
+        assert!(wizard.expended_charges.is_empty());
+    }
+}
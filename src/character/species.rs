@@ -0,0 +1,455 @@
+use crate::{
+    core::{Ability, DamageType, Level, Recharge, Skill, Tool},
+    creature::Size,
+};
+use alloc::borrow::Cow;
+use core::fmt::Display;
+use enumflags2::{BitFlags, make_bitflags};
+
+/// Where a character's ability score increases come from at character creation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AbilityIncreases {
+    /// Fixed increases granted by the species, as in the 2014 rules.
+    Fixed(Cow<'static, [(Ability, u8)]>),
+
+    /// Increases chosen from the character's background instead, as in the 2024 rules.
+    FromBackground,
+}
+
+/// A spell a species can cast without a spellcasting feature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InnateSpell {
+    /// The name of the spell, such as "Darkness".
+    pub name: Cow<'static, str>,
+
+    /// The character level at which the spell is gained.
+    pub level: Level,
+
+    /// When the spell can be cast again without a spell slot, or `None` for a cantrip that can
+    /// be cast at will.
+    pub recharge: Option<Recharge>,
+}
+
+impl InnateSpell {
+    /// Creates a new `InnateSpell`.
+    #[must_use]
+    pub const fn new(name: &'static str, level: Level, recharge: Option<Recharge>) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            level,
+            recharge,
+        }
+    }
+}
+
+/// A species (or race), or one of its subraces, and the traits it grants.
+///
+/// A subrace is itself a `Species` listed in [`Species::subraces`], and only needs the traits it
+/// adds; [`Species::subrace`] and [`Species::with_subrace`] combine it with its parent species. Species from the 2014
+/// System Reference Document have [`AbilityIncreases::Fixed`] ability score increases, and those
+/// introduced in 2024 take theirs from the background.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::Species;
+/// use dnd::core::{Ability, DamageType, Level};
+/// use dnd::creature::Size;
+///
+/// let dwarf = Species::DWARF.subrace("Hill Dwarf").unwrap();
+/// assert_eq!(dwarf.name, "Hill Dwarf");
+/// assert_eq!(dwarf.size, Size::Medium);
+/// assert_eq!(dwarf.speed, 25);
+/// assert_eq!(dwarf.darkvision, 60);
+/// assert_eq!(dwarf.ability_increase(Ability::Constitution), 2);
+/// assert_eq!(dwarf.ability_increase(Ability::Wisdom), 1);
+/// assert!(dwarf.resistances.contains(DamageType::Poison));
+///
+/// let tiefling = Species::TIEFLING;
+/// let spells: Vec<_> = tiefling.spells_at(Level::new(3)).map(|spell| &spell.name).collect();
+/// assert_eq!(spells, ["Thaumaturgy", "Hellish Rebuke"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::unsafe_derive_deserialize)] // `make_bitflags!` expands to an `unsafe` block.
+pub struct Species {
+    /// The name of the species, such as "Dwarf" or "Hill Dwarf".
+    pub name: Cow<'static, str>,
+
+    /// The size of members of the species.
+    pub size: Size,
+
+    /// The walking speed, in feet, or 0 for a subrace that doesn't change it.
+    pub speed: u16,
+
+    /// The range of darkvision, in feet, or 0 without it.
+    pub darkvision: u16,
+
+    /// The ability score increases the species grants.
+    pub ability_increases: AbilityIncreases,
+
+    /// The languages members of the species know.
+    pub languages: Cow<'static, [Cow<'static, str>]>,
+
+    /// The ability used to cast innate spells, if any.
+    pub spellcasting_ability: Option<Ability>,
+
+    /// The spells members of the species can cast innately.
+    pub spells: Cow<'static, [InnateSpell]>,
+
+    /// The damage types members of the species resist.
+    pub resistances: BitFlags<DamageType>,
+
+    /// The skills members of the species are proficient in.
+    pub skills: BitFlags<Skill>,
+
+    /// The tools members of the species are proficient with.
+    pub tools: BitFlags<Tool>,
+
+    /// The subraces of the species, each listing only the traits it adds.
+    pub subraces: Cow<'static, [Species]>,
+}
+
+impl Species {
+    const BASE: Self = Self {
+        name: Cow::Borrowed(""),
+        size: Size::Medium,
+        speed: 30,
+        darkvision: 0,
+        ability_increases: AbilityIncreases::FromBackground,
+        languages: Cow::Borrowed(&[]),
+        spellcasting_ability: None,
+        spells: Cow::Borrowed(&[]),
+        resistances: BitFlags::EMPTY,
+        skills: BitFlags::EMPTY,
+        tools: BitFlags::EMPTY,
+        subraces: Cow::Borrowed(&[]),
+    };
+
+    /// Proud descendants of dragons, with a breath weapon and a resistance from their draconic
+    /// ancestry.
+    ///
+    /// Each ancestry is a subrace named for its dragon, such as "Red Dragonborn", that grants
+    /// resistance to the damage type of its breath weapon.
+    pub const DRAGONBORN: Self = Self {
+        name: Cow::Borrowed("Dragonborn"),
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[
+            (Ability::Strength, 2),
+            (Ability::Charisma, 1),
+        ])),
+        languages: Cow::Borrowed(&[Cow::Borrowed("Common"), Cow::Borrowed("Draconic")]),
+        subraces: Cow::Borrowed(&[
+            Self::ancestry("Black Dragonborn", make_bitflags!(DamageType::{Acid})),
+            Self::ancestry("Blue Dragonborn", make_bitflags!(DamageType::{Lightning})),
+            Self::ancestry("Brass Dragonborn", make_bitflags!(DamageType::{Fire})),
+            Self::ancestry("Bronze Dragonborn", make_bitflags!(DamageType::{Lightning})),
+            Self::ancestry("Copper Dragonborn", make_bitflags!(DamageType::{Acid})),
+            Self::ancestry("Gold Dragonborn", make_bitflags!(DamageType::{Fire})),
+            Self::ancestry("Green Dragonborn", make_bitflags!(DamageType::{Poison})),
+            Self::ancestry("Red Dragonborn", make_bitflags!(DamageType::{Fire})),
+            Self::ancestry("Silver Dragonborn", make_bitflags!(DamageType::{Cold})),
+            Self::ancestry("White Dragonborn", make_bitflags!(DamageType::{Cold})),
+        ]),
+        ..Self::BASE
+    };
+
+    const fn ancestry(name: &'static str, resistances: BitFlags<DamageType>) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            speed: 0,
+            ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[])),
+            resistances,
+            ..Self::BASE
+        }
+    }
+
+    /// Bold and hardy, with dwarven resilience against poison.
+    pub const DWARF: Self = Self {
+        name: Cow::Borrowed("Dwarf"),
+        speed: 25,
+        darkvision: 60,
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[(Ability::Constitution, 2)])),
+        languages: Cow::Borrowed(&[Cow::Borrowed("Common"), Cow::Borrowed("Dwarvish")]),
+        resistances: make_bitflags!(DamageType::{Poison}),
+        subraces: Cow::Borrowed(&[Self::HILL_DWARF]),
+        ..Self::BASE
+    };
+
+    /// A dwarf with keen senses and deep intuition.
+    const HILL_DWARF: Self = Self {
+        name: Cow::Borrowed("Hill Dwarf"),
+        speed: 0,
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[(Ability::Wisdom, 1)])),
+        ..Self::BASE
+    };
+
+    /// Graceful and long-lived, with keen senses.
+    pub const ELF: Self = Self {
+        name: Cow::Borrowed("Elf"),
+        darkvision: 60,
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[(Ability::Dexterity, 2)])),
+        languages: Cow::Borrowed(&[Cow::Borrowed("Common"), Cow::Borrowed("Elvish")]),
+        skills: make_bitflags!(Skill::{Perception}),
+        subraces: Cow::Borrowed(&[Self::HIGH_ELF]),
+        ..Self::BASE
+    };
+
+    /// An elf with a keen mind and a mastery of at least the basics of magic.
+    const HIGH_ELF: Self = Self {
+        name: Cow::Borrowed("High Elf"),
+        speed: 0,
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[(Ability::Intelligence, 1)])),
+        ..Self::BASE
+    };
+
+    /// Small, curious, and cunning, with a natural resistance to magic.
+    pub const GNOME: Self = Self {
+        name: Cow::Borrowed("Gnome"),
+        size: Size::Small,
+        speed: 25,
+        darkvision: 60,
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[(Ability::Intelligence, 2)])),
+        languages: Cow::Borrowed(&[Cow::Borrowed("Common"), Cow::Borrowed("Gnomish")]),
+        subraces: Cow::Borrowed(&[Self::ROCK_GNOME]),
+        ..Self::BASE
+    };
+
+    /// A gnome with a knack for tinkering and artifice.
+    const ROCK_GNOME: Self = Self {
+        name: Cow::Borrowed("Rock Gnome"),
+        speed: 0,
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[(Ability::Constitution, 1)])),
+        tools: make_bitflags!(Tool::{TinkersTools}),
+        ..Self::BASE
+    };
+
+    /// Walking in two worlds, with the charisma of humans and the grace of elves.
+    ///
+    /// Half-elves also increase two other ability scores of the player's choice by 1, and gain
+    /// two skill proficiencies and a language of their choice, which aren't included here.
+    pub const HALF_ELF: Self = Self {
+        name: Cow::Borrowed("Half-Elf"),
+        darkvision: 60,
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[(Ability::Charisma, 2)])),
+        languages: Cow::Borrowed(&[Cow::Borrowed("Common"), Cow::Borrowed("Elvish")]),
+        ..Self::BASE
+    };
+
+    /// Small and nimble, and uncommonly lucky.
+    pub const HALFLING: Self = Self {
+        name: Cow::Borrowed("Halfling"),
+        size: Size::Small,
+        speed: 25,
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[(Ability::Dexterity, 2)])),
+        languages: Cow::Borrowed(&[Cow::Borrowed("Common"), Cow::Borrowed("Halfling")]),
+        subraces: Cow::Borrowed(&[Self::LIGHTFOOT_HALFLING]),
+        ..Self::BASE
+    };
+
+    /// A halfling able to hide behind larger creatures.
+    const LIGHTFOOT_HALFLING: Self = Self {
+        name: Cow::Borrowed("Lightfoot Halfling"),
+        speed: 0,
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[(Ability::Charisma, 1)])),
+        ..Self::BASE
+    };
+
+    /// Strong and fierce, with a menacing presence.
+    pub const HALF_ORC: Self = Self {
+        name: Cow::Borrowed("Half-Orc"),
+        darkvision: 60,
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[
+            (Ability::Strength, 2),
+            (Ability::Constitution, 1),
+        ])),
+        languages: Cow::Borrowed(&[Cow::Borrowed("Common"), Cow::Borrowed("Orc")]),
+        skills: make_bitflags!(Skill::{Intimidation}),
+        ..Self::BASE
+    };
+
+    /// Ambitious and versatile, improving at everything.
+    pub const HUMAN: Self = Self {
+        name: Cow::Borrowed("Human"),
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[
+            (Ability::Strength, 1),
+            (Ability::Dexterity, 1),
+            (Ability::Constitution, 1),
+            (Ability::Intelligence, 1),
+            (Ability::Wisdom, 1),
+            (Ability::Charisma, 1),
+        ])),
+        languages: Cow::Borrowed(&[Cow::Borrowed("Common")]),
+        ..Self::BASE
+    };
+
+    /// Touched by the Nine Hells, with resistance to fire and infernal magic.
+    pub const TIEFLING: Self = Self {
+        name: Cow::Borrowed("Tiefling"),
+        darkvision: 60,
+        ability_increases: AbilityIncreases::Fixed(Cow::Borrowed(&[
+            (Ability::Charisma, 2),
+            (Ability::Intelligence, 1),
+        ])),
+        languages: Cow::Borrowed(&[Cow::Borrowed("Common"), Cow::Borrowed("Infernal")]),
+        spellcasting_ability: Some(Ability::Charisma),
+        spells: Cow::Borrowed(&[
+            InnateSpell::new("Thaumaturgy", Level::new(1), None),
+            InnateSpell::new("Hellish Rebuke", Level::new(3), Some(Recharge::LongRest)),
+            InnateSpell::new("Darkness", Level::new(5), Some(Recharge::LongRest)),
+        ]),
+        resistances: make_bitflags!(DamageType::{Fire}),
+        ..Self::BASE
+    };
+
+    /// Towering descendants of giants, introduced in the 2024 rules.
+    pub const GOLIATH: Self = Self {
+        name: Cow::Borrowed("Goliath"),
+        speed: 35,
+        ..Self::BASE
+    };
+
+    /// Tireless and relentless, introduced in the 2024 rules.
+    pub const ORC: Self = Self {
+        name: Cow::Borrowed("Orc"),
+        darkvision: 120,
+        ..Self::BASE
+    };
+
+    /// Returns the species from the System Reference Document, without their subraces.
+    #[must_use]
+    pub const fn all() -> &'static [Species] {
+        const ALL: &[Species] = &[
+            Species::DRAGONBORN,
+            Species::DWARF,
+            Species::ELF,
+            Species::GNOME,
+            Species::GOLIATH,
+            Species::HALF_ELF,
+            Species::HALF_ORC,
+            Species::HALFLING,
+            Species::HUMAN,
+            Species::ORC,
+            Species::TIEFLING,
+        ];
+        ALL
+    }
+
+    /// Returns the total increase the species grants to `ability`, which is 0 if its increases
+    /// come from the background.
+    #[must_use]
+    pub fn ability_increase(&self, ability: Ability) -> u8 {
+        match &self.ability_increases {
+            AbilityIncreases::Fixed(increases) => increases
+                .iter()
+                .filter(|(increased, _)| *increased == ability)
+                .map(|(_, increase)| increase)
+                .sum(),
+            AbilityIncreases::FromBackground => 0,
+        }
+    }
+
+    /// Returns the innate spells a character of `level` can cast.
+    pub fn spells_at(&self, level: Level) -> impl Iterator<Item = &InnateSpell> {
+        self.spells.iter().filter(move |spell| spell.level <= level)
+    }
+
+    /// Combines the species with one of its subraces.
+    ///
+    /// The result takes the subrace's name, and the greater speed and darkvision of the two.
+    /// Every other trait of the subrace is added to those of the species.
+    #[must_use]
+    pub fn with_subrace(&self, subrace: &Species) -> Species {
+        fn concat<T: Clone>(first: &[T], second: &[T]) -> Cow<'static, [T]> {
+            Cow::Owned(first.iter().chain(second).cloned().collect())
+        }
+
+        let ability_increases = match (&self.ability_increases, &subrace.ability_increases) {
+            (AbilityIncreases::Fixed(first), AbilityIncreases::Fixed(second)) => {
+                AbilityIncreases::Fixed(concat(first, second))
+            }
+            _ => AbilityIncreases::FromBackground,
+        };
+        Species {
+            name: subrace.name.clone(),
+            size: self.size,
+            speed: self.speed.max(subrace.speed),
+            darkvision: self.darkvision.max(subrace.darkvision),
+            ability_increases,
+            languages: concat(&self.languages, &subrace.languages),
+            spellcasting_ability: subrace.spellcasting_ability.or(self.spellcasting_ability),
+            spells: concat(&self.spells, &subrace.spells),
+            resistances: self.resistances | subrace.resistances,
+            skills: self.skills | subrace.skills,
+            tools: self.tools | subrace.tools,
+            subraces: Cow::Borrowed(&[]),
+        }
+    }
+
+    /// Returns the species combined with the subrace with the given name, ignoring case, or
+    /// `None` if it has no such subrace.
+    #[must_use]
+    pub fn subrace(&self, name: &str) -> Option<Species> {
+        self.subraces
+            .iter()
+            .find(|subrace| subrace.name.eq_ignore_ascii_case(name))
+            .map(|subrace| self.with_subrace(subrace))
+    }
+}
+
+impl Display for Species {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subraces() {
+        let elf = Species::ELF.subrace("high elf").unwrap();
+        assert_eq!(elf.name, "High Elf");
+        assert_eq!(elf.speed, 30);
+        assert_eq!(elf.ability_increase(Ability::Dexterity), 2);
+        assert_eq!(elf.ability_increase(Ability::Intelligence), 1);
+        assert!(elf.skills.contains(Skill::Perception));
+        assert_eq!(elf.languages.len(), 2);
+        assert!(elf.subraces.is_empty());
+        assert!(Species::ELF.subrace("Hill Dwarf").is_none());
+    }
+
+    #[test]
+    fn ability_increases() {
+        for &ability in Ability::all() {
+            assert_eq!(Species::HUMAN.ability_increase(ability), 1);
+            assert_eq!(Species::ORC.ability_increase(ability), 0);
+        }
+        let goliath = Species::GOLIATH.with_subrace(&Species::HILL_DWARF);
+        assert_eq!(goliath.ability_increases, AbilityIncreases::FromBackground);
+        assert_eq!(goliath.speed, 35);
+    }
+
+    #[test]
+    fn dragonborn_ancestry() {
+        let dragonborn = Species::DRAGONBORN.subrace("Red Dragonborn").unwrap();
+        assert_eq!(dragonborn.resistances, DamageType::Fire);
+        assert_eq!(dragonborn.speed, 30);
+        assert_eq!(dragonborn.ability_increase(Ability::Strength), 2);
+        assert_eq!(Species::DRAGONBORN.subraces.len(), 10);
+        assert!(Species::DRAGONBORN.resistances.is_empty());
+
+        let gnome = Species::GNOME.subrace("Rock Gnome").unwrap();
+        assert_eq!(gnome.speed, 25);
+        assert!(gnome.tools.contains(Tool::TinkersTools));
+    }
+
+    #[test]
+    fn spells() {
+        assert_eq!(Species::TIEFLING.spells_at(Level::new(1)).count(), 1);
+        assert_eq!(Species::TIEFLING.spells_at(Level::new(20)).count(), 3);
+        assert_eq!(Species::DWARF.spells_at(Level::new(20)).count(), 0);
+    }
+}
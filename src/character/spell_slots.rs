@@ -0,0 +1,208 @@
+use crate::core::{Level, Recharge, Rest};
+
+/// Slots of each spell level from 1st to 9th, used to cast spells of that level or higher.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::SpellSlots;
+/// use dnd::core::{Level, Rest};
+///
+/// let mut slots = SpellSlots::full_caster(Level::new(3));
+/// assert_eq!(slots.maximum(1), 4);
+/// assert_eq!(slots.maximum(2), 2);
+///
+/// slots.expend(2).unwrap().expend(2).unwrap();
+/// assert!(slots.expend(2).is_err());
+///
+/// slots.rest(Rest::Long);
+/// assert_eq!(slots.available(2), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpellSlots {
+    maximum: [u8; 9],
+    expended: [u8; 9],
+
+    /// When expended slots are regained.
+    pub recharge: Recharge,
+}
+
+/// Spell slots of a full caster of each level, from 1st to 20th.
+const FULL_CASTER: [[u8; 9]; 20] = [
+    [2, 0, 0, 0, 0, 0, 0, 0, 0],
+    [3, 0, 0, 0, 0, 0, 0, 0, 0],
+    [4, 2, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 2, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 1, 0, 0, 0, 0, 0],
+    [4, 3, 3, 2, 0, 0, 0, 0, 0],
+    [4, 3, 3, 3, 1, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 2, 1, 1],
+];
+
+impl SpellSlots {
+    /// Creates a new `SpellSlots` with the given number of slots of each level, all available,
+    /// regained on a long rest.
+    #[must_use]
+    pub const fn new(maximum: [u8; 9]) -> Self {
+        Self {
+            maximum,
+            expended: [0; 9],
+            recharge: Recharge::LongRest,
+        }
+    }
+
+    /// Creates a new `SpellSlots` for a full spellcaster, such as a wizard, of the given level.
+    ///
+    /// A multiclass spellcaster uses this table with its combined spellcaster level.
+    #[must_use]
+    pub const fn full_caster(level: Level) -> Self {
+        Self::new(FULL_CASTER[level.value() as usize - 1])
+    }
+
+    /// Creates a new `SpellSlots` for a warlock's Pact Magic at the given warlock level.
+    ///
+    /// Every slot is of the same level, and expended slots are regained on a short rest.
+    #[must_use]
+    pub const fn pact_magic(level: Level) -> Self {
+        let count = match level.value() {
+            1 => 1,
+            2..=10 => 2,
+            11..=16 => 3,
+            _ => 4,
+        };
+        let slot_level = match level.value() {
+            1..=8 => level.value().div_ceil(2),
+            _ => 5,
+        };
+        let mut maximum = [0; 9];
+        maximum[slot_level as usize - 1] = count;
+        Self {
+            maximum,
+            expended: [0; 9],
+            recharge: Recharge::ShortRest,
+        }
+    }
+
+    /// Returns the index of `level`, which must be between 1 and 9.
+    const fn index(level: u8) -> usize {
+        debug_assert!(
+            level >= 1 && level <= 9,
+            "Spell level must be between 1 and 9"
+        );
+        level as usize - 1
+    }
+
+    /// Returns the number of slots of the given spell level.
+    #[must_use]
+    pub const fn maximum(&self, level: u8) -> u8 {
+        self.maximum[Self::index(level)]
+    }
+
+    /// Returns the number of unexpended slots of the given spell level.
+    #[must_use]
+    pub const fn available(&self, level: u8) -> u8 {
+        let index = Self::index(level);
+        self.maximum[index] - self.expended[index]
+    }
+
+    /// Expends a slot of the given spell level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the level is not between 1 and 9, or if no slots of that level are
+    /// available.
+    pub fn expend(&mut self, level: u8) -> Result<&mut Self, &'static str> {
+        if !(1..=9).contains(&level) {
+            return Err("Spell level must be between 1 and 9");
+        }
+        if self.available(level) == 0 {
+            return Err("No spell slots of this level available");
+        }
+        self.expended[Self::index(level)] += 1;
+        Ok(self)
+    }
+
+    /// Changes the number of slots of each level to that of `slots`, such as when gaining a
+    /// level, keeping slots that were expended expended.
+    pub fn set_maximum(&mut self, slots: &Self) -> &mut Self {
+        for (expended, maximum) in self.expended.iter_mut().zip(slots.maximum) {
+            *expended = (*expended).min(maximum);
+        }
+        self.maximum = slots.maximum;
+        self.recharge = slots.recharge;
+        self
+    }
+
+    /// Regains every expended slot.
+    pub fn restore(&mut self) -> &mut Self {
+        self.expended = [0; 9];
+        self
+    }
+
+    /// Regains every expended slot, if the slots recharge on the given rest.
+    pub fn rest(&mut self, rest: Rest) -> &mut Self {
+        if rest.recharges(self.recharge) {
+            self.restore();
+        }
+        self
+    }
+}
+
+impl Default for SpellSlots {
+    fn default() -> Self {
+        Self::new([0; 9])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_caster() {
+        let slots = SpellSlots::full_caster(Level::new(20));
+        assert_eq!(slots.maximum(9), 1);
+        assert_eq!(slots.maximum(7), 2);
+        assert_eq!(SpellSlots::full_caster(Level::new(1)).maximum(2), 0);
+    }
+
+    #[test]
+    fn set_maximum() {
+        let mut slots = SpellSlots::full_caster(Level::new(2));
+        slots.expend(1).unwrap().expend(1).unwrap();
+        slots.set_maximum(&SpellSlots::full_caster(Level::new(3)));
+        assert_eq!(slots.available(1), 2);
+        assert_eq!(slots.available(2), 2);
+        slots.set_maximum(&SpellSlots::default());
+        assert_eq!(slots.available(1), 0);
+    }
+
+    #[test]
+    fn pact_magic() {
+        let mut slots = SpellSlots::pact_magic(Level::new(5));
+        assert_eq!(slots.maximum(3), 2);
+        assert_eq!(slots.maximum(1), 0);
+        assert_eq!(SpellSlots::pact_magic(Level::new(11)).maximum(5), 3);
+
+        slots.expend(3).unwrap();
+        slots.rest(Rest::Short);
+        assert_eq!(slots.available(3), 2);
+        assert_eq!(
+            slots.expend(0).unwrap_err(),
+            "Spell level must be between 1 and 9"
+        );
+    }
+}
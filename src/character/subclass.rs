@@ -0,0 +1,122 @@
+use crate::{
+    character::{Class, FeatureOptionKind, Resource, class::options_at},
+    core::Level,
+};
+use alloc::borrow::Cow;
+use core::fmt::Display;
+
+/// A specialization of a class, chosen at the class's [`Class::subclass_level`].
+///
+/// The subclass of each class from the System Reference Document is provided as a constant,
+/// along with the Battle Master, whose maneuvers are a [`FeatureOptionKind`].
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::{Class, FeatureOptionKind, Subclass};
+/// use dnd::core::Level;
+///
+/// let champion = Subclass::CHAMPION;
+/// assert_eq!(champion.class, Class::Fighter);
+/// assert_eq!(champion.to_string(), "Champion");
+/// assert_eq!(
+///     Subclass::BATTLE_MASTER.feature_options_at(FeatureOptionKind::Maneuver, Level::new(7)),
+///     5
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Subclass {
+    /// The name of the subclass, such as "School of Evocation".
+    pub name: Cow<'static, str>,
+
+    /// The class the subclass belongs to.
+    pub class: Class,
+
+    /// The feature options the subclass grants, as the level in the class, the kind of option,
+    /// and how many more options of that kind are chosen at that level.
+    pub feature_options: Cow<'static, [(Level, FeatureOptionKind, u8)]>,
+
+    /// The limited-use features the subclass grants, as the level in the class at which each is
+    /// gained.
+    pub resources: Cow<'static, [(Level, Resource)]>,
+}
+
+impl Subclass {
+    /// Path of the Berserker, for barbarians.
+    pub const BERSERKER: Self = Self::new("Path of the Berserker", Class::Barbarian);
+
+    /// College of Lore, for bards.
+    pub const LORE: Self = Self::new("College of Lore", Class::Bard);
+
+    /// Life Domain, for clerics.
+    pub const LIFE: Self = Self::new("Life Domain", Class::Cleric);
+
+    /// Circle of the Land, for druids.
+    pub const LAND: Self = Self::new("Circle of the Land", Class::Druid);
+
+    /// Champion, for fighters, with an additional Fighting Style at level 10.
+    pub const CHAMPION: Self = Self {
+        name: Cow::Borrowed("Champion"),
+        class: Class::Fighter,
+        feature_options: Cow::Borrowed(&[(Level::new(10), FeatureOptionKind::FightingStyle, 1)]),
+        resources: Cow::Borrowed(&[]),
+    };
+
+    /// Battle Master, for fighters, with maneuvers fueled by superiority dice.
+    pub const BATTLE_MASTER: Self = Self {
+        name: Cow::Borrowed("Battle Master"),
+        class: Class::Fighter,
+        feature_options: Cow::Borrowed(&[
+            (Level::new(3), FeatureOptionKind::Maneuver, 3),
+            (Level::new(7), FeatureOptionKind::Maneuver, 2),
+            (Level::new(10), FeatureOptionKind::Maneuver, 2),
+            (Level::new(15), FeatureOptionKind::Maneuver, 2),
+        ]),
+        resources: Cow::Borrowed(&[(Level::new(3), Resource::SUPERIORITY_DICE)]),
+    };
+
+    /// Way of the Open Hand, for monks.
+    pub const OPEN_HAND: Self = Self::new("Way of the Open Hand", Class::Monk);
+
+    /// Oath of Devotion, for paladins.
+    pub const DEVOTION: Self = Self::new("Oath of Devotion", Class::Paladin);
+
+    /// Hunter, for rangers.
+    pub const HUNTER: Self = Self::new("Hunter", Class::Ranger);
+
+    /// Thief, for rogues.
+    pub const THIEF: Self = Self::new("Thief", Class::Rogue);
+
+    /// Draconic Bloodline, for sorcerers.
+    pub const DRACONIC_BLOODLINE: Self = Self::new("Draconic Bloodline", Class::Sorcerer);
+
+    /// The Fiend, for warlocks.
+    pub const FIEND: Self = Self::new("The Fiend", Class::Warlock);
+
+    /// School of Evocation, for wizards.
+    pub const EVOCATION: Self = Self::new("School of Evocation", Class::Wizard);
+
+    /// Creates a new `Subclass` of `class` that grants no feature options or resources.
+    #[must_use]
+    pub const fn new(name: &'static str, class: Class) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            class,
+            feature_options: Cow::Borrowed(&[]),
+            resources: Cow::Borrowed(&[]),
+        }
+    }
+
+    /// Returns how many options of `kind` the subclass grants by `level` in its class.
+    #[must_use]
+    pub fn feature_options_at(&self, kind: FeatureOptionKind, level: Level) -> u8 {
+        options_at(&self.feature_options, kind, level)
+    }
+}
+
+impl Display for Subclass {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{
+    core::{Dice, Die},
+    equipment::EncumbranceRule,
+};
+
+/// The optional and house rules a campaign is played with.
+///
+/// Every rule is off by default, except that feats are allowed. A [`Character`] consults its
+/// variant rules for checks, rests, and the choices it can make, and combat resolution uses
+/// them directly, such as to roll the damage of a critical hit.
+///
+/// [`Character`]: crate::character::Character
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::VariantRules;
+/// use dnd::core::{Dice, Die};
+///
+/// let mut rules = VariantRules::new();
+/// let dice = Dice::new(2, Die::D6).with_modifier(3);
+/// assert_eq!(rules.critical_damage(dice, |_| 2), 11);
+///
+/// rules.maximized_critical_hits = true;
+/// assert_eq!(rules.critical_damage(dice, |_| 2), 19);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::struct_excessive_bools)]
+pub struct VariantRules {
+    /// Whether a creature has advantage on melee attack rolls against a creature it and an ally
+    /// flank, on opposite sides of it.
+    pub flanking: bool,
+
+    /// Which rule determines whether a creature is slowed by what it carries.
+    pub encumbrance: EncumbranceRule,
+
+    /// Whether characters can take feats, such as in place of an Ability Score Improvement.
+    pub feats: bool,
+
+    /// Whether the extra damage dice of a critical hit deal their maximum, instead of being
+    /// rolled.
+    pub maximized_critical_hits: bool,
+
+    /// Whether a long rest regains every spent hit die, instead of half of them.
+    pub regain_all_hit_dice: bool,
+
+    /// Whether a die is rolled in place of the proficiency bonus, as given by
+    /// [`ProficiencyBonus::die`], rolled twice with expertise.
+    ///
+    /// [`ProficiencyBonus::die`]: crate::core::ProficiencyBonus::die
+    pub proficiency_dice: bool,
+}
+
+impl VariantRules {
+    /// Creates a new `VariantRules` with only the standard rules: feats are allowed, and every
+    /// other rule is off.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            flanking: false,
+            encumbrance: EncumbranceRule::Standard,
+            feats: true,
+            maximized_critical_hits: false,
+            regain_all_hit_dice: false,
+            proficiency_dice: false,
+        }
+    }
+
+    /// Returns the damage of a critical hit with `damage`, rolling each die with `roll`.
+    ///
+    /// The damage dice are rolled twice, or rolled once and dealt once at their maximum with
+    /// [`Self::maximized_critical_hits`]. The modifier of `damage` is added once.
+    pub fn critical_damage(&self, damage: Dice, mut roll: impl FnMut(Die) -> u8) -> i16 {
+        let extra = match damage.die() {
+            Some(die) if self.maximized_critical_hits => {
+                i16::from(damage.count()) * i16::from(die.sides())
+            }
+            Some(die) => Dice::new(damage.count(), die).roll(&mut roll),
+            None => 0,
+        };
+        damage.roll(roll) + extra
+    }
+}
+
+impl Default for VariantRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
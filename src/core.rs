@@ -10,14 +10,57 @@ pub use ability_modifier::AbilityModifier;
 mod ability_score;
 pub use ability_score::AbilityScore;
 
+mod challenge_rating;
+pub use challenge_rating::ChallengeRating;
+
+mod condition;
+pub use condition::Condition;
+
+#[cfg(feature = "alloc")]
+mod custom_skill;
+#[cfg(feature = "alloc")]
+pub use custom_skill::CustomSkill;
+
+mod damage_type;
+pub use damage_type::DamageType;
+
+mod dice;
+pub use dice::{Dice, Die};
+
 mod level;
 pub use level::Level;
 
+mod passive_scores;
+pub use passive_scores::PassiveScores;
+
 mod proficiency_bonus;
 pub use proficiency_bonus::ProficiencyBonus;
 
+mod recharge;
+pub use recharge::Recharge;
+
+mod rest;
+pub use rest::Rest;
+
+mod roll_mode;
+pub use roll_mode::RollMode;
+
+mod rules_version;
+pub use rules_version::RulesVersion;
+
 mod skill_proficiencies;
-pub use skill_proficiencies::{SkillLevel, SkillProficiencies};
+pub use skill_proficiencies::{SkillLevel, SkillProficiencies, SkillReplacements};
 
 mod skill;
 pub use skill::Skill;
+
+#[cfg(feature = "alloc")]
+mod skill_registry;
+#[cfg(feature = "alloc")]
+pub use skill_registry::SkillRegistry;
+
+mod tool;
+pub use tool::{Tool, ToolCategory};
+
+mod tool_proficiencies;
+pub use tool_proficiencies::ToolProficiencies;
@@ -0,0 +1,378 @@
+use crate::core::ProficiencyBonus;
+use core::{fmt::Display, str::FromStr};
+
+/// Challenge rating of a monster, which tells how great a threat it is.
+///
+/// A challenge rating is 0, 1/8, 1/4, 1/2, or a whole number in the range of `1..=30`.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{ChallengeRating, ProficiencyBonus};
+///
+/// let cr = ChallengeRating::new(5);
+/// assert_eq!(cr.xp(), 1_800);
+/// assert_eq!(ProficiencyBonus::from(cr), ProficiencyBonus::new(3));
+///
+/// let cr: ChallengeRating = "1/4".parse().unwrap();
+/// assert_eq!(cr, ChallengeRating::ONE_QUARTER);
+/// assert_eq!(cr.xp(), 50);
+/// assert_eq!(cr.to_string(), "1/4");
+/// ```
+///
+/// Challenge ratings are ordered from least to most dangerous:
+///
+/// ```rust
+/// use dnd::core::ChallengeRating;
+///
+/// assert!(ChallengeRating::ONE_HALF < ChallengeRating::new(1));
+/// assert_eq!(ChallengeRating::ONE_HALF.next(), Some(ChallengeRating::new(1)));
+/// assert_eq!(ChallengeRating::MAX.next(), None);
+/// ```
+///
+/// With the `serde` feature, a challenge rating is serialized as its string form, such as
+/// `"1/4"` or `"5"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ChallengeRating(u8);
+
+impl ChallengeRating {
+    /// The minimum possible challenge rating, 0.
+    pub const MIN: Self = Self(0);
+
+    /// The maximum possible challenge rating, 30.
+    pub const MAX: Self = Self(30 * 8);
+
+    /// Challenge rating 0.
+    pub const ZERO: Self = Self(0);
+
+    /// Challenge rating 1/8.
+    pub const ONE_EIGHTH: Self = Self(1);
+
+    /// Challenge rating 1/4.
+    pub const ONE_QUARTER: Self = Self(2);
+
+    /// Challenge rating 1/2.
+    pub const ONE_HALF: Self = Self(4);
+
+    /// Creates a new `ChallengeRating` with the given whole number value.
+    ///
+    /// Use [`Self::ONE_EIGHTH`], [`Self::ONE_QUARTER`], or [`Self::ONE_HALF`] for fractional
+    /// challenge ratings.
+    ///
+    /// In debug mode, this will panic if the value is greater than 30.
+    ///
+    /// In release mode, it will clamp the value to the range of [`Self::MIN`] to [`Self::MAX`].
+    #[must_use]
+    pub const fn new(value: u8) -> Self {
+        debug_assert!(value <= 30, "Challenge rating must be between 0 and 30");
+        Self::new_clamped(value)
+    }
+
+    /// Creates a new `ChallengeRating` with the given whole number value.
+    ///
+    /// The value is automatically clamped to the range of [`Self::MIN`] to [`Self::MAX`].
+    #[must_use]
+    pub const fn new_clamped(value: u8) -> Self {
+        if value > 30 {
+            Self::MAX
+        } else {
+            Self(value * 8)
+        }
+    }
+
+    /// Creates a new `ChallengeRating` with the given whole number value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is greater than 30.
+    pub fn try_new(value: u8) -> Result<Self, &'static str> {
+        if value > 30 {
+            Err("Challenge rating cannot be greater than 30")
+        } else {
+            Ok(Self(value * 8))
+        }
+    }
+
+    /// Returns an array containing every challenge rating, from least to most dangerous.
+    #[must_use]
+    pub const fn all() -> &'static [ChallengeRating] {
+        const ALL: [ChallengeRating; 34] = {
+            let mut all = [ChallengeRating::ZERO; 34];
+            all[1] = ChallengeRating::ONE_EIGHTH;
+            all[2] = ChallengeRating::ONE_QUARTER;
+            all[3] = ChallengeRating::ONE_HALF;
+            let mut i = 4;
+            while i < 34 {
+                #[allow(clippy::cast_possible_truncation)]
+                let value = (i - 3) as u8;
+                all[i] = ChallengeRating::new(value);
+                i += 1;
+            }
+            all
+        };
+        &ALL
+    }
+
+    /// Returns the challenge rating in eighths, such as 2 for a challenge rating of 1/4.
+    #[must_use]
+    pub const fn eighths(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns the challenge rating as a floating point number, such as 0.25 for 1/4.
+    #[must_use]
+    pub fn as_f32(&self) -> f32 {
+        f32::from(self.0) / 8.0
+    }
+
+    /// Returns the position of the challenge rating in [`Self::all`].
+    const fn index(self) -> usize {
+        match self.0 {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            4 => 3,
+            eighths => eighths as usize / 8 + 3,
+        }
+    }
+
+    /// Returns the next higher challenge rating, if any.
+    #[must_use]
+    pub const fn next(&self) -> Option<Self> {
+        let all = Self::all();
+        let index = self.index() + 1;
+        if index < all.len() {
+            Some(all[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the next lower challenge rating, if any.
+    #[must_use]
+    pub const fn previous(&self) -> Option<Self> {
+        match self.index() {
+            0 => None,
+            index => Some(Self::all()[index - 1]),
+        }
+    }
+
+    /// Returns the experience points awarded for defeating a monster of this challenge rating.
+    ///
+    /// A monster of challenge rating 0 is worth 10 XP, or 0 XP if it has no effective attacks.
+    #[must_use]
+    pub const fn xp(&self) -> u32 {
+        const XP: [u32; 34] = [
+            10, 25, 50, 100, 200, 450, 700, 1_100, 1_800, 2_300, 2_900, 3_900, 5_000, 5_900, 7_200,
+            8_400, 10_000, 11_500, 13_000, 15_000, 18_000, 20_000, 22_000, 25_000, 33_000, 41_000,
+            50_000, 62_000, 75_000, 90_000, 105_000, 120_000, 135_000, 155_000,
+        ];
+        XP[self.index()]
+    }
+
+    /// Returns the proficiency bonus of a monster of this challenge rating.
+    #[must_use]
+    pub const fn proficiency_bonus(&self) -> ProficiencyBonus {
+        ProficiencyBonus::new_clamped(match self.0 / 8 {
+            0..=4 => 2,
+            5..=8 => 3,
+            9..=12 => 4,
+            13..=16 => 5,
+            17..=20 => 6,
+            21..=24 => 7,
+            25..=28 => 8,
+            _ => 9,
+        })
+    }
+}
+
+impl Default for ChallengeRating {
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
+impl From<ChallengeRating> for ProficiencyBonus {
+    fn from(cr: ChallengeRating) -> Self {
+        cr.proficiency_bonus()
+    }
+}
+
+impl Display for ChallengeRating {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            1 => write!(f, "1/8"),
+            2 => write!(f, "1/4"),
+            4 => write!(f, "1/2"),
+            eighths => write!(f, "{}", eighths / 8),
+        }
+    }
+}
+
+impl FromStr for ChallengeRating {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1/8" => Ok(Self::ONE_EIGHTH),
+            "1/4" => Ok(Self::ONE_QUARTER),
+            "1/2" => Ok(Self::ONE_HALF),
+            value => Self::try_new(value.parse().map_err(|_| "Invalid challenge rating")?),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChallengeRating {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChallengeRating {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = ChallengeRating;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a challenge rating, such as \"1/4\" or \"5\"")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn all() {
+        let all = ChallengeRating::all();
+        assert_eq!(all.len(), 34);
+        assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(all[4], ChallengeRating::new(1));
+        assert_eq!(all[33], ChallengeRating::MAX);
+    }
+
+    #[test]
+    fn new_clamped_max() {
+        assert_eq!(ChallengeRating::new_clamped(31), ChallengeRating::MAX);
+    }
+
+    #[test]
+    fn try_new_max() {
+        assert_eq!(
+            ChallengeRating::try_new(31),
+            Err("Challenge rating cannot be greater than 30")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Challenge rating must be between 0 and 30")]
+    fn new_panic() {
+        let _cr = ChallengeRating::new(31);
+    }
+
+    #[test]
+    fn next_and_previous() {
+        for pair in ChallengeRating::all().windows(2) {
+            assert_eq!(pair[0].next(), Some(pair[1]));
+            assert_eq!(pair[1].previous(), Some(pair[0]));
+        }
+        assert_eq!(ChallengeRating::MIN.previous(), None);
+    }
+
+    #[test]
+    fn xp() {
+        let expected = [
+            (ChallengeRating::ZERO, 10),
+            (ChallengeRating::ONE_EIGHTH, 25),
+            (ChallengeRating::ONE_HALF, 100),
+            (ChallengeRating::new(10), 5_900),
+            (ChallengeRating::new(20), 25_000),
+            (ChallengeRating::MAX, 155_000),
+        ];
+        for (cr, xp) in expected {
+            assert_eq!(cr.xp(), xp, "{cr}");
+        }
+    }
+
+    #[test]
+    fn proficiency_bonus() {
+        let expected = [
+            (0..=4, 2),
+            (5..=8, 3),
+            (9..=12, 4),
+            (13..=16, 5),
+            (17..=20, 6),
+            (21..=24, 7),
+            (25..=28, 8),
+            (29..=30, 9),
+        ];
+        for (range, bonus) in expected {
+            for cr in range {
+                assert_eq!(
+                    ProficiencyBonus::from(ChallengeRating::new(cr)),
+                    ProficiencyBonus::new(bonus)
+                );
+            }
+        }
+        assert_eq!(
+            ChallengeRating::ONE_EIGHTH.proficiency_bonus(),
+            ProficiencyBonus::new(2)
+        );
+    }
+
+    #[test]
+    fn parse() {
+        for cr in ChallengeRating::all() {
+            assert_eq!(cr.to_string().parse(), Ok(*cr));
+        }
+        assert_eq!(
+            "1/3".parse::<ChallengeRating>(),
+            Err("Invalid challenge rating")
+        );
+        assert_eq!(
+            "31".parse::<ChallengeRating>(),
+            Err("Challenge rating cannot be greater than 30")
+        );
+    }
+
+    #[test]
+    fn as_f32() {
+        assert!((ChallengeRating::ONE_QUARTER.as_f32() - 0.25).abs() < f32::EPSILON);
+        assert!((ChallengeRating::new(3).as_f32() - 3.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let serialized = serde_json::to_string(&ChallengeRating::ONE_QUARTER).unwrap();
+        assert_eq!(serialized, "\"1/4\"");
+
+        let deserialized: ChallengeRating = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, ChallengeRating::ONE_QUARTER);
+
+        for cr in ChallengeRating::all() {
+            let serialized = serde_json::to_string(cr).unwrap();
+            assert_eq!(
+                serde_json::from_str::<ChallengeRating>(&serialized).unwrap(),
+                *cr
+            );
+        }
+        assert!(serde_json::from_str::<ChallengeRating>("\"1/3\"").is_err());
+        assert!(serde_json::from_str::<ChallengeRating>("255").is_err());
+    }
+}
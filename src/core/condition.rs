@@ -0,0 +1,158 @@
+use core::{fmt::Display, str::FromStr};
+use enumflags2::bitflags;
+
+/// A condition that alters a creature's capabilities, such as being blinded or prone.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::Condition;
+///
+/// assert_eq!(Condition::Frightened.name(), "Frightened");
+/// assert_eq!("prone".parse(), Ok(Condition::Prone));
+/// ```
+#[bitflags]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum Condition {
+    /// Can't see, and fails any ability check that requires sight.
+    Blinded,
+
+    /// Can't attack the charmer or target it with harmful abilities or magical effects.
+    Charmed,
+
+    /// Can't hear, and fails any ability check that requires hearing.
+    Deafened,
+
+    /// Suffers cumulative penalties from levels of exhaustion.
+    Exhaustion,
+
+    /// Has disadvantage on ability checks and attack rolls while the source of fear is in sight.
+    Frightened,
+
+    /// Speed becomes 0, and can't benefit from any bonus to speed.
+    Grappled,
+
+    /// Can't take actions or reactions.
+    Incapacitated,
+
+    /// Impossible to see without the aid of magic or a special sense.
+    Invisible,
+
+    /// Incapacitated and can't move or speak.
+    Paralyzed,
+
+    /// Transformed, along with any nonmagical object it is wearing or carrying, into stone.
+    Petrified,
+
+    /// Has disadvantage on attack rolls and ability checks.
+    Poisoned,
+
+    /// Can only crawl, unless it stands up.
+    Prone,
+
+    /// Speed becomes 0, and attack rolls against it have advantage.
+    Restrained,
+
+    /// Incapacitated, can't move, and can speak only falteringly.
+    Stunned,
+
+    /// Incapacitated, can't move or speak, and is unaware of its surroundings.
+    Unconscious,
+}
+
+impl Condition {
+    /// Returns an array containing all possible [`Condition`] variants, in alphabetical order.
+    #[must_use]
+    pub const fn all() -> &'static [Condition] {
+        &[
+            Condition::Blinded,
+            Condition::Charmed,
+            Condition::Deafened,
+            Condition::Exhaustion,
+            Condition::Frightened,
+            Condition::Grappled,
+            Condition::Incapacitated,
+            Condition::Invisible,
+            Condition::Paralyzed,
+            Condition::Petrified,
+            Condition::Poisoned,
+            Condition::Prone,
+            Condition::Restrained,
+            Condition::Stunned,
+            Condition::Unconscious,
+        ]
+    }
+
+    /// Returns the name of the condition, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Condition::Blinded => "Blinded",
+            Condition::Charmed => "Charmed",
+            Condition::Deafened => "Deafened",
+            Condition::Exhaustion => "Exhaustion",
+            Condition::Frightened => "Frightened",
+            Condition::Grappled => "Grappled",
+            Condition::Incapacitated => "Incapacitated",
+            Condition::Invisible => "Invisible",
+            Condition::Paralyzed => "Paralyzed",
+            Condition::Petrified => "Petrified",
+            Condition::Poisoned => "Poisoned",
+            Condition::Prone => "Prone",
+            Condition::Restrained => "Restrained",
+            Condition::Stunned => "Stunned",
+            Condition::Unconscious => "Unconscious",
+        }
+    }
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl AsRef<str> for Condition {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl FromStr for Condition {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Condition::all()
+            .iter()
+            .find(|condition| condition.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or("Unknown condition")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        for condition in Condition::all() {
+            assert_eq!(condition.to_string().parse(), Ok(*condition));
+        }
+        assert_eq!("Dazed".parse::<Condition>(), Err("Unknown condition"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let serialized = serde_json::to_string(&Condition::Poisoned).unwrap();
+        assert_eq!(serialized, "\"Poisoned\"");
+
+        let deserialized: Condition = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, Condition::Poisoned);
+    }
+}
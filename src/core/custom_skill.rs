@@ -0,0 +1,55 @@
+use crate::core::Ability;
+use alloc::borrow::Cow;
+use core::fmt::Display;
+
+/// A homebrew skill that isn't one of the standard [`Skill`]s, such as "Engineering".
+///
+/// Like the standard skills, a custom skill is tied to an ability. Custom skills are
+/// registered with a [`SkillRegistry`], and creatures can be proficient in them through
+/// [`SkillProficiencies`].
+///
+/// [`Skill`]: crate::core::Skill
+/// [`SkillRegistry`]: crate::core::SkillRegistry
+/// [`SkillProficiencies`]: crate::core::SkillProficiencies
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Ability, CustomSkill};
+///
+/// let engineering = CustomSkill::new("Engineering", Ability::Intelligence);
+/// assert_eq!(engineering.name, "Engineering");
+/// assert_eq!(engineering.to_string(), "Engineering");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomSkill {
+    /// The name of the skill, such as "Engineering".
+    pub name: Cow<'static, str>,
+
+    /// The ability used for checks with the skill.
+    pub ability: Ability,
+}
+
+impl CustomSkill {
+    /// Creates a new `CustomSkill` with the given name and ability.
+    #[must_use]
+    pub const fn new(name: &'static str, ability: Ability) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            ability,
+        }
+    }
+}
+
+impl Display for CustomSkill {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl AsRef<str> for CustomSkill {
+    fn as_ref(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,182 @@
+use core::{fmt::Display, str::FromStr};
+use enumflags2::bitflags;
+
+/// A kind of damage, which determines how resistances, vulnerabilities, and immunities apply.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::DamageType;
+///
+/// assert_eq!(DamageType::Slashing.name(), "Slashing");
+/// assert!(DamageType::Piercing.is_physical());
+/// assert!(!DamageType::Fire.is_physical());
+/// ```
+#[bitflags]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum DamageType {
+    /// Corrosive liquids and digestive enzymes.
+    Acid,
+
+    /// Blunt force attacks, such as hammers and falling.
+    Bludgeoning,
+
+    /// Infernal chill and frigid blasts.
+    Cold,
+
+    /// Flames and unbearable heat.
+    Fire,
+
+    /// Pure magical energy.
+    Force,
+
+    /// Electricity.
+    Lightning,
+
+    /// Life-draining energy.
+    Necrotic,
+
+    /// Puncturing and impaling attacks, such as spears and bites.
+    Piercing,
+
+    /// Venomous stings and toxic gases.
+    Poison,
+
+    /// Mind-rending energy.
+    Psychic,
+
+    /// Holy energy and searing light.
+    Radiant,
+
+    /// Cuts and slashes, such as swords and claws.
+    Slashing,
+
+    /// Concussive bursts of sound.
+    Thunder,
+}
+
+impl DamageType {
+    /// Returns an array containing all possible [`DamageType`] variants, in alphabetical order.
+    #[must_use]
+    pub const fn all() -> &'static [DamageType] {
+        &[
+            DamageType::Acid,
+            DamageType::Bludgeoning,
+            DamageType::Cold,
+            DamageType::Fire,
+            DamageType::Force,
+            DamageType::Lightning,
+            DamageType::Necrotic,
+            DamageType::Piercing,
+            DamageType::Poison,
+            DamageType::Psychic,
+            DamageType::Radiant,
+            DamageType::Slashing,
+            DamageType::Thunder,
+        ]
+    }
+
+    /// Returns the name of the damage type, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            DamageType::Acid => "Acid",
+            DamageType::Bludgeoning => "Bludgeoning",
+            DamageType::Cold => "Cold",
+            DamageType::Fire => "Fire",
+            DamageType::Force => "Force",
+            DamageType::Lightning => "Lightning",
+            DamageType::Necrotic => "Necrotic",
+            DamageType::Piercing => "Piercing",
+            DamageType::Poison => "Poison",
+            DamageType::Psychic => "Psychic",
+            DamageType::Radiant => "Radiant",
+            DamageType::Slashing => "Slashing",
+            DamageType::Thunder => "Thunder",
+        }
+    }
+
+    /// Returns whether this is bludgeoning, piercing, or slashing damage.
+    #[must_use]
+    pub const fn is_physical(&self) -> bool {
+        matches!(
+            self,
+            DamageType::Bludgeoning | DamageType::Piercing | DamageType::Slashing
+        )
+    }
+}
+
+impl Display for DamageType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl AsRef<str> for DamageType {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl FromStr for DamageType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DamageType::all()
+            .iter()
+            .find(|damage_type| damage_type.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or("Unknown damage type")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        for damage_type in DamageType::all() {
+            assert_eq!(DamageType::from_str(damage_type.name()), Ok(*damage_type));
+        }
+        assert_eq!(DamageType::from_str("fire"), Ok(DamageType::Fire));
+        assert!(DamageType::from_str("Sonic").is_err());
+    }
+
+    #[test]
+    fn display() {
+        for damage_type in DamageType::all() {
+            assert_eq!(damage_type.to_string(), damage_type.name());
+        }
+    }
+
+    #[test]
+    fn is_physical() {
+        let physical: alloc::vec::Vec<_> = DamageType::all()
+            .iter()
+            .filter(|damage_type| damage_type.is_physical())
+            .collect();
+        assert_eq!(
+            physical,
+            [
+                &DamageType::Bludgeoning,
+                &DamageType::Piercing,
+                &DamageType::Slashing
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let serialized = serde_json::to_string(&DamageType::Necrotic).unwrap();
+        assert_eq!(serialized, "\"Necrotic\"");
+
+        let deserialized: DamageType = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, DamageType::Necrotic);
+    }
+}
@@ -0,0 +1,431 @@
+use core::{fmt::Display, str::FromStr};
+
+/// A single polyhedral die.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::Die;
+///
+/// assert_eq!(Die::D6.sides(), 6);
+/// assert_eq!(Die::D20.to_string(), "d20");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Die {
+    /// A four-sided die.
+    D4 = 4,
+
+    /// A six-sided die.
+    D6 = 6,
+
+    /// An eight-sided die.
+    D8 = 8,
+
+    /// A ten-sided die.
+    D10 = 10,
+
+    /// A twelve-sided die.
+    D12 = 12,
+
+    /// A twenty-sided die.
+    D20 = 20,
+
+    /// A percentile die.
+    D100 = 100,
+}
+
+impl Die {
+    /// Returns an array containing all possible [`Die`] variants, from smallest to largest.
+    #[must_use]
+    pub const fn all() -> &'static [Die] {
+        &[
+            Die::D4,
+            Die::D6,
+            Die::D8,
+            Die::D10,
+            Die::D12,
+            Die::D20,
+            Die::D100,
+        ]
+    }
+
+    /// Returns the number of sides on the die.
+    #[must_use]
+    pub const fn sides(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Creates a `Die` from the number of sides, if such a die exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dnd::core::Die;
+    ///
+    /// assert_eq!(Die::from_sides(8), Some(Die::D8));
+    /// assert_eq!(Die::from_sides(7), None);
+    /// ```
+    #[must_use]
+    pub const fn from_sides(sides: u8) -> Option<Self> {
+        match sides {
+            4 => Some(Die::D4),
+            6 => Some(Die::D6),
+            8 => Some(Die::D8),
+            10 => Some(Die::D10),
+            12 => Some(Die::D12),
+            20 => Some(Die::D20),
+            100 => Some(Die::D100),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Die {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "d{}", self.sides())
+    }
+}
+
+impl FromStr for Die {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sides = s
+            .strip_prefix('d')
+            .or_else(|| s.strip_prefix('D'))
+            .ok_or("Die must start with 'd'")?;
+        sides
+            .parse()
+            .ok()
+            .and_then(Die::from_sides)
+            .ok_or("Unknown die")
+    }
+}
+
+/// A dice expression, such as `2d6` or `1d6+1`.
+///
+/// An expression is a number of identical dice plus a flat modifier. An expression with no dice
+/// represents a fixed value, such as the single point of damage dealt by a blowgun.
+///
+/// Rolling is left to the caller, which keeps this crate free of any random number generator.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Dice, Die};
+///
+/// let dice = Dice::new(2, Die::D6);
+/// assert_eq!(dice.min(), 2);
+/// assert_eq!(dice.max(), 12);
+/// assert_eq!(dice.average(), 7);
+///
+/// let recharge: Dice = "1d6+1".parse().unwrap();
+/// assert_eq!(recharge, Dice::new(1, Die::D6).with_modifier(1));
+/// assert_eq!(recharge.to_string(), "1d6+1");
+/// ```
+///
+/// With the `serde` feature, dice are serialized as their string form, such as `"1d6+1"` or
+/// `"1"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dice {
+    count: u8,
+    die: Option<Die>,
+    modifier: i8,
+}
+
+impl Dice {
+    /// Creates a new expression of `count` dice of the given kind, with no modifier.
+    ///
+    /// A `count` of zero is equivalent to [`Dice::flat(0)`][Dice::flat].
+    #[must_use]
+    pub const fn new(count: u8, die: Die) -> Self {
+        if count == 0 {
+            Self::flat(0)
+        } else {
+            Self {
+                count,
+                die: Some(die),
+                modifier: 0,
+            }
+        }
+    }
+
+    /// Creates a new expression with no dice that always results in `value`.
+    #[must_use]
+    pub const fn flat(value: i8) -> Self {
+        Self {
+            count: 0,
+            die: None,
+            modifier: value,
+        }
+    }
+
+    /// Returns a copy of this expression with the modifier replaced by `modifier`.
+    #[must_use]
+    pub const fn with_modifier(self, modifier: i8) -> Self {
+        Self { modifier, ..self }
+    }
+
+    /// Returns the number of dice rolled.
+    #[must_use]
+    pub const fn count(&self) -> u8 {
+        self.count
+    }
+
+    /// Returns the kind of die rolled, or `None` if the expression is a flat value.
+    #[must_use]
+    pub const fn die(&self) -> Option<Die> {
+        self.die
+    }
+
+    /// Returns the flat modifier added to the roll.
+    #[must_use]
+    pub const fn modifier(&self) -> i8 {
+        self.modifier
+    }
+
+    /// Returns the lowest possible result.
+    #[must_use]
+    pub const fn min(&self) -> i16 {
+        self.count as i16 + self.modifier as i16
+    }
+
+    /// Returns the highest possible result.
+    #[must_use]
+    pub const fn max(&self) -> i16 {
+        let sides = match self.die {
+            Some(die) => die.sides() as i16,
+            None => 0,
+        };
+        self.count as i16 * sides + self.modifier as i16
+    }
+
+    /// Returns the average result, rounded down as is done in stat blocks.
+    #[must_use]
+    pub const fn average(&self) -> i16 {
+        (self.min() + self.max()).div_euclid(2)
+    }
+
+    /// Returns the total of this expression using `roll` to produce the result of each die.
+    ///
+    /// Each result returned by `roll` is clamped to the faces of the die.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dnd::core::{Dice, Die};
+    ///
+    /// let dice = Dice::new(2, Die::D6).with_modifier(3);
+    /// assert_eq!(dice.roll(|_| 4), 11);
+    /// ```
+    pub fn roll(&self, mut roll: impl FnMut(Die) -> u8) -> i16 {
+        let mut total = i16::from(self.modifier);
+        if let Some(die) = self.die {
+            for _ in 0..self.count {
+                total += i16::from(roll(die).clamp(1, die.sides()));
+            }
+        }
+        total
+    }
+}
+
+impl From<Die> for Dice {
+    fn from(die: Die) -> Self {
+        Self::new(1, die)
+    }
+}
+
+impl Display for Dice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.die {
+            Some(die) => {
+                write!(f, "{}{die}", self.count)?;
+                if self.modifier != 0 {
+                    write!(f, "{:+}", self.modifier)?;
+                }
+                Ok(())
+            }
+            None => write!(f, "{}", self.modifier),
+        }
+    }
+}
+
+impl FromStr for Dice {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let Some(index) = s.find(['d', 'D']) else {
+            return s.parse().map(Self::flat).map_err(|_| "Invalid dice");
+        };
+        let count = match s[..index].trim() {
+            "" => 1,
+            count => count.parse().map_err(|_| "Invalid dice count")?,
+        };
+        let rest = &s[index..];
+        let (die, modifier) = match rest.find(['+', '-']) {
+            Some(sign) => {
+                let magnitude = rest[sign + 1..].trim();
+                if !magnitude.starts_with(|c: char| c.is_ascii_digit()) {
+                    return Err("Invalid dice modifier");
+                }
+                let magnitude = i16::from(
+                    magnitude
+                        .parse::<u8>()
+                        .map_err(|_| "Invalid dice modifier")?,
+                );
+                let modifier = if rest[sign..].starts_with('-') {
+                    -magnitude
+                } else {
+                    magnitude
+                };
+                let modifier = i8::try_from(modifier).map_err(|_| "Invalid dice modifier")?;
+                (&rest[..sign], modifier)
+            }
+            None => (rest, 0),
+        };
+        if count == 0 {
+            return Err("Dice count cannot be 0");
+        }
+        Ok(Self::new(count, die.trim().parse()?).with_modifier(modifier))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Dice {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Dice {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Dice;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a dice expression, such as \"2d6\" or \"1d6+1\"")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn die_sides() {
+        for die in Die::all() {
+            assert_eq!(Die::from_sides(die.sides()), Some(*die));
+        }
+    }
+
+    #[test]
+    fn die_parse() {
+        assert_eq!("d8".parse(), Ok(Die::D8));
+        assert_eq!("D100".parse(), Ok(Die::D100));
+        assert!("d7".parse::<Die>().is_err());
+        assert!("8".parse::<Die>().is_err());
+    }
+
+    #[test]
+    fn new_zero_is_flat() {
+        assert_eq!(Dice::new(0, Die::D6), Dice::flat(0));
+    }
+
+    #[test]
+    fn min_max_average() {
+        let cases = [
+            (Dice::new(1, Die::D4), 1, 4, 2),
+            (Dice::new(2, Die::D6), 2, 12, 7),
+            (Dice::new(1, Die::D6).with_modifier(1), 2, 7, 4),
+            (Dice::new(1, Die::D4).with_modifier(-1), 0, 3, 1),
+            (Dice::flat(1), 1, 1, 1),
+        ];
+        for (dice, min, max, average) in cases {
+            assert_eq!(dice.min(), min, "{dice}");
+            assert_eq!(dice.max(), max, "{dice}");
+            assert_eq!(dice.average(), average, "{dice}");
+        }
+    }
+
+    #[test]
+    fn roll() {
+        let dice = Dice::new(3, Die::D8).with_modifier(-2);
+        let mut rolls = [8, 1, 5].into_iter();
+        assert_eq!(dice.roll(|_| rolls.next().unwrap()), 12);
+        assert_eq!(dice.roll(|_| 20), 22);
+        assert_eq!(Dice::flat(1).roll(|_| unreachable!()), 1);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Dice::new(2, Die::D6).to_string(), "2d6");
+        assert_eq!(Dice::new(1, Die::D6).with_modifier(1).to_string(), "1d6+1");
+        assert_eq!(Dice::new(1, Die::D8).with_modifier(-1).to_string(), "1d8-1");
+        assert_eq!(Dice::flat(1).to_string(), "1");
+    }
+
+    #[test]
+    fn parse() {
+        let cases = [
+            ("2d6", Dice::new(2, Die::D6)),
+            ("d20", Dice::new(1, Die::D20)),
+            ("1d6+1", Dice::new(1, Die::D6).with_modifier(1)),
+            ("1d6 + 1", Dice::new(1, Die::D6).with_modifier(1)),
+            ("4D4-2", Dice::new(4, Die::D4).with_modifier(-2)),
+            ("1", Dice::flat(1)),
+            ("1d6-128", Dice::new(1, Die::D6).with_modifier(-128)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(input.parse(), Ok(expected), "{input}");
+        }
+        assert!("0d6".parse::<Dice>().is_err());
+        assert!("1d7".parse::<Dice>().is_err());
+        assert!("1d6+x".parse::<Dice>().is_err());
+        assert!("1d6--128".parse::<Dice>().is_err());
+        assert!("1d6+-1".parse::<Dice>().is_err());
+        assert!("1d6++1".parse::<Dice>().is_err());
+        assert!("1d6+128".parse::<Dice>().is_err());
+        assert!("".parse::<Dice>().is_err());
+    }
+
+    #[test]
+    fn parse_display_round_trip() {
+        for input in ["1d4", "2d6+3", "1d10-1", "7"] {
+            assert_eq!(input.parse::<Dice>().unwrap().to_string(), input);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let dice = Dice::new(2, Die::D6).with_modifier(1);
+        let serialized = serde_json::to_string(&dice).unwrap();
+        assert_eq!(serialized, "\"2d6+1\"");
+        let deserialized: Dice = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, dice);
+
+        let flat = Dice::flat(-1);
+        let serialized = serde_json::to_string(&flat).unwrap();
+        assert_eq!(serde_json::from_str::<Dice>(&serialized).unwrap(), flat);
+
+        assert!(serde_json::from_str::<Dice>("\"0d6\"").is_err());
+        let invalid = r#"{"count":0,"die":"D6","modifier":0}"#;
+        assert!(serde_json::from_str::<Dice>(invalid).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+use crate::core::{Abilities, ProficiencyBonus, RollMode, Skill, SkillProficiencies};
+
+/// The passive scores most often used by the DM to resolve checks without a roll.
+///
+/// Each passive score is 10 plus the bonus to checks with its skill, plus 5 with advantage or
+/// minus 5 with disadvantage.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{
+///     Abilities, AbilityScore, PassiveScores, ProficiencyBonus, RollMode, Skill,
+///     SkillProficiencies,
+/// };
+///
+/// let mut abilities = Abilities::new();
+/// abilities.wisdom = AbilityScore::new(14);
+///
+/// let mut profs = SkillProficiencies::new();
+/// profs.set_proficient(Skill::Perception);
+///
+/// let pb = ProficiencyBonus::new(2);
+/// let passive = PassiveScores::new(&profs, &abilities, pb);
+/// assert_eq!(passive.perception, 14);
+/// assert_eq!(passive.insight, 12);
+/// assert_eq!(passive.investigation, 10);
+///
+/// // Dim light imposes disadvantage on sight-based Perception.
+/// let dim = RollMode::Disadvantage;
+/// assert_eq!(PassiveScores::score(Skill::Perception, &profs, &abilities, pb, dim), 9);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PassiveScores {
+    /// Passive Wisdom (Perception), used to notice hidden creatures and objects.
+    pub perception: u8,
+
+    /// Passive Intelligence (Investigation), used to notice clues and see through illusions.
+    pub investigation: u8,
+
+    /// Passive Wisdom (Insight), used to notice lies and read intentions.
+    pub insight: u8,
+}
+
+impl PassiveScores {
+    /// Creates a new `PassiveScores` for a creature rolling normally.
+    #[must_use]
+    pub fn new(
+        proficiencies: &SkillProficiencies,
+        abilities: &Abilities,
+        proficiency_bonus: ProficiencyBonus,
+    ) -> Self {
+        let score = |skill| {
+            Self::score(
+                skill,
+                proficiencies,
+                abilities,
+                proficiency_bonus,
+                RollMode::Normal,
+            )
+        };
+        Self {
+            perception: score(Skill::Perception),
+            investigation: score(Skill::Investigation),
+            insight: score(Skill::Insight),
+        }
+    }
+
+    /// Returns the passive score for any skill, which never goes below 0.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn score(
+        skill: Skill,
+        proficiencies: &SkillProficiencies,
+        abilities: &Abilities,
+        proficiency_bonus: ProficiencyBonus,
+        mode: RollMode,
+    ) -> u8 {
+        let bonus = proficiencies.bonus(skill, abilities, proficiency_bonus);
+        (10 + bonus + mode.passive_modifier()).max(0) as u8
+    }
+
+    /// Returns the passive score for `skill`, if it is one of the first-class passive scores.
+    #[must_use]
+    pub const fn get(&self, skill: Skill) -> Option<u8> {
+        match skill {
+            Skill::Perception => Some(self.perception),
+            Skill::Investigation => Some(self.investigation),
+            Skill::Insight => Some(self.insight),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::AbilityScore;
+
+    #[test]
+    fn advantage_and_disadvantage() {
+        let mut abilities = Abilities::new();
+        abilities.intelligence = AbilityScore::new(16);
+        let mut profs = SkillProficiencies::new();
+        profs.set_expertise(Skill::Investigation);
+        let pb = ProficiencyBonus::new(3);
+
+        let score = |mode| PassiveScores::score(Skill::Investigation, &profs, &abilities, pb, mode);
+        assert_eq!(score(RollMode::Normal), 19);
+        assert_eq!(score(RollMode::Advantage), 24);
+        assert_eq!(score(RollMode::Disadvantage), 14);
+        assert_eq!(
+            PassiveScores::new(&profs, &abilities, pb).get(Skill::Investigation),
+            Some(19)
+        );
+        assert_eq!(PassiveScores::default().get(Skill::Stealth), None);
+    }
+
+    #[test]
+    fn never_negative() {
+        let mut abilities = Abilities::new();
+        abilities.wisdom = AbilityScore::new(1);
+        let score = PassiveScores::score(
+            Skill::Perception,
+            &SkillProficiencies::new(),
+            &abilities,
+            ProficiencyBonus::new(2),
+            RollMode::Disadvantage,
+        );
+        assert_eq!(score, 0);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::core::Level;
+use crate::core::{Die, Level};
 
 /// Represents a proficiency bonus.
 ///
@@ -72,6 +72,29 @@ impl ProficiencyBonus {
     pub const fn value(&self) -> u8 {
         self.0
     }
+
+    /// Returns the die rolled in place of the bonus under the proficiency dice variant rule.
+    ///
+    /// A bonus of +2 becomes a d4, +3 a d6, +4 a d8, +5 a d10, and +6 or higher a d12.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dnd::core::{Die, ProficiencyBonus};
+    ///
+    /// assert_eq!(ProficiencyBonus::new(2).die(), Die::D4);
+    /// assert_eq!(ProficiencyBonus::new(6).die(), Die::D12);
+    /// ```
+    #[must_use]
+    pub const fn die(&self) -> Die {
+        match self.0 {
+            0..=2 => Die::D4,
+            3 => Die::D6,
+            4 => Die::D8,
+            5 => Die::D10,
+            _ => Die::D12,
+        }
+    }
 }
 
 impl TryFrom<u8> for ProficiencyBonus {
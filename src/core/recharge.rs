@@ -0,0 +1,109 @@
+use core::{fmt::Display, str::FromStr};
+
+/// When a limited-use feature, item, or resource regains its uses.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::Recharge;
+///
+/// assert_eq!(Recharge::LongRest.name(), "Long Rest");
+/// assert!(Recharge::LongRest.on_long_rest());
+/// assert!(Recharge::ShortRest.on_long_rest());
+/// assert!(!Recharge::LongRest.on_short_rest());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Recharge {
+    /// Regained after finishing a short or long rest.
+    ShortRest,
+
+    /// Regained after finishing a long rest.
+    LongRest,
+
+    /// Regained each day at dawn.
+    Dawn,
+
+    /// Regained each day at dusk.
+    Dusk,
+}
+
+impl Recharge {
+    /// Returns an array containing all possible [`Recharge`] variants.
+    #[must_use]
+    pub const fn all() -> &'static [Recharge] {
+        &[
+            Recharge::ShortRest,
+            Recharge::LongRest,
+            Recharge::Dawn,
+            Recharge::Dusk,
+        ]
+    }
+
+    /// Returns the name of the recharge timing, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Recharge::ShortRest => "Short Rest",
+            Recharge::LongRest => "Long Rest",
+            Recharge::Dawn => "Dawn",
+            Recharge::Dusk => "Dusk",
+        }
+    }
+
+    /// Returns whether uses are regained after finishing a short rest.
+    #[must_use]
+    pub const fn on_short_rest(&self) -> bool {
+        matches!(self, Recharge::ShortRest)
+    }
+
+    /// Returns whether uses are regained after finishing a long rest.
+    ///
+    /// Anything regained on a short rest is also regained on a long rest.
+    #[must_use]
+    pub const fn on_long_rest(&self) -> bool {
+        matches!(self, Recharge::ShortRest | Recharge::LongRest)
+    }
+}
+
+impl Display for Recharge {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Recharge {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Recharge::all()
+            .iter()
+            .find(|recharge| recharge.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or("Unknown recharge")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        for recharge in Recharge::all() {
+            assert_eq!(recharge.to_string().parse(), Ok(*recharge));
+        }
+        assert_eq!("long rest".parse(), Ok(Recharge::LongRest));
+        assert!("Noon".parse::<Recharge>().is_err());
+    }
+
+    #[test]
+    fn rests() {
+        assert!(Recharge::ShortRest.on_short_rest());
+        assert!(!Recharge::Dawn.on_short_rest());
+        assert!(!Recharge::Dusk.on_long_rest());
+    }
+}
@@ -0,0 +1,49 @@
+use crate::core::Recharge;
+use core::fmt::Display;
+
+/// A period of downtime in which a creature recovers hit points and expended resources.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Recharge, Rest};
+///
+/// assert!(Rest::Long.recharges(Recharge::ShortRest));
+/// assert!(!Rest::Short.recharges(Recharge::LongRest));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Rest {
+    /// A period of at least 1 hour, in which hit dice can be spent to regain hit points.
+    Short,
+
+    /// A period of at least 8 hours, which restores hit points and most resources.
+    Long,
+}
+
+impl Rest {
+    /// Returns the name of the rest, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Rest::Short => "Short Rest",
+            Rest::Long => "Long Rest",
+        }
+    }
+
+    /// Returns whether finishing this rest regains uses of something with the given `recharge`.
+    #[must_use]
+    pub const fn recharges(&self, recharge: Recharge) -> bool {
+        match self {
+            Rest::Short => recharge.on_short_rest(),
+            Rest::Long => recharge.on_long_rest(),
+        }
+    }
+}
+
+impl Display for Rest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,131 @@
+/// Whether a d20 roll is made normally, with advantage, or with disadvantage.
+///
+/// Any number of sources of advantage and disadvantage cancel each other out, so a roll with
+/// at least one of each is made normally.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::RollMode;
+///
+/// assert_eq!(RollMode::new(true, false), RollMode::Advantage);
+/// assert_eq!(RollMode::combine([RollMode::Advantage, RollMode::Disadvantage]), RollMode::Normal);
+/// assert_eq!(RollMode::Disadvantage.passive_modifier(), -5);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum RollMode {
+    /// Roll one d20.
+    #[default]
+    Normal,
+
+    /// Roll two d20s and use the higher roll.
+    Advantage,
+
+    /// Roll two d20s and use the lower roll.
+    Disadvantage,
+}
+
+impl RollMode {
+    /// Creates a new `RollMode` from whether any source grants advantage or imposes disadvantage.
+    #[must_use]
+    pub const fn new(advantage: bool, disadvantage: bool) -> Self {
+        match (advantage, disadvantage) {
+            (true, false) => RollMode::Advantage,
+            (false, true) => RollMode::Disadvantage,
+            _ => RollMode::Normal,
+        }
+    }
+
+    /// Returns the mode of a roll affected by every one of `modes`.
+    ///
+    /// Advantage and disadvantage cancel out, and multiple sources of either don't stack, so
+    /// the roll is made normally if `modes` include both, in any number and order.
+    #[must_use]
+    pub fn combine(modes: impl IntoIterator<Item = RollMode>) -> Self {
+        let (advantage, disadvantage) = modes.into_iter().fold(
+            (false, false),
+            |(advantage, disadvantage), mode| match mode {
+                RollMode::Normal => (advantage, disadvantage),
+                RollMode::Advantage => (true, disadvantage),
+                RollMode::Disadvantage => (advantage, true),
+            },
+        );
+        Self::new(advantage, disadvantage)
+    }
+
+    /// Returns the modifier applied to passive scores, which is +5 for advantage and -5 for
+    /// disadvantage.
+    #[must_use]
+    pub const fn passive_modifier(&self) -> i8 {
+        match self {
+            RollMode::Normal => 0,
+            RollMode::Advantage => 5,
+            RollMode::Disadvantage => -5,
+        }
+    }
+
+    /// Returns the result of rolling a d20 in this mode, given two rolls of the die.
+    ///
+    /// The second roll is ignored when rolling normally.
+    #[must_use]
+    pub const fn pick(&self, first: u8, second: u8) -> u8 {
+        match self {
+            RollMode::Normal => first,
+            RollMode::Advantage => {
+                if first > second {
+                    first
+                } else {
+                    second
+                }
+            }
+            RollMode::Disadvantage => {
+                if first < second {
+                    first
+                } else {
+                    second
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combine() {
+        let modes = [
+            RollMode::Normal,
+            RollMode::Advantage,
+            RollMode::Disadvantage,
+        ];
+        for mode in modes {
+            assert_eq!(RollMode::combine([RollMode::Normal, mode]), mode);
+            assert_eq!(RollMode::combine([mode, mode]), mode);
+        }
+        assert_eq!(
+            RollMode::combine([RollMode::Disadvantage, RollMode::Advantage]),
+            RollMode::Normal
+        );
+        assert_eq!(
+            RollMode::combine([
+                RollMode::Advantage,
+                RollMode::Disadvantage,
+                RollMode::Advantage
+            ]),
+            RollMode::Normal
+        );
+        assert_eq!(RollMode::combine([]), RollMode::Normal);
+        assert_eq!(RollMode::new(true, true), RollMode::Normal);
+    }
+
+    #[test]
+    fn pick() {
+        assert_eq!(RollMode::Normal.pick(4, 17), 4);
+        assert_eq!(RollMode::Advantage.pick(4, 17), 17);
+        assert_eq!(RollMode::Disadvantage.pick(4, 17), 4);
+    }
+}
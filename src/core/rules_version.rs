@@ -0,0 +1,111 @@
+use core::{fmt::Display, str::FromStr};
+
+/// The edition of the fifth edition rules a campaign is played with.
+///
+/// Several mechanics differ between the 2014 Player's Handbook and its 2024 revision, such as
+/// exhaustion, ability score increases from species, weapon mastery, surprise, and grappling.
+/// Each system that depends on the rules consults the version chosen for the campaign.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::RulesVersion;
+///
+/// let rules = RulesVersion::default();
+/// assert_eq!(rules, RulesVersion::Phb2014);
+/// assert!(rules.species_increase_abilities());
+/// assert!(!rules.weapon_mastery());
+///
+/// assert_eq!("2024".parse(), Ok(RulesVersion::Phb2024));
+/// assert_eq!(RulesVersion::Phb2024.to_string(), "2024");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum RulesVersion {
+    /// The rules of the 2014 Player's Handbook, and the SRD 5.1.
+    #[default]
+    Phb2014,
+
+    /// The rules of the 2024 Player's Handbook, and the SRD 5.2.
+    Phb2024,
+}
+
+impl RulesVersion {
+    /// Returns an array containing all possible [`RulesVersion`] variants.
+    #[must_use]
+    pub const fn all() -> &'static [RulesVersion] {
+        &[RulesVersion::Phb2014, RulesVersion::Phb2024]
+    }
+
+    /// Returns the year the rules were published, such as "2014".
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            RulesVersion::Phb2014 => "2014",
+            RulesVersion::Phb2024 => "2024",
+        }
+    }
+
+    /// Returns whether a character's species increases its ability scores.
+    ///
+    /// Under the 2024 rules, the increases come from the background instead.
+    #[must_use]
+    pub const fn species_increase_abilities(&self) -> bool {
+        matches!(self, RulesVersion::Phb2014)
+    }
+
+    /// Returns whether weapons have mastery properties that characters with the Weapon Mastery
+    /// feature can use, which is only under the 2024 rules.
+    #[must_use]
+    pub const fn weapon_mastery(&self) -> bool {
+        matches!(self, RulesVersion::Phb2024)
+    }
+
+    /// Returns whether a surprised creature can't move or act on its first turn of combat.
+    ///
+    /// Under the 2024 rules, it has disadvantage on its initiative roll instead.
+    #[must_use]
+    pub const fn surprise_skips_first_turn(&self) -> bool {
+        matches!(self, RulesVersion::Phb2014)
+    }
+}
+
+impl Display for RulesVersion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl AsRef<str> for RulesVersion {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl FromStr for RulesVersion {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RulesVersion::all()
+            .iter()
+            .find(|rules| rules.name() == s)
+            .copied()
+            .ok_or("Unknown rules version")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        for rules in RulesVersion::all() {
+            assert_eq!(rules.to_string().parse(), Ok(*rules));
+        }
+        assert!("2019".parse::<RulesVersion>().is_err());
+    }
+}
@@ -1,4 +1,8 @@
-use crate::core::Skill;
+#[cfg(feature = "alloc")]
+use crate::core::SkillRegistry;
+use crate::core::{Abilities, Ability, ProficiencyBonus, Skill};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
 use enumflags2::BitFlags;
 
 /// Represents the proficiency level a creature has in a skill.
@@ -19,6 +23,12 @@ pub enum SkillLevel {
 /// - [`SkillLevel::Proficient`]; the creature is proficient in the skill.
 /// - [`SkillLevel::Expertise`]; the creature has expertise in the skill.
 ///
+/// Proficiencies in the standard skills are stored as bitflags. With the `alloc` feature,
+/// proficiencies in [`CustomSkill`]s registered in a [`SkillRegistry`] are stored alongside
+/// them, by name.
+///
+/// [`CustomSkill`]: crate::core::CustomSkill
+///
 /// # Examples
 ///
 /// ```rust
@@ -44,6 +54,12 @@ pub enum SkillLevel {
 pub struct SkillProficiencies {
     proficient: BitFlags<Skill>,
     expertise: BitFlags<Skill>,
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    custom: Vec<(Cow<'static, str>, SkillLevel)>,
 }
 
 impl SkillProficiencies {
@@ -53,6 +69,8 @@ impl SkillProficiencies {
         Self {
             proficient: BitFlags::EMPTY,
             expertise: BitFlags::EMPTY,
+            #[cfg(feature = "alloc")]
+            custom: Vec::new(),
         }
     }
 
@@ -125,6 +143,22 @@ impl SkillProficiencies {
         self.set_proficiency(skill, SkillLevel::Proficient)
     }
 
+    /// Adds proficiency in each of the given skills, keeping expertise in any that already have
+    /// it.
+    ///
+    /// Returns the skills that were already proficient or had expertise.
+    pub fn add_proficiencies(&mut self, skills: BitFlags<Skill>) -> BitFlags<Skill> {
+        let overlap = skills & (self.proficient | self.expertise);
+        self.proficient |= skills & !self.expertise;
+        overlap
+    }
+
+    /// Adds proficiency in each of the given skills, like [`Self::add_proficiencies`], and
+    /// returns those already proficient as [`SkillReplacements`] the player can choose instead.
+    pub fn grant(&mut self, skills: BitFlags<Skill>) -> SkillReplacements {
+        SkillReplacements::new(self.add_proficiencies(skills))
+    }
+
     /// Sets the proficiency level for the given skill to `Expertise`.
     ///
     /// This will remove any existing proficiency for the skill.
@@ -143,9 +177,92 @@ impl SkillProficiencies {
     pub fn clear_all(&mut self) -> &mut Self {
         self.proficient = BitFlags::EMPTY;
         self.expertise = BitFlags::EMPTY;
+        #[cfg(feature = "alloc")]
+        self.custom.clear();
         self
     }
 
+    /// Returns the bonus added to ability checks with the given skill.
+    ///
+    /// This is the modifier of the skill's ability, plus the proficiency bonus if proficient, or
+    /// twice the proficiency bonus with expertise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dnd::core::{Abilities, AbilityScore, ProficiencyBonus, Skill, SkillProficiencies};
+    ///
+    /// let mut abilities = Abilities::new();
+    /// abilities.dexterity = AbilityScore::new(14);
+    ///
+    /// let mut profs = SkillProficiencies::new();
+    /// profs.set_expertise(Skill::Stealth);
+    ///
+    /// let bonus = ProficiencyBonus::new(2);
+    /// assert_eq!(profs.bonus(Skill::Stealth, &abilities, bonus), 6);
+    /// assert_eq!(profs.bonus(Skill::Acrobatics, &abilities, bonus), 2);
+    /// ```
+    #[must_use]
+    pub fn bonus(
+        &self,
+        skill: Skill,
+        abilities: &Abilities,
+        proficiency_bonus: ProficiencyBonus,
+    ) -> i8 {
+        self.bonus_with(skill.ability(), skill, abilities, proficiency_bonus)
+    }
+
+    /// Returns the bonus added to ability checks with the given skill, using the modifier of
+    /// `ability` in place of the skill's usual ability, such as a Strength (Intimidation) check.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dnd::core::{Abilities, Ability, AbilityScore, ProficiencyBonus, Skill, SkillProficiencies};
+    ///
+    /// let mut abilities = Abilities::new();
+    /// abilities.strength = AbilityScore::new(18);
+    ///
+    /// let mut profs = SkillProficiencies::new();
+    /// profs.set_proficient(Skill::Intimidation);
+    ///
+    /// let bonus = ProficiencyBonus::new(2);
+    /// assert_eq!(profs.bonus(Skill::Intimidation, &abilities, bonus), 2);
+    /// assert_eq!(profs.bonus_with(Ability::Strength, Skill::Intimidation, &abilities, bonus), 6);
+    /// ```
+    #[must_use]
+    pub fn bonus_with(
+        &self,
+        ability: Ability,
+        skill: Skill,
+        abilities: &Abilities,
+        proficiency_bonus: ProficiencyBonus,
+    ) -> i8 {
+        Self::check_bonus(
+            self.get_proficiency(skill),
+            ability,
+            abilities,
+            proficiency_bonus,
+        )
+    }
+
+    /// Returns the modifier of `ability`, plus the proficiency bonus for `level`.
+    #[allow(clippy::cast_possible_wrap)]
+    fn check_bonus(
+        level: Option<SkillLevel>,
+        ability: Ability,
+        abilities: &Abilities,
+        proficiency_bonus: ProficiencyBonus,
+    ) -> i8 {
+        let modifier = abilities[ability].modifier().value();
+        let proficiency_bonus = proficiency_bonus.value() as i8;
+        match level {
+            None => modifier,
+            Some(SkillLevel::Proficient) => modifier + proficiency_bonus,
+            Some(SkillLevel::Expertise) => modifier + proficiency_bonus * 2,
+        }
+    }
+
     /// Returns an iterator over all proficient skills, including those with expertise.
     pub fn iter(&self) -> impl Iterator<Item = (Skill, SkillLevel)> + '_ {
         Skill::all().iter().filter_map(move |&skill| {
@@ -158,6 +275,154 @@ impl SkillProficiencies {
             }
         })
     }
+
+    /// Returns the proficiency level for the custom skill with the given name, ignoring case.
+    ///
+    /// If the creature does not have proficiency or expertise in the skill, it returns `None`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn get_custom_proficiency(&self, name: &str) -> Option<SkillLevel> {
+        self.custom
+            .iter()
+            .find(|(custom, _)| custom.eq_ignore_ascii_case(name))
+            .map(|&(_, level)| level)
+    }
+
+    /// Sets the proficiency level for the custom skill with the given name, which must be
+    /// registered in `registry`. The skill is stored under its registered name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `registry` has no custom skill with the given name.
+    #[cfg(feature = "alloc")]
+    pub fn set_custom_proficiency(
+        &mut self,
+        registry: &SkillRegistry,
+        name: &str,
+        proficiency: SkillLevel,
+    ) -> Result<&mut Self, &'static str> {
+        let skill = registry.get(name).ok_or("Unknown custom skill")?;
+        match self
+            .custom
+            .iter_mut()
+            .find(|(custom, _)| custom.eq_ignore_ascii_case(name))
+        {
+            Some((_, level)) => *level = proficiency,
+            None => self.custom.push((skill.name.clone(), proficiency)),
+        }
+        Ok(self)
+    }
+
+    /// Clears the proficiency for the custom skill with the given name, ignoring case.
+    #[cfg(feature = "alloc")]
+    pub fn clear_custom_proficiency(&mut self, name: &str) -> &mut Self {
+        self.custom
+            .retain(|(custom, _)| !custom.eq_ignore_ascii_case(name));
+        self
+    }
+
+    /// Returns the bonus added to ability checks with the custom skill with the given name, like
+    /// [`Self::bonus`], or `None` if `registry` has no such skill.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn custom_bonus(
+        &self,
+        registry: &SkillRegistry,
+        name: &str,
+        abilities: &Abilities,
+        proficiency_bonus: ProficiencyBonus,
+    ) -> Option<i8> {
+        let ability = registry.get(name)?.ability;
+        Some(Self::check_bonus(
+            self.get_custom_proficiency(name),
+            ability,
+            abilities,
+            proficiency_bonus,
+        ))
+    }
+
+    /// Returns an iterator over the names of all proficient custom skills, including those with
+    /// expertise.
+    #[cfg(feature = "alloc")]
+    pub fn iter_custom(&self) -> impl Iterator<Item = (&str, SkillLevel)> + '_ {
+        self.custom.iter().map(|(name, level)| (&**name, *level))
+    }
+}
+
+/// Skills granted a second time, which the player can replace with other skills.
+///
+/// When a character would gain proficiency in the same skill from two sources, such as their
+/// class and background, they can choose a different skill proficiency instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Skill, SkillProficiencies, SkillReplacements};
+///
+/// let mut profs = SkillProficiencies::new();
+/// profs.set_proficient(Skill::Stealth);
+///
+/// let mut replacements = profs.grant(Skill::Stealth | Skill::SleightOfHand);
+/// assert_eq!(replacements.remaining(), 1);
+///
+/// assert!(replacements.replace(Skill::Stealth, Skill::SleightOfHand, &mut profs).is_err());
+/// replacements.replace(Skill::Stealth, Skill::Acrobatics, &mut profs).unwrap();
+/// assert!(profs.is_proficient(Skill::Acrobatics));
+/// assert!(replacements.is_empty());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[must_use = "duplicate skills can be replaced with skills of the player's choice"]
+pub struct SkillReplacements {
+    duplicates: BitFlags<Skill>,
+}
+
+impl SkillReplacements {
+    /// Creates a new `SkillReplacements` for the given duplicate skills.
+    pub const fn new(duplicates: BitFlags<Skill>) -> Self {
+        Self { duplicates }
+    }
+
+    /// Returns the duplicate skills that have not been replaced yet.
+    #[must_use]
+    pub const fn duplicates(&self) -> BitFlags<Skill> {
+        self.duplicates
+    }
+
+    /// Returns how many skills can still be chosen as replacements.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.duplicates.len()
+    }
+
+    /// Returns `true` if there are no duplicate skills left to replace.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.duplicates.is_empty()
+    }
+
+    /// Replaces `duplicate` with proficiency in `replacement`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `duplicate` is not a skill left to replace, or `profs` is already
+    /// proficient in `replacement`.
+    pub fn replace(
+        &mut self,
+        duplicate: Skill,
+        replacement: Skill,
+        profs: &mut SkillProficiencies,
+    ) -> Result<(), &'static str> {
+        if !self.duplicates.contains(duplicate) {
+            return Err("Skill is not a duplicate to replace");
+        }
+        if profs.get_proficiency(replacement).is_some() {
+            return Err("Already proficient in the replacement skill");
+        }
+        profs.set_proficient(replacement);
+        self.duplicates.remove(duplicate);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -203,6 +468,19 @@ mod tests {
         assert_eq!(&skills, &[(Skill::Acrobatics, SkillLevel::Expertise)]);
     }
 
+    #[test]
+    fn add_proficiencies() {
+        let mut profs = SkillProficiencies::new();
+        profs.set_proficient(Skill::Acrobatics);
+        profs.set_expertise(Skill::Stealth);
+
+        let overlap = profs.add_proficiencies(Skill::Stealth | Skill::Acrobatics | Skill::Arcana);
+        assert_eq!(overlap, Skill::Stealth | Skill::Acrobatics);
+        assert!(profs.has_expertise(Skill::Stealth));
+        assert!(profs.is_proficient(Skill::Acrobatics));
+        assert!(profs.is_proficient(Skill::Arcana));
+    }
+
     #[test]
     fn clear_proficiency() {
         let mut profs = SkillProficiencies::new();
@@ -259,4 +537,80 @@ mod tests {
         assert!(profs.is_proficient(Skill::Acrobatics));
         assert!(profs.has_expertise(Skill::Stealth));
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn custom_proficiency() {
+        use crate::core::{Ability, AbilityScore, CustomSkill};
+
+        let mut registry = SkillRegistry::new();
+        registry
+            .register(CustomSkill::new("Engineering", Ability::Intelligence))
+            .unwrap();
+        let mut profs = SkillProficiencies::new();
+        assert_eq!(
+            profs
+                .set_custom_proficiency(&registry, "Stealth", SkillLevel::Proficient)
+                .unwrap_err(),
+            "Unknown custom skill"
+        );
+        profs
+            .set_custom_proficiency(&registry, "engineering", SkillLevel::Proficient)
+            .unwrap()
+            .set_custom_proficiency(&registry, "Engineering", SkillLevel::Expertise)
+            .unwrap();
+        assert_eq!(
+            profs.get_custom_proficiency("engineering"),
+            Some(SkillLevel::Expertise)
+        );
+        assert!(
+            profs
+                .iter_custom()
+                .eq([("Engineering", SkillLevel::Expertise)])
+        );
+        assert!(profs.iter().next().is_none());
+
+        let mut abilities = Abilities::new();
+        abilities.intelligence = AbilityScore::new(14);
+        let bonus = ProficiencyBonus::new(2);
+        assert_eq!(
+            profs.custom_bonus(&registry, "Engineering", &abilities, bonus),
+            Some(6)
+        );
+        assert_eq!(
+            profs.custom_bonus(&registry, "Sailing", &abilities, bonus),
+            None
+        );
+
+        profs.clear_custom_proficiency("ENGINEERING");
+        assert_eq!(profs.get_custom_proficiency("Engineering"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        use crate::core::{Ability, CustomSkill};
+
+        let mut registry = SkillRegistry::new();
+        registry
+            .register(CustomSkill::new("Engineering", Ability::Intelligence))
+            .unwrap();
+
+        let mut profs = SkillProficiencies::new();
+        profs.set_expertise(Skill::Stealth);
+        let serialized = serde_json::to_string(&profs).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SkillProficiencies>(&serialized).unwrap(),
+            profs
+        );
+
+        profs
+            .set_custom_proficiency(&registry, "Engineering", SkillLevel::Proficient)
+            .unwrap();
+        let serialized = serde_json::to_string(&profs).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SkillProficiencies>(&serialized).unwrap(),
+            profs
+        );
+    }
 }
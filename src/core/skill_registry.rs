@@ -0,0 +1,77 @@
+use crate::core::{Ability, CustomSkill, Skill};
+use alloc::vec::Vec;
+
+/// The skills used in a campaign: the standard [`Skill`]s, plus any [`CustomSkill`]s.
+///
+/// The standard skills are always available, and a custom skill can't share a name with any
+/// other skill. Names are compared ignoring ASCII case. Proficiencies and checks in custom skills are only allowed for skills in the
+/// registry, such as with [`SkillProficiencies::set_custom_proficiency`].
+///
+/// [`SkillProficiencies::set_custom_proficiency`]: crate::core::SkillProficiencies::set_custom_proficiency
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Ability, CustomSkill, SkillRegistry};
+///
+/// let mut registry = SkillRegistry::new();
+/// registry.register(CustomSkill::new("Engineering", Ability::Intelligence)).unwrap();
+/// assert!(registry.register(CustomSkill::new("Stealth", Ability::Wisdom)).is_err());
+///
+/// assert!(registry.register(CustomSkill::new("engineering", Ability::Wisdom)).is_err());
+///
+/// assert_eq!(registry.ability("Engineering"), Some(Ability::Intelligence));
+/// assert_eq!(registry.ability("Stealth"), Some(Ability::Dexterity));
+/// assert_eq!(registry.ability("Sailing"), None);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkillRegistry {
+    custom: Vec<CustomSkill>,
+}
+
+impl SkillRegistry {
+    /// Creates a new `SkillRegistry` with only the standard skills.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { custom: Vec::new() }
+    }
+
+    /// Adds `skill` to the registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a standard or custom skill with the same name already exists.
+    pub fn register(&mut self, skill: CustomSkill) -> Result<&mut Self, &'static str> {
+        if self.ability(&skill.name).is_some() {
+            return Err("Skill already exists");
+        }
+        self.custom.push(skill);
+        Ok(self)
+    }
+
+    /// Returns the custom skill with the given name, ignoring case, if it has been registered.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&CustomSkill> {
+        self.custom
+            .iter()
+            .find(|skill| skill.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the ability of the standard or custom skill with the given name, ignoring case.
+    #[must_use]
+    pub fn ability(&self, name: &str) -> Option<Ability> {
+        match Skill::all()
+            .iter()
+            .find(|skill| skill.name().eq_ignore_ascii_case(name))
+        {
+            Some(skill) => Some(skill.ability()),
+            None => self.get(name).map(|skill| skill.ability),
+        }
+    }
+
+    /// Returns an iterator over the registered custom skills.
+    pub fn iter(&self) -> impl Iterator<Item = &CustomSkill> {
+        self.custom.iter()
+    }
+}
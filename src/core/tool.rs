@@ -0,0 +1,366 @@
+use core::{fmt::Display, str::FromStr};
+use enumflags2::bitflags;
+
+/// A kind of [`Tool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum ToolCategory {
+    /// Tools used to pursue a craft or trade, such as smith's tools.
+    ArtisansTools,
+
+    /// A set of dice, cards, or other pieces for playing a game.
+    GamingSet,
+
+    /// An instrument for playing music.
+    MusicalInstrument,
+
+    /// A kind of vehicle to drive, pilot, or sail.
+    Vehicle,
+
+    /// Any other tool, such as thieves' tools.
+    Other,
+}
+
+impl ToolCategory {
+    /// Returns the name of the tool category, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ToolCategory::ArtisansTools => "Artisan's Tools",
+            ToolCategory::GamingSet => "Gaming Set",
+            ToolCategory::MusicalInstrument => "Musical Instrument",
+            ToolCategory::Vehicle => "Vehicle",
+            ToolCategory::Other => "Other Tool",
+        }
+    }
+}
+
+impl Display for ToolCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A tool, gaming set, instrument, or vehicle a creature can be proficient with.
+///
+/// Unlike a [`Skill`][crate::core::Skill], a tool isn't tied to one ability: the ability used
+/// for a check with it depends on the situation.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Tool, ToolCategory};
+///
+/// assert_eq!(Tool::ThievesTools.name(), "Thieves' Tools");
+/// assert_eq!(Tool::SmithsTools.category(), ToolCategory::ArtisansTools);
+/// assert_eq!("lute".parse(), Ok(Tool::Lute));
+/// ```
+#[bitflags]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u64)]
+pub enum Tool {
+    /// Alchemist's supplies.
+    AlchemistsSupplies,
+
+    /// Brewer's supplies.
+    BrewersSupplies,
+
+    /// Calligrapher's supplies.
+    CalligraphersSupplies,
+
+    /// Carpenter's tools.
+    CarpentersTools,
+
+    /// Cartographer's tools.
+    CartographersTools,
+
+    /// Cobbler's tools.
+    CobblersTools,
+
+    /// Cook's utensils.
+    CooksUtensils,
+
+    /// Glassblower's tools.
+    GlassblowersTools,
+
+    /// Jeweler's tools.
+    JewelersTools,
+
+    /// Leatherworker's tools.
+    LeatherworkersTools,
+
+    /// Mason's tools.
+    MasonsTools,
+
+    /// Painter's supplies.
+    PaintersSupplies,
+
+    /// Potter's tools.
+    PottersTools,
+
+    /// Smith's tools.
+    SmithsTools,
+
+    /// Tinker's tools.
+    TinkersTools,
+
+    /// Weaver's tools.
+    WeaversTools,
+
+    /// Woodcarver's tools.
+    WoodcarversTools,
+
+    /// A set of dice.
+    DiceSet,
+
+    /// A dragonchess set.
+    DragonchessSet,
+
+    /// A deck of playing cards.
+    PlayingCardSet,
+
+    /// A Three-Dragon Ante set.
+    ThreeDragonAnteSet,
+
+    /// Bagpipes.
+    Bagpipes,
+
+    /// A drum.
+    Drum,
+
+    /// A dulcimer.
+    Dulcimer,
+
+    /// A flute.
+    Flute,
+
+    /// A horn.
+    Horn,
+
+    /// A lute.
+    Lute,
+
+    /// A lyre.
+    Lyre,
+
+    /// A pan flute.
+    PanFlute,
+
+    /// A shawm.
+    Shawm,
+
+    /// A viol.
+    Viol,
+
+    /// Land vehicles, such as carts and chariots.
+    LandVehicles,
+
+    /// Water vehicles, such as rowboats and ships.
+    WaterVehicles,
+
+    /// A disguise kit.
+    DisguiseKit,
+
+    /// A forgery kit.
+    ForgeryKit,
+
+    /// An herbalism kit.
+    HerbalismKit,
+
+    /// Navigator's tools.
+    NavigatorsTools,
+
+    /// A poisoner's kit.
+    PoisonersKit,
+
+    /// Thieves' tools.
+    ThievesTools,
+}
+
+impl Tool {
+    /// Returns an array containing all possible [`Tool`] variants, grouped by category.
+    #[must_use]
+    pub const fn all() -> &'static [Tool] {
+        &[
+            Tool::AlchemistsSupplies,
+            Tool::BrewersSupplies,
+            Tool::CalligraphersSupplies,
+            Tool::CarpentersTools,
+            Tool::CartographersTools,
+            Tool::CobblersTools,
+            Tool::CooksUtensils,
+            Tool::GlassblowersTools,
+            Tool::JewelersTools,
+            Tool::LeatherworkersTools,
+            Tool::MasonsTools,
+            Tool::PaintersSupplies,
+            Tool::PottersTools,
+            Tool::SmithsTools,
+            Tool::TinkersTools,
+            Tool::WeaversTools,
+            Tool::WoodcarversTools,
+            Tool::DiceSet,
+            Tool::DragonchessSet,
+            Tool::PlayingCardSet,
+            Tool::ThreeDragonAnteSet,
+            Tool::Bagpipes,
+            Tool::Drum,
+            Tool::Dulcimer,
+            Tool::Flute,
+            Tool::Horn,
+            Tool::Lute,
+            Tool::Lyre,
+            Tool::PanFlute,
+            Tool::Shawm,
+            Tool::Viol,
+            Tool::LandVehicles,
+            Tool::WaterVehicles,
+            Tool::DisguiseKit,
+            Tool::ForgeryKit,
+            Tool::HerbalismKit,
+            Tool::NavigatorsTools,
+            Tool::PoisonersKit,
+            Tool::ThievesTools,
+        ]
+    }
+
+    /// Returns the name of the tool, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Tool::AlchemistsSupplies => "Alchemist's Supplies",
+            Tool::BrewersSupplies => "Brewer's Supplies",
+            Tool::CalligraphersSupplies => "Calligrapher's Supplies",
+            Tool::CarpentersTools => "Carpenter's Tools",
+            Tool::CartographersTools => "Cartographer's Tools",
+            Tool::CobblersTools => "Cobbler's Tools",
+            Tool::CooksUtensils => "Cook's Utensils",
+            Tool::GlassblowersTools => "Glassblower's Tools",
+            Tool::JewelersTools => "Jeweler's Tools",
+            Tool::LeatherworkersTools => "Leatherworker's Tools",
+            Tool::MasonsTools => "Mason's Tools",
+            Tool::PaintersSupplies => "Painter's Supplies",
+            Tool::PottersTools => "Potter's Tools",
+            Tool::SmithsTools => "Smith's Tools",
+            Tool::TinkersTools => "Tinker's Tools",
+            Tool::WeaversTools => "Weaver's Tools",
+            Tool::WoodcarversTools => "Woodcarver's Tools",
+            Tool::DiceSet => "Dice Set",
+            Tool::DragonchessSet => "Dragonchess Set",
+            Tool::PlayingCardSet => "Playing Card Set",
+            Tool::ThreeDragonAnteSet => "Three-Dragon Ante Set",
+            Tool::Bagpipes => "Bagpipes",
+            Tool::Drum => "Drum",
+            Tool::Dulcimer => "Dulcimer",
+            Tool::Flute => "Flute",
+            Tool::Horn => "Horn",
+            Tool::Lute => "Lute",
+            Tool::Lyre => "Lyre",
+            Tool::PanFlute => "Pan Flute",
+            Tool::Shawm => "Shawm",
+            Tool::Viol => "Viol",
+            Tool::LandVehicles => "Land Vehicles",
+            Tool::WaterVehicles => "Water Vehicles",
+            Tool::DisguiseKit => "Disguise Kit",
+            Tool::ForgeryKit => "Forgery Kit",
+            Tool::HerbalismKit => "Herbalism Kit",
+            Tool::NavigatorsTools => "Navigator's Tools",
+            Tool::PoisonersKit => "Poisoner's Kit",
+            Tool::ThievesTools => "Thieves' Tools",
+        }
+    }
+
+    /// Returns the category of the tool.
+    #[must_use]
+    pub const fn category(&self) -> ToolCategory {
+        match self {
+            Tool::AlchemistsSupplies
+            | Tool::BrewersSupplies
+            | Tool::CalligraphersSupplies
+            | Tool::CarpentersTools
+            | Tool::CartographersTools
+            | Tool::CobblersTools
+            | Tool::CooksUtensils
+            | Tool::GlassblowersTools
+            | Tool::JewelersTools
+            | Tool::LeatherworkersTools
+            | Tool::MasonsTools
+            | Tool::PaintersSupplies
+            | Tool::PottersTools
+            | Tool::SmithsTools
+            | Tool::TinkersTools
+            | Tool::WeaversTools
+            | Tool::WoodcarversTools => ToolCategory::ArtisansTools,
+            Tool::DiceSet
+            | Tool::DragonchessSet
+            | Tool::PlayingCardSet
+            | Tool::ThreeDragonAnteSet => ToolCategory::GamingSet,
+            Tool::Bagpipes
+            | Tool::Drum
+            | Tool::Dulcimer
+            | Tool::Flute
+            | Tool::Horn
+            | Tool::Lute
+            | Tool::Lyre
+            | Tool::PanFlute
+            | Tool::Shawm
+            | Tool::Viol => ToolCategory::MusicalInstrument,
+            Tool::LandVehicles | Tool::WaterVehicles => ToolCategory::Vehicle,
+            Tool::DisguiseKit
+            | Tool::ForgeryKit
+            | Tool::HerbalismKit
+            | Tool::NavigatorsTools
+            | Tool::PoisonersKit
+            | Tool::ThievesTools => ToolCategory::Other,
+        }
+    }
+}
+
+impl Display for Tool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl AsRef<str> for Tool {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl FromStr for Tool {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tool::all()
+            .iter()
+            .find(|tool| tool.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or("Unknown tool")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        for tool in Tool::all() {
+            assert_eq!(tool.to_string().parse(), Ok(*tool));
+        }
+        assert!("Spyglass".parse::<Tool>().is_err());
+    }
+
+    #[test]
+    fn category() {
+        assert_eq!(Tool::ThievesTools.category(), ToolCategory::Other);
+        assert_eq!(Tool::DiceSet.category(), ToolCategory::GamingSet);
+        assert_eq!(Tool::Viol.category(), ToolCategory::MusicalInstrument);
+        assert_eq!(Tool::WaterVehicles.category().to_string(), "Vehicle");
+    }
+}
@@ -0,0 +1,217 @@
+use crate::core::{
+    Abilities, Ability, ProficiencyBonus, RollMode, Skill, SkillLevel, SkillProficiencies, Tool,
+};
+use enumflags2::BitFlags;
+
+/// What tool proficiencies a creature has.
+///
+/// This type acts as a set of tools, where each tool can either be:
+/// - [`SkillLevel::Proficient`]; the creature is proficient with the tool.
+/// - [`SkillLevel::Expertise`]; the creature has expertise with the tool.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{
+///     Abilities, Ability, AbilityScore, ProficiencyBonus, RollMode, Skill, SkillProficiencies,
+///     Tool, ToolProficiencies,
+/// };
+///
+/// let mut tools = ToolProficiencies::new();
+/// tools.set_expertise(Tool::ThievesTools);
+///
+/// let mut abilities = Abilities::new();
+/// abilities.dexterity = AbilityScore::new(16);
+/// let bonus = ProficiencyBonus::new(2);
+/// assert_eq!(tools.bonus(Tool::ThievesTools, Ability::Dexterity, &abilities, bonus), 7);
+///
+/// // Proficiency in both the tool and a skill that applies grants advantage.
+/// let mut skills = SkillProficiencies::new();
+/// skills.set_proficient(Skill::SleightOfHand);
+/// assert_eq!(
+///     tools.mode_with_skill(Tool::ThievesTools, Skill::SleightOfHand, &skills),
+///     RollMode::Advantage
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ToolProficiencies {
+    proficient: BitFlags<Tool>,
+    expertise: BitFlags<Tool>,
+}
+
+impl ToolProficiencies {
+    /// Creates a new `ToolProficiencies` with no proficiencies.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            proficient: BitFlags::EMPTY,
+            expertise: BitFlags::EMPTY,
+        }
+    }
+
+    /// Returns whether the creature is proficient with the given tool.
+    ///
+    /// If the creature has expertise with the tool, this will return `false`.
+    #[must_use]
+    pub fn is_proficient(&self, tool: Tool) -> bool {
+        self.proficient.contains(tool)
+    }
+
+    /// Returns whether the creature has expertise with the given tool.
+    #[must_use]
+    pub fn has_expertise(&self, tool: Tool) -> bool {
+        self.expertise.contains(tool)
+    }
+
+    /// Returns the proficiency level for the given tool.
+    ///
+    /// If the creature does not have proficiency or expertise with the tool, it returns `None`.
+    #[must_use]
+    pub fn get_proficiency(&self, tool: Tool) -> Option<SkillLevel> {
+        if self.has_expertise(tool) {
+            Some(SkillLevel::Expertise)
+        } else if self.is_proficient(tool) {
+            Some(SkillLevel::Proficient)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the proficiency level for the given tool.
+    pub fn set_proficiency(&mut self, tool: Tool, proficiency: SkillLevel) -> &mut Self {
+        match proficiency {
+            SkillLevel::Proficient => {
+                self.proficient.insert(tool);
+                self.expertise.remove(tool);
+            }
+            SkillLevel::Expertise => {
+                self.expertise.insert(tool);
+                self.proficient.remove(tool);
+            }
+        }
+        self
+    }
+
+    /// Sets the proficiency level for the given tool to `Proficient`.
+    ///
+    /// This will remove any existing expertise for the tool.
+    pub fn set_proficient(&mut self, tool: Tool) -> &mut Self {
+        self.set_proficiency(tool, SkillLevel::Proficient)
+    }
+
+    /// Sets the proficiency level for the given tool to `Expertise`.
+    ///
+    /// This will remove any existing proficiency for the tool.
+    pub fn set_expertise(&mut self, tool: Tool) -> &mut Self {
+        self.set_proficiency(tool, SkillLevel::Expertise)
+    }
+
+    /// Adds proficiency with each of the given tools, keeping expertise with any that already
+    /// have it.
+    ///
+    /// Returns the tools that were already proficient or had expertise.
+    pub fn add_proficiencies(&mut self, tools: BitFlags<Tool>) -> BitFlags<Tool> {
+        let overlap = tools & (self.proficient | self.expertise);
+        self.proficient |= tools & !self.expertise;
+        overlap
+    }
+
+    /// Clears the proficiency for the given tool.
+    pub fn clear_proficiency(&mut self, tool: Tool) -> &mut Self {
+        self.proficient.remove(tool);
+        self.expertise.remove(tool);
+        self
+    }
+
+    /// Returns the bonus added to ability checks with the given tool, using `ability`.
+    ///
+    /// The ability depends on what the tool is used for, such as Dexterity to pick a lock with
+    /// thieves' tools, or Intelligence to identify a poison with a poisoner's kit. The bonus is
+    /// the ability modifier, plus the proficiency bonus if proficient, or twice the proficiency
+    /// bonus with expertise.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn bonus(
+        &self,
+        tool: Tool,
+        ability: Ability,
+        abilities: &Abilities,
+        proficiency_bonus: ProficiencyBonus,
+    ) -> i8 {
+        let modifier = abilities[ability].modifier().value();
+        let proficiency_bonus = proficiency_bonus.value() as i8;
+        match self.get_proficiency(tool) {
+            None => modifier,
+            Some(SkillLevel::Proficient) => modifier + proficiency_bonus,
+            Some(SkillLevel::Expertise) => modifier + proficiency_bonus * 2,
+        }
+    }
+
+    /// Returns the roll mode of a check that both `tool` and `skill` apply to.
+    ///
+    /// Under the optional rule from Xanathar's Guide to Everything, a creature proficient with
+    /// both the tool and the skill has advantage on the check.
+    #[must_use]
+    pub fn mode_with_skill(
+        &self,
+        tool: Tool,
+        skill: Skill,
+        skills: &SkillProficiencies,
+    ) -> RollMode {
+        if self.get_proficiency(tool).is_some() && skills.get_proficiency(skill).is_some() {
+            RollMode::Advantage
+        } else {
+            RollMode::Normal
+        }
+    }
+
+    /// Returns an iterator over all proficient tools, including those with expertise.
+    pub fn iter(&self) -> impl Iterator<Item = (Tool, SkillLevel)> + '_ {
+        Tool::all()
+            .iter()
+            .filter_map(move |&tool| self.get_proficiency(tool).map(|level| (tool, level)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proficiencies() {
+        let mut tools = ToolProficiencies::new();
+        tools.set_expertise(Tool::ThievesTools);
+        let overlap = tools.add_proficiencies(Tool::ThievesTools | Tool::Lute);
+        assert_eq!(overlap, Tool::ThievesTools);
+        assert!(tools.has_expertise(Tool::ThievesTools));
+        assert_eq!(
+            tools.get_proficiency(Tool::Lute),
+            Some(SkillLevel::Proficient)
+        );
+        assert_eq!(tools.iter().count(), 2);
+
+        tools.clear_proficiency(Tool::Lute);
+        assert_eq!(tools.get_proficiency(Tool::Lute), None);
+    }
+
+    #[test]
+    fn mode_with_skill() {
+        let mut tools = ToolProficiencies::new();
+        let mut skills = SkillProficiencies::new();
+        skills.set_expertise(Skill::Medicine);
+        assert_eq!(
+            tools.mode_with_skill(Tool::HerbalismKit, Skill::Medicine, &skills),
+            RollMode::Normal
+        );
+        tools.set_proficient(Tool::HerbalismKit);
+        assert_eq!(
+            tools.mode_with_skill(Tool::HerbalismKit, Skill::Medicine, &skills),
+            RollMode::Advantage
+        );
+        assert_eq!(
+            tools.mode_with_skill(Tool::HerbalismKit, Skill::Nature, &skills),
+            RollMode::Normal
+        );
+    }
+}
@@ -0,0 +1,19 @@
+mod alignment;
+pub use alignment::Alignment;
+
+#[cfg(feature = "alloc")]
+mod creature_kind;
+#[cfg(feature = "alloc")]
+pub use creature_kind::CreatureKind;
+
+mod creature_type;
+pub use creature_type::CreatureType;
+
+mod senses;
+pub use senses::Senses;
+
+mod size;
+pub use size::Size;
+
+mod speeds;
+pub use speeds::Speeds;
@@ -0,0 +1,210 @@
+use core::{fmt::Display, str::FromStr};
+
+/// A creature's moral and personal attitudes.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::creature::Alignment;
+///
+/// let alignment = Alignment::ChaoticGood;
+/// assert_eq!(alignment.name(), "Chaotic Good");
+/// assert_eq!(alignment.abbr(), "CG");
+/// assert!(alignment.is_good());
+/// assert!(alignment.is_chaotic());
+/// assert_eq!("LN".parse(), Ok(Alignment::LawfulNeutral));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Alignment {
+    /// Can be counted on to do the right thing as expected by society.
+    LawfulGood,
+
+    /// Does the best they can to help others according to their needs.
+    NeutralGood,
+
+    /// Acts as their conscience directs, with little regard for what others expect.
+    ChaoticGood,
+
+    /// Acts in accordance with law, tradition, or personal codes.
+    LawfulNeutral,
+
+    /// Prefers to steer clear of moral questions and doesn't take sides.
+    Neutral,
+
+    /// Follows their whims, holding their personal freedom above all else.
+    ChaoticNeutral,
+
+    /// Methodically takes what they want, within the limits of a code of tradition or loyalty.
+    LawfulEvil,
+
+    /// Does whatever they can get away with, without compassion or qualms.
+    NeutralEvil,
+
+    /// Acts with arbitrary violence, spurred by greed, hatred, or bloodlust.
+    ChaoticEvil,
+
+    /// Lacks the capacity for rational thought, such as most beasts.
+    Unaligned,
+
+    /// Can be of any alignment, such as a commoner or a mage.
+    Any,
+}
+
+impl Alignment {
+    /// Returns an array containing all possible [`Alignment`] variants.
+    #[must_use]
+    pub const fn all() -> &'static [Alignment] {
+        &[
+            Alignment::LawfulGood,
+            Alignment::NeutralGood,
+            Alignment::ChaoticGood,
+            Alignment::LawfulNeutral,
+            Alignment::Neutral,
+            Alignment::ChaoticNeutral,
+            Alignment::LawfulEvil,
+            Alignment::NeutralEvil,
+            Alignment::ChaoticEvil,
+            Alignment::Unaligned,
+            Alignment::Any,
+        ]
+    }
+
+    /// Returns the full name of the alignment, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Alignment::LawfulGood => "Lawful Good",
+            Alignment::NeutralGood => "Neutral Good",
+            Alignment::ChaoticGood => "Chaotic Good",
+            Alignment::LawfulNeutral => "Lawful Neutral",
+            Alignment::Neutral => "Neutral",
+            Alignment::ChaoticNeutral => "Chaotic Neutral",
+            Alignment::LawfulEvil => "Lawful Evil",
+            Alignment::NeutralEvil => "Neutral Evil",
+            Alignment::ChaoticEvil => "Chaotic Evil",
+            Alignment::Unaligned => "Unaligned",
+            Alignment::Any => "Any Alignment",
+        }
+    }
+
+    /// Returns the abbreviation of the alignment, in all uppercase letters.
+    #[must_use]
+    pub const fn abbr(&self) -> &'static str {
+        match self {
+            Alignment::LawfulGood => "LG",
+            Alignment::NeutralGood => "NG",
+            Alignment::ChaoticGood => "CG",
+            Alignment::LawfulNeutral => "LN",
+            Alignment::Neutral => "N",
+            Alignment::ChaoticNeutral => "CN",
+            Alignment::LawfulEvil => "LE",
+            Alignment::NeutralEvil => "NE",
+            Alignment::ChaoticEvil => "CE",
+            Alignment::Unaligned => "U",
+            Alignment::Any => "A",
+        }
+    }
+
+    /// Returns whether the alignment is good.
+    #[must_use]
+    pub const fn is_good(&self) -> bool {
+        matches!(
+            self,
+            Alignment::LawfulGood | Alignment::NeutralGood | Alignment::ChaoticGood
+        )
+    }
+
+    /// Returns whether the alignment is evil.
+    #[must_use]
+    pub const fn is_evil(&self) -> bool {
+        matches!(
+            self,
+            Alignment::LawfulEvil | Alignment::NeutralEvil | Alignment::ChaoticEvil
+        )
+    }
+
+    /// Returns whether the alignment is lawful.
+    #[must_use]
+    pub const fn is_lawful(&self) -> bool {
+        matches!(
+            self,
+            Alignment::LawfulGood | Alignment::LawfulNeutral | Alignment::LawfulEvil
+        )
+    }
+
+    /// Returns whether the alignment is chaotic.
+    #[must_use]
+    pub const fn is_chaotic(&self) -> bool {
+        matches!(
+            self,
+            Alignment::ChaoticGood | Alignment::ChaoticNeutral | Alignment::ChaoticEvil
+        )
+    }
+}
+
+impl Display for Alignment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl AsRef<str> for Alignment {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl FromStr for Alignment {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Alignment::all()
+            .iter()
+            .find(|alignment| alignment.name().eq_ignore_ascii_case(s) || alignment.abbr() == s)
+            .copied()
+            .ok_or("Unknown alignment")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        for alignment in Alignment::all() {
+            assert_eq!(alignment.to_string().parse(), Ok(*alignment));
+            assert_eq!(alignment.abbr().parse(), Ok(*alignment));
+        }
+        assert_eq!(
+            "true neutral".parse::<Alignment>(),
+            Err("Unknown alignment")
+        );
+    }
+
+    #[test]
+    fn axes() {
+        let good = Alignment::all().iter().filter(|a| a.is_good()).count();
+        let evil = Alignment::all().iter().filter(|a| a.is_evil()).count();
+        let lawful = Alignment::all().iter().filter(|a| a.is_lawful()).count();
+        let chaotic = Alignment::all().iter().filter(|a| a.is_chaotic()).count();
+        assert_eq!((good, evil, lawful, chaotic), (3, 3, 3, 3));
+        assert!(!Alignment::Neutral.is_good());
+        assert!(!Alignment::Unaligned.is_lawful());
+        assert!(!Alignment::Any.is_evil());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let serialized = serde_json::to_string(&Alignment::LawfulEvil).unwrap();
+        assert_eq!(serialized, "\"LawfulEvil\"");
+
+        let deserialized: Alignment = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, Alignment::LawfulEvil);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::creature::CreatureType;
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use core::{fmt::Display, str::FromStr};
+
+/// A creature type along with any tags that further categorize it, such as "Humanoid (Goblinoid)".
+///
+/// Tags carry no rules of their own, but other rules can refer to them, such as a weapon that
+/// deals extra damage to fiends tagged as demons.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::creature::{CreatureKind, CreatureType};
+///
+/// let kind: CreatureKind = "Fiend (Demon, Shapechanger)".parse().unwrap();
+/// assert_eq!(kind.creature_type, CreatureType::Fiend);
+/// assert!(kind.has_tag("demon"));
+/// assert!(!kind.has_tag("Devil"));
+///
+/// let goblin = CreatureKind::new(CreatureType::Humanoid).with_tag("Goblinoid");
+/// assert_eq!(goblin.to_string(), "Humanoid (Goblinoid)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreatureKind {
+    /// The creature's type.
+    pub creature_type: CreatureType,
+
+    /// The tags applied to the creature, in the order they are listed.
+    pub tags: Vec<Cow<'static, str>>,
+}
+
+impl CreatureKind {
+    /// Creates a new `CreatureKind` of the given type without any tags.
+    #[must_use]
+    pub const fn new(creature_type: CreatureType) -> Self {
+        Self {
+            creature_type,
+            tags: Vec::new(),
+        }
+    }
+
+    /// Returns this kind with `tag` added.
+    #[must_use]
+    pub fn with_tag(mut self, tag: impl Into<Cow<'static, str>>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Returns whether the creature has the given tag, ignoring case.
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+}
+
+impl From<CreatureType> for CreatureKind {
+    fn from(creature_type: CreatureType) -> Self {
+        Self::new(creature_type)
+    }
+}
+
+impl Display for CreatureKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.creature_type)?;
+        if let Some((first, rest)) = self.tags.split_first() {
+            write!(f, " ({first}")?;
+            for tag in rest {
+                write!(f, ", {tag}")?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for CreatureKind {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((creature_type, tags)) = s.split_once('(') else {
+            return Ok(Self::new(s.trim().parse()?));
+        };
+        let tags = tags
+            .trim()
+            .strip_suffix(')')
+            .ok_or("Unclosed creature tags")?;
+        Ok(Self {
+            creature_type: creature_type.trim().parse()?,
+            tags: tags
+                .split(',')
+                .map(|tag| Cow::Owned(tag.trim().to_string()))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        for kind in [
+            "Beast",
+            "Humanoid (Goblinoid)",
+            "Fiend (Demon, Shapechanger)",
+        ] {
+            assert_eq!(kind.parse::<CreatureKind>().unwrap().to_string(), kind);
+        }
+        assert_eq!(
+            "humanoid (elf".parse::<CreatureKind>(),
+            Err("Unclosed creature tags")
+        );
+        assert_eq!(
+            "Titan (Giant)".parse::<CreatureKind>(),
+            Err("Unknown creature type")
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use core::{fmt::Display, str::FromStr};
+
+/// The fundamental nature of a creature, which certain spells and features interact with.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::creature::CreatureType;
+///
+/// assert_eq!(CreatureType::Monstrosity.name(), "Monstrosity");
+/// assert_eq!("fey".parse(), Ok(CreatureType::Fey));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum CreatureType {
+    /// Utterly alien beings, such as beholders and mind flayers.
+    Aberration,
+
+    /// Nonhumanoid creatures that are a natural part of the fantasy ecology, such as wolves.
+    Beast,
+
+    /// Creatures native to the Upper Planes, such as angels and pegasi.
+    Celestial,
+
+    /// Made, not born, such as golems and animated objects.
+    Construct,
+
+    /// Large reptilian creatures of ancient origin and tremendous power, such as wyverns.
+    Dragon,
+
+    /// Creatures native to the elemental planes, such as djinn and salamanders.
+    Elemental,
+
+    /// Magical creatures closely tied to the forces of nature, such as dryads and pixies.
+    Fey,
+
+    /// Creatures of wickedness native to the Lower Planes, such as demons and devils.
+    Fiend,
+
+    /// Humanlike creatures that tower over humans and their kind, such as ogres and trolls.
+    Giant,
+
+    /// The main peoples of the world, such as humans, elves, and goblins.
+    Humanoid,
+
+    /// Frightening creatures that are not ordinary, not truly natural, and almost never benign.
+    Monstrosity,
+
+    /// Gelatinous creatures that rarely have a fixed shape, such as gelatinous cubes.
+    Ooze,
+
+    /// Vegetable creatures, such as shambling mounds and treants.
+    Plant,
+
+    /// Once-living creatures brought to a horrifying state of undeath, such as zombies.
+    Undead,
+}
+
+impl CreatureType {
+    /// Returns an array containing all possible [`CreatureType`] variants, in alphabetical order.
+    #[must_use]
+    pub const fn all() -> &'static [CreatureType] {
+        &[
+            CreatureType::Aberration,
+            CreatureType::Beast,
+            CreatureType::Celestial,
+            CreatureType::Construct,
+            CreatureType::Dragon,
+            CreatureType::Elemental,
+            CreatureType::Fey,
+            CreatureType::Fiend,
+            CreatureType::Giant,
+            CreatureType::Humanoid,
+            CreatureType::Monstrosity,
+            CreatureType::Ooze,
+            CreatureType::Plant,
+            CreatureType::Undead,
+        ]
+    }
+
+    /// Returns the name of the creature type, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            CreatureType::Aberration => "Aberration",
+            CreatureType::Beast => "Beast",
+            CreatureType::Celestial => "Celestial",
+            CreatureType::Construct => "Construct",
+            CreatureType::Dragon => "Dragon",
+            CreatureType::Elemental => "Elemental",
+            CreatureType::Fey => "Fey",
+            CreatureType::Fiend => "Fiend",
+            CreatureType::Giant => "Giant",
+            CreatureType::Humanoid => "Humanoid",
+            CreatureType::Monstrosity => "Monstrosity",
+            CreatureType::Ooze => "Ooze",
+            CreatureType::Plant => "Plant",
+            CreatureType::Undead => "Undead",
+        }
+    }
+}
+
+impl Display for CreatureType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl AsRef<str> for CreatureType {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl FromStr for CreatureType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CreatureType::all()
+            .iter()
+            .find(|creature_type| creature_type.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or("Unknown creature type")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        for creature_type in CreatureType::all() {
+            assert_eq!(creature_type.to_string().parse(), Ok(*creature_type));
+        }
+        assert_eq!(
+            "Goblinoid".parse::<CreatureType>(),
+            Err("Unknown creature type")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let serialized = serde_json::to_string(&CreatureType::Undead).unwrap();
+        assert_eq!(serialized, "\"Undead\"");
+
+        let deserialized: CreatureType = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, CreatureType::Undead);
+    }
+}
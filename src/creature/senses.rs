@@ -0,0 +1,101 @@
+use crate::creature::speeds::parse_feet;
+use core::{fmt::Display, str::FromStr};
+
+/// The range, in feet, of each special sense a creature has.
+///
+/// A range of 0 means the creature lacks that sense.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::creature::Senses;
+///
+/// let senses = Senses {
+///     darkvision: 60,
+///     ..Senses::default()
+/// };
+/// assert_eq!(senses.truesight, 0);
+/// assert_eq!(senses.to_string(), "darkvision 60 ft.");
+/// assert_eq!("Darkvision 60 ft.".parse(), Ok(senses));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Senses {
+    /// Can see in dim light as if it were bright light, and in darkness as if it were dim light.
+    pub darkvision: u16,
+
+    /// Can perceive its surroundings without relying on sight.
+    pub blindsight: u16,
+
+    /// Can detect and pinpoint the origin of vibrations through the ground.
+    pub tremorsense: u16,
+
+    /// Can see in darkness, see invisible creatures, and see through illusions.
+    pub truesight: u16,
+}
+
+impl Display for Senses {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let senses = [
+            ("blindsight", self.blindsight),
+            ("darkvision", self.darkvision),
+            ("tremorsense", self.tremorsense),
+            ("truesight", self.truesight),
+        ];
+        let mut separator = "";
+        for (name, range) in senses {
+            if range > 0 {
+                write!(f, "{separator}{name} {range} ft.")?;
+                separator = ", ";
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Senses {
+    type Err = &'static str;
+
+    /// Parses senses as written in a stat block, such as `blindsight 30 ft., darkvision 60 ft.`.
+    ///
+    /// An empty string has no special senses.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut senses = Senses::default();
+        for part in s.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            let (name, range) = part.split_once(' ').ok_or("Missing sense range")?;
+            let sense = [
+                ("blindsight", &mut senses.blindsight),
+                ("darkvision", &mut senses.darkvision),
+                ("tremorsense", &mut senses.tremorsense),
+                ("truesight", &mut senses.truesight),
+            ]
+            .into_iter()
+            .find(|(sense, _)| sense.eq_ignore_ascii_case(name))
+            .ok_or("Unknown sense")?
+            .1;
+            *sense = parse_feet(range)?;
+        }
+        Ok(senses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        let senses = Senses {
+            blindsight: 10,
+            truesight: 120,
+            ..Senses::default()
+        };
+        assert_eq!(senses.to_string(), "blindsight 10 ft., truesight 120 ft.");
+        assert_eq!(senses.to_string().parse(), Ok(senses));
+        assert_eq!("".parse(), Ok(Senses::default()));
+        assert_eq!("scent 30 ft.".parse::<Senses>(), Err("Unknown sense"));
+        assert_eq!("darkvision".parse::<Senses>(), Err("Missing sense range"));
+    }
+}
@@ -0,0 +1,223 @@
+use crate::core::Die;
+use core::{fmt::Display, str::FromStr};
+
+/// How much space a creature occupies, from Tiny to Gargantuan.
+///
+/// Sizes are ordered from smallest to largest.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::creature::Size;
+///
+/// assert!(Size::Small < Size::Large);
+/// assert_eq!(Size::Medium.larger(), Size::Large);
+/// assert_eq!(Size::Gargantuan.larger(), Size::Gargantuan);
+/// assert_eq!(Size::Large.space(), 10.0);
+/// assert!(Size::Medium.can_grapple(Size::Large));
+/// assert_eq!("huge".parse(), Ok(Size::Huge));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Size {
+    /// Such as an imp or sprite.
+    Tiny,
+
+    /// Such as a giant rat or goblin.
+    Small,
+
+    /// Such as an orc or werewolf.
+    #[default]
+    Medium,
+
+    /// Such as a hippogriff or ogre.
+    Large,
+
+    /// Such as a fire giant or treant.
+    Huge,
+
+    /// Such as a kraken or purple worm.
+    Gargantuan,
+}
+
+impl Size {
+    /// Returns an array containing all possible [`Size`] variants, from smallest to largest.
+    #[must_use]
+    pub const fn all() -> &'static [Size] {
+        &[
+            Size::Tiny,
+            Size::Small,
+            Size::Medium,
+            Size::Large,
+            Size::Huge,
+            Size::Gargantuan,
+        ]
+    }
+
+    /// Returns the name of the size, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Size::Tiny => "Tiny",
+            Size::Small => "Small",
+            Size::Medium => "Medium",
+            Size::Large => "Large",
+            Size::Huge => "Huge",
+            Size::Gargantuan => "Gargantuan",
+        }
+    }
+
+    /// Returns the next size larger than this one, or `Gargantuan` if already the largest.
+    #[must_use]
+    pub const fn larger(&self) -> Self {
+        match self {
+            Size::Tiny => Size::Small,
+            Size::Small => Size::Medium,
+            Size::Medium => Size::Large,
+            Size::Large => Size::Huge,
+            Size::Huge | Size::Gargantuan => Size::Gargantuan,
+        }
+    }
+
+    /// Returns the next size smaller than this one, or `Tiny` if already the smallest.
+    #[must_use]
+    pub const fn smaller(&self) -> Self {
+        match self {
+            Size::Tiny | Size::Small => Size::Tiny,
+            Size::Medium => Size::Small,
+            Size::Large => Size::Medium,
+            Size::Huge => Size::Large,
+            Size::Gargantuan => Size::Huge,
+        }
+    }
+
+    /// Returns the width, in feet, of the square a creature of this size controls in combat.
+    ///
+    /// Gargantuan creatures occupy a space of at least 20 feet by 20 feet.
+    #[must_use]
+    pub const fn space(&self) -> f32 {
+        match self {
+            Size::Tiny => 2.5,
+            Size::Small | Size::Medium => 5.0,
+            Size::Large => 10.0,
+            Size::Huge => 15.0,
+            Size::Gargantuan => 20.0,
+        }
+    }
+
+    /// Returns the hit die used by monsters of this size.
+    #[must_use]
+    pub const fn hit_die(&self) -> Die {
+        match self {
+            Size::Tiny => Die::D4,
+            Size::Small => Die::D6,
+            Size::Medium => Die::D8,
+            Size::Large => Die::D10,
+            Size::Huge => Die::D12,
+            Size::Gargantuan => Die::D20,
+        }
+    }
+
+    /// Returns the percentage by which carrying capacity is scaled for this size.
+    ///
+    /// Tiny creatures carry half as much as Medium ones, and each size above Medium doubles it.
+    #[must_use]
+    pub const fn capacity_percentage(&self) -> u32 {
+        match self {
+            Size::Tiny => 50,
+            Size::Small | Size::Medium => 100,
+            Size::Large => 200,
+            Size::Huge => 400,
+            Size::Gargantuan => 800,
+        }
+    }
+
+    /// Returns whether a creature of this size can grapple or shove a `target` of the given size.
+    ///
+    /// The target can be no more than one size larger.
+    #[must_use]
+    pub const fn can_grapple(&self, target: Size) -> bool {
+        target as u8 <= *self as u8 + 1
+    }
+}
+
+impl Display for Size {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl AsRef<str> for Size {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl FromStr for Size {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Size::all()
+            .iter()
+            .find(|size| size.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or("Unknown size")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn ordered() {
+        for pair in Size::all().windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
+    }
+
+    #[test]
+    fn larger_and_smaller() {
+        for pair in Size::all().windows(2) {
+            assert_eq!(pair[0].larger(), pair[1]);
+            assert_eq!(pair[1].smaller(), pair[0]);
+        }
+        assert_eq!(Size::Tiny.smaller(), Size::Tiny);
+        assert_eq!(Size::Gargantuan.larger(), Size::Gargantuan);
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Size::default(), Size::Medium);
+    }
+
+    #[test]
+    fn grapple() {
+        assert!(Size::Tiny.can_grapple(Size::Small));
+        assert!(!Size::Tiny.can_grapple(Size::Medium));
+        assert!(Size::Medium.can_grapple(Size::Tiny));
+        assert!(Size::Huge.can_grapple(Size::Gargantuan));
+        assert!(Size::Gargantuan.can_grapple(Size::Gargantuan));
+    }
+
+    #[test]
+    fn parse() {
+        for size in Size::all() {
+            assert_eq!(size.to_string().parse(), Ok(*size));
+        }
+        assert_eq!("Colossal".parse::<Size>(), Err("Unknown size"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let serialized = serde_json::to_string(&Size::Huge).unwrap();
+        assert_eq!(serialized, "\"Huge\"");
+
+        let deserialized: Size = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, Size::Huge);
+    }
+}
@@ -0,0 +1,158 @@
+use core::{fmt::Display, str::FromStr};
+
+/// How far, in feet, a creature can move on its turn with each mode of movement.
+///
+/// A speed of 0 means the creature lacks that mode of movement.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::creature::Speeds;
+///
+/// let speeds = Speeds {
+///     fly: 60,
+///     hover: true,
+///     ..Speeds::walking(30)
+/// };
+/// assert_eq!(speeds.walk, 30);
+/// assert_eq!(speeds.swim, 0);
+/// assert_eq!(speeds.to_string(), "30 ft., fly 60 ft. (hover)");
+/// assert_eq!("30 ft., fly 60 ft. (hover)".parse(), Ok(speeds));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Speeds {
+    /// Speed when walking on the ground.
+    pub walk: u16,
+
+    /// Speed when flying.
+    pub fly: u16,
+
+    /// Whether the creature can hover, and so doesn't fall when its speed is reduced to 0.
+    pub hover: bool,
+
+    /// Speed when swimming, without spending extra movement.
+    pub swim: u16,
+
+    /// Speed when climbing, without spending extra movement.
+    pub climb: u16,
+
+    /// Speed when moving through sand, earth, mud, or ice.
+    pub burrow: u16,
+}
+
+impl Speeds {
+    /// Creates a new `Speeds` with only the given walking speed.
+    #[must_use]
+    pub const fn walking(walk: u16) -> Self {
+        Self {
+            walk,
+            fly: 0,
+            hover: false,
+            swim: 0,
+            climb: 0,
+            burrow: 0,
+        }
+    }
+}
+
+impl Display for Speeds {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} ft.", self.walk)?;
+        for (name, speed) in [("burrow", self.burrow), ("climb", self.climb)] {
+            if speed > 0 {
+                write!(f, ", {name} {speed} ft.")?;
+            }
+        }
+        if self.fly > 0 {
+            write!(f, ", fly {} ft.", self.fly)?;
+            if self.hover {
+                write!(f, " (hover)")?;
+            }
+        }
+        if self.swim > 0 {
+            write!(f, ", swim {} ft.", self.swim)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Speeds {
+    type Err = &'static str;
+
+    /// Parses speeds as written in a stat block, such as `40 ft., climb 30 ft.`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut speeds = Speeds::default();
+        for part in s.split(',').map(str::trim) {
+            let (mode, distance) = match part.split_once(' ') {
+                Some((mode, distance)) if !mode.starts_with(|c: char| c.is_ascii_digit()) => {
+                    (mode, distance)
+                }
+                _ => ("walk", part),
+            };
+            let (distance, hover) = match distance.strip_suffix("(hover)") {
+                Some(distance) => (distance, true),
+                None => (distance, false),
+            };
+            let is_fly = mode.eq_ignore_ascii_case("fly");
+            let speed = [
+                ("walk", &mut speeds.walk),
+                ("burrow", &mut speeds.burrow),
+                ("climb", &mut speeds.climb),
+                ("fly", &mut speeds.fly),
+                ("swim", &mut speeds.swim),
+            ]
+            .into_iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(mode))
+            .ok_or("Unknown movement mode")?
+            .1;
+            *speed = parse_feet(distance)?;
+            if hover {
+                if !is_fly {
+                    return Err("Only a flying speed can hover");
+                }
+                speeds.hover = true;
+            }
+        }
+        Ok(speeds)
+    }
+}
+
+/// Parses a distance in feet, such as `30 ft.`.
+pub(super) fn parse_feet(s: &str) -> Result<u16, &'static str> {
+    let s = s.trim();
+    let s = s
+        .strip_suffix("ft.")
+        .or_else(|| s.strip_suffix("ft"))
+        .or_else(|| s.strip_suffix("feet"))
+        .ok_or("Distance must be in feet")?;
+    s.trim().parse().map_err(|_| "Invalid distance")
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        let speeds = Speeds {
+            burrow: 20,
+            swim: 40,
+            ..Speeds::walking(10)
+        };
+        assert_eq!(speeds.to_string(), "10 ft., burrow 20 ft., swim 40 ft.");
+        assert_eq!(speeds.to_string().parse(), Ok(speeds));
+        assert_eq!("0 ft.".parse(), Ok(Speeds::default()));
+        assert_eq!(
+            "30 ft., teleport 30 ft.".parse::<Speeds>(),
+            Err("Unknown movement mode")
+        );
+        assert_eq!(
+            "30 ft., swim 30 ft. (hover)".parse::<Speeds>(),
+            Err("Only a flying speed can hover")
+        );
+        assert_eq!("30".parse::<Speeds>(), Err("Distance must be in feet"));
+    }
+}
@@ -0,0 +1,11 @@
+mod breakdown;
+pub use breakdown::{Breakdown, Contribution, Source};
+
+mod effects;
+pub use effects::{Effect, Effects};
+
+mod modifier;
+pub use modifier::Modifier;
+
+mod target;
+pub use target::Target;
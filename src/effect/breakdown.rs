@@ -0,0 +1,308 @@
+use crate::{
+    core::{Ability, Condition, Dice, Die, RollMode},
+    equipment::Armor,
+};
+use alloc::{borrow::Cow, vec::Vec};
+use core::fmt::Display;
+
+/// Where part of a derived value comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Source {
+    /// The fixed starting value, such as 10 for Armor Class or 8 for a spell save DC.
+    Base,
+
+    /// An ability modifier.
+    Ability(Ability),
+
+    /// The proficiency bonus, added once.
+    Proficiency,
+
+    /// Twice the proficiency bonus, from expertise.
+    Expertise,
+
+    /// Armor or a shield.
+    Armor(Armor),
+
+    /// A magic item or other item, by name.
+    Item(Cow<'static, str>),
+
+    /// A class feature, species trait, feat, or other feature, by name.
+    Feature(Cow<'static, str>),
+
+    /// A spell, by name.
+    Spell(Cow<'static, str>),
+
+    /// A condition affecting the creature.
+    Condition(Condition),
+
+    /// Carrying too much weight.
+    Encumbrance,
+
+    /// Being surprised at the start of combat.
+    Surprise,
+
+    /// Flanking a creature with an ally.
+    Flanking,
+
+    /// Advantage, which adds 5 to passive scores.
+    Advantage,
+
+    /// Disadvantage, which subtracts 5 from passive scores.
+    Disadvantage,
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Source::Base => write!(f, "Base"),
+            Source::Ability(ability) => write!(f, "{ability}"),
+            Source::Proficiency => write!(f, "Proficiency"),
+            Source::Expertise => write!(f, "Expertise"),
+            Source::Armor(armor) => write!(f, "{armor}"),
+            Source::Item(name) | Source::Feature(name) | Source::Spell(name) => {
+                write!(f, "{name}")
+            }
+            Source::Condition(condition) => write!(f, "{condition}"),
+            Source::Encumbrance => write!(f, "Encumbrance"),
+            Source::Surprise => write!(f, "Surprise"),
+            Source::Flanking => write!(f, "Flanking"),
+            Source::Advantage => write!(f, "Advantage"),
+            Source::Disadvantage => write!(f, "Disadvantage"),
+        }
+    }
+}
+
+/// A single source's share of a derived value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contribution {
+    /// Where the value comes from.
+    pub source: Source,
+
+    /// How much is added to the total, which may be negative.
+    pub value: i16,
+}
+
+/// A derived value, such as a skill bonus or Armor Class, along with every source that
+/// contributes to it.
+///
+/// Besides flat contributions, a breakdown tracks dice added to rolls (such as the d4 from
+/// *bless*), a value the total is set to, a minimum d20 roll, and sources of advantage and
+/// disadvantage.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Ability, Dice, Die, RollMode};
+/// use dnd::effect::{Breakdown, Source};
+/// use dnd::equipment::Armor;
+///
+/// let mut stealth = Breakdown::new();
+/// stealth
+///     .add(Source::Ability(Ability::Dexterity), 4)
+///     .add(Source::Proficiency, 3)
+///     .add_dice(Source::Spell("Guidance".into()), Dice::new(1, Die::D4))
+///     .impose_disadvantage(Source::Armor(Armor::HalfPlate));
+///
+/// assert_eq!(stealth.total(), 7);
+/// assert_eq!(stealth.mode(), RollMode::Disadvantage);
+/// assert_eq!(stealth.to_string(), "Dexterity +4, Proficiency +3, Guidance +1d4");
+///
+/// // The lower of 15 and 9 is rolled, plus 7, plus 3 on the d4.
+/// assert_eq!(stealth.roll(15, 9, |_| 3), 19);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Breakdown {
+    contributions: Vec<Contribution>,
+    dice: Vec<(Source, Dice)>,
+    set: Option<(Source, i16)>,
+    minimum_roll: Option<(Source, u8)>,
+    advantage: Vec<Source>,
+    disadvantage: Vec<Source>,
+}
+
+impl Breakdown {
+    /// Creates a new, empty `Breakdown` with a total of 0.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            contributions: Vec::new(),
+            dice: Vec::new(),
+            set: None,
+            minimum_roll: None,
+            advantage: Vec::new(),
+            disadvantage: Vec::new(),
+        }
+    }
+
+    /// Adds `value` from `source` to the total.
+    pub fn add(&mut self, source: Source, value: i16) -> &mut Self {
+        self.contributions.push(Contribution { source, value });
+        self
+    }
+
+    /// Adds `dice` from `source`, which are rolled along with the d20.
+    pub fn add_dice(&mut self, source: Source, dice: Dice) -> &mut Self {
+        self.dice.push((source, dice));
+        self
+    }
+
+    /// Sets the total to `value`, ignoring every contribution.
+    ///
+    /// If the total is set more than once, the highest value applies.
+    pub fn set_to(&mut self, source: Source, value: i16) -> &mut Self {
+        if self.set.as_ref().is_none_or(|(_, set)| value > *set) {
+            self.set = Some((source, value));
+        }
+        self
+    }
+
+    /// Treats any d20 roll lower than `value` as `value`.
+    ///
+    /// If more than one minimum applies, the highest applies.
+    pub fn set_minimum_roll(&mut self, source: Source, value: u8) -> &mut Self {
+        if self
+            .minimum_roll
+            .as_ref()
+            .is_none_or(|(_, minimum)| value > *minimum)
+        {
+            self.minimum_roll = Some((source, value));
+        }
+        self
+    }
+
+    /// Records that `source` grants advantage.
+    pub fn grant_advantage(&mut self, source: Source) -> &mut Self {
+        self.advantage.push(source);
+        self
+    }
+
+    /// Records that `source` imposes disadvantage.
+    pub fn impose_disadvantage(&mut self, source: Source) -> &mut Self {
+        self.disadvantage.push(source);
+        self
+    }
+
+    /// Returns every contribution to the total, in the order they were added.
+    #[must_use]
+    pub fn contributions(&self) -> &[Contribution] {
+        &self.contributions
+    }
+
+    /// Returns every source of dice added to the roll.
+    #[must_use]
+    pub fn dice(&self) -> &[(Source, Dice)] {
+        &self.dice
+    }
+
+    /// Returns the value the total is set to, and its source, if any.
+    #[must_use]
+    pub fn set(&self) -> Option<&(Source, i16)> {
+        self.set.as_ref()
+    }
+
+    /// Returns the minimum d20 roll, and its source, if any.
+    #[must_use]
+    pub fn minimum_roll(&self) -> Option<&(Source, u8)> {
+        self.minimum_roll.as_ref()
+    }
+
+    /// Returns every source of advantage.
+    #[must_use]
+    pub fn advantage(&self) -> &[Source] {
+        &self.advantage
+    }
+
+    /// Returns every source of disadvantage.
+    #[must_use]
+    pub fn disadvantage(&self) -> &[Source] {
+        &self.disadvantage
+    }
+
+    /// Returns the value the total is set to, or else the sum of every contribution.
+    ///
+    /// Dice are not included, since they must be rolled.
+    #[must_use]
+    pub fn total(&self) -> i16 {
+        match self.set {
+            Some((_, value)) => value,
+            None => self
+                .contributions
+                .iter()
+                .map(|contribution| contribution.value)
+                .sum(),
+        }
+    }
+
+    /// Returns whether a roll is made with advantage, disadvantage, or neither.
+    #[must_use]
+    pub fn mode(&self) -> RollMode {
+        RollMode::new(!self.advantage.is_empty(), !self.disadvantage.is_empty())
+    }
+
+    /// Returns the result of a d20 roll with this bonus, given two rolls of the d20 and `roll`
+    /// to produce the result of each added die.
+    ///
+    /// The second d20 is only used with advantage or disadvantage.
+    pub fn roll(&self, first: u8, second: u8, mut roll: impl FnMut(Die) -> u8) -> i16 {
+        let mut d20 = self.mode().pick(first, second);
+        if let Some((_, minimum)) = self.minimum_roll {
+            d20 = d20.max(minimum);
+        }
+        let dice: i16 = self.dice.iter().map(|(_, dice)| dice.roll(&mut roll)).sum();
+        i16::from(d20) + self.total() + dice
+    }
+}
+
+impl Display for Breakdown {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some((source, value)) = &self.set {
+            return write!(f, "{source} {value}");
+        }
+        let mut separator = "";
+        for Contribution { source, value } in &self.contributions {
+            write!(f, "{separator}{source} {value:+}")?;
+            separator = ", ";
+        }
+        for (source, dice) in &self.dice {
+            write!(f, "{separator}{source} +{dice}")?;
+            separator = ", ";
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn advantage_and_disadvantage_cancel() {
+        let mut breakdown = Breakdown::new();
+        breakdown
+            .add(Source::Base, 10)
+            .add(Source::Item("Ring of Protection".into()), 1)
+            .grant_advantage(Source::Feature("Reckless Attack".into()))
+            .impose_disadvantage(Source::Condition(Condition::Poisoned));
+        assert_eq!(breakdown.total(), 11);
+        assert_eq!(breakdown.mode(), RollMode::Normal);
+        assert_eq!(breakdown.to_string(), "Base +10, Ring of Protection +1");
+    }
+
+    #[test]
+    fn set_to_and_minimum_roll() {
+        let mut breakdown = Breakdown::new();
+        breakdown
+            .add(Source::Base, 9)
+            .set_to(Source::Item("Gauntlets of Ogre Power".into()), 19)
+            .set_to(Source::Item("Belt of Hill Giant Strength".into()), 21)
+            .set_to(Source::Spell("Enlarge".into()), 20)
+            .set_minimum_roll(Source::Feature("Reliable Talent".into()), 10);
+        assert_eq!(breakdown.total(), 21);
+        assert_eq!(breakdown.to_string(), "Belt of Hill Giant Strength 21");
+        assert_eq!(breakdown.roll(3, 20, |_| 1), 31);
+    }
+}
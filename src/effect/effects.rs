@@ -0,0 +1,467 @@
+use crate::{
+    core::{Abilities, Ability, Condition, ProficiencyBonus, RulesVersion},
+    effect::{Breakdown, Modifier, Source, Target},
+    equipment::{Encumbrance, MagicItem, MagicItemEffect},
+};
+use alloc::vec::Vec;
+use enumflags2::BitFlags;
+
+/// A modifier that a source applies to a target, such as *bless* adding a d4 to attack rolls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Effect {
+    /// What the effect comes from.
+    pub source: Source,
+
+    /// What the effect changes.
+    pub target: Target,
+
+    /// How the effect changes it.
+    pub modifier: Modifier,
+}
+
+/// Every effect currently applied to a creature.
+///
+/// Effects are registered by their source, and applied to a [`Breakdown`] following the rules
+/// for combining them described on [`Modifier`].
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Ability, Condition, Dice, Die, RollMode};
+/// use dnd::effect::{Breakdown, Effects, Modifier, Source, Target};
+///
+/// let bless = Source::Spell("Bless".into());
+/// let d4 = Modifier::Dice(Dice::new(1, Die::D4));
+///
+/// let mut effects = Effects::new();
+/// effects
+///     .add(bless.clone(), Target::AttackRolls, d4)
+///     .add(bless.clone(), Target::AllSavingThrows, d4)
+///     .add_conditions(Condition::Poisoned.into());
+///
+/// let mut attack = Breakdown::new();
+/// attack.add(Source::Ability(Ability::Strength), 3);
+/// effects.apply(&[Target::AttackRolls], &mut attack);
+/// assert_eq!(attack.to_string(), "Strength +3, Bless +1d4");
+/// assert_eq!(attack.mode(), RollMode::Disadvantage);
+///
+/// effects.remove(&bless);
+/// assert_eq!(effects.len(), 2);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Effects {
+    effects: Vec<Effect>,
+}
+
+impl Effects {
+    /// Creates a new `Effects` with no effects.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            effects: Vec::new(),
+        }
+    }
+
+    /// Returns the number of effects.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Returns whether there are no effects.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Returns an iterator over every effect.
+    pub fn iter(&self) -> impl Iterator<Item = &Effect> {
+        self.effects.iter()
+    }
+
+    /// Registers an effect from `source`.
+    pub fn add(&mut self, source: Source, target: Target, modifier: Modifier) -> &mut Self {
+        self.effects.push(Effect {
+            source,
+            target,
+            modifier,
+        });
+        self
+    }
+
+    /// Replaces every [`Modifier::ProficiencyBonus`] with a flat bonus of `proficiency_bonus`.
+    pub fn resolve_proficiency_bonus(&mut self, proficiency_bonus: ProficiencyBonus) -> &mut Self {
+        for effect in &mut self.effects {
+            effect.modifier = effect.modifier.resolve(proficiency_bonus);
+        }
+        self
+    }
+
+    /// Removes every effect from `source`, such as when a spell ends.
+    pub fn remove(&mut self, source: &Source) -> &mut Self {
+        self.effects.retain(|effect| effect.source != *source);
+        self
+    }
+
+    /// Registers the effects of each condition on the creature's own rolls and speed.
+    ///
+    /// Effects on rolls made against the creature, and automatic failures, are not included.
+    pub fn add_conditions(&mut self, conditions: BitFlags<Condition>) -> &mut Self {
+        for condition in conditions {
+            let source = Source::Condition(condition);
+            let mut add = |target, modifier| {
+                self.add(source.clone(), target, modifier);
+            };
+            match condition {
+                Condition::Blinded | Condition::Prone => {
+                    add(Target::AttackRolls, Modifier::Disadvantage);
+                }
+                Condition::Frightened | Condition::Poisoned => {
+                    add(Target::AttackRolls, Modifier::Disadvantage);
+                    add(Target::AllAbilityChecks, Modifier::Disadvantage);
+                }
+                Condition::Invisible => add(Target::AttackRolls, Modifier::Advantage),
+                Condition::Restrained => {
+                    add(Target::Speed, Modifier::SetTo(0));
+                    add(Target::AttackRolls, Modifier::Disadvantage);
+                    add(
+                        Target::SavingThrow(Ability::Dexterity),
+                        Modifier::Disadvantage,
+                    );
+                }
+                Condition::Grappled
+                | Condition::Paralyzed
+                | Condition::Petrified
+                | Condition::Stunned
+                | Condition::Unconscious => add(Target::Speed, Modifier::SetTo(0)),
+                Condition::Charmed
+                | Condition::Deafened
+                | Condition::Exhaustion
+                | Condition::Incapacitated => {}
+            }
+        }
+        self
+    }
+
+    /// Registers the effects of `level` levels of exhaustion on a creature with the given base
+    /// walking `speed`.
+    ///
+    /// Under the 2014 rules, each level adds to the effects of the last: disadvantage on ability
+    /// checks, then half speed, then disadvantage on attack rolls and saving throws, and at
+    /// level 5 a speed of 0. The halving of the hit point maximum at level 4 is not included.
+    /// Under the 2024 rules, each level instead subtracts 2 from every d20 test and 5 feet from
+    /// speed. Either way, a creature dies at level 6.
+    pub fn add_exhaustion(&mut self, level: u8, speed: u16, rules: RulesVersion) -> &mut Self {
+        let source = Source::Condition(Condition::Exhaustion);
+        let mut add = |target, modifier| {
+            self.add(source.clone(), target, modifier);
+        };
+        match rules {
+            RulesVersion::Phb2014 => {
+                if level >= 1 {
+                    add(Target::AllAbilityChecks, Modifier::Disadvantage);
+                }
+                if level >= 5 {
+                    add(Target::Speed, Modifier::SetTo(0));
+                } else if level >= 2 {
+                    let halved = speed - speed / 2;
+                    add(
+                        Target::Speed,
+                        Modifier::Flat(-i16::try_from(halved).unwrap_or(i16::MAX)),
+                    );
+                }
+                if level >= 3 {
+                    add(Target::AttackRolls, Modifier::Disadvantage);
+                    add(Target::AllSavingThrows, Modifier::Disadvantage);
+                }
+            }
+            RulesVersion::Phb2024 if level > 0 => {
+                let penalty = -2 * i16::from(level);
+                add(Target::AllAbilityChecks, Modifier::Flat(penalty));
+                add(Target::AttackRolls, Modifier::Flat(penalty));
+                add(Target::AllSavingThrows, Modifier::Flat(penalty));
+                add(Target::Speed, Modifier::Flat(-5 * i16::from(level)));
+            }
+            RulesVersion::Phb2024 => {}
+        }
+        self
+    }
+
+    /// Registers the effects of `encumbrance` on a creature with the given base walking
+    /// `speed`.
+    pub fn add_encumbrance(&mut self, encumbrance: Encumbrance, speed: u16) -> &mut Self {
+        let source = Source::Encumbrance;
+        let slowed = speed - encumbrance.speed(speed);
+        if slowed > 0 {
+            self.add(
+                source.clone(),
+                Target::Speed,
+                Modifier::Flat(-i16::try_from(slowed).unwrap_or(i16::MAX)),
+            );
+        }
+        let abilities = encumbrance.disadvantage();
+        for ability in abilities {
+            self.add(
+                source.clone(),
+                Target::AbilityCheck(ability),
+                Modifier::Disadvantage,
+            )
+            .add(
+                source.clone(),
+                Target::SavingThrow(ability),
+                Modifier::Disadvantage,
+            );
+        }
+        if !abilities.is_empty() {
+            self.add(source, Target::AttackRolls, Modifier::Disadvantage);
+        }
+        self
+    }
+
+    /// Registers the effects of a magic item on a creature with the given base `abilities`.
+    ///
+    /// An item that sets an ability score only applies if the score is lower, and an item that
+    /// increases a score never raises it above the item's maximum. Bonuses to attack and damage
+    /// rolls made with the item itself are not registered.
+    pub fn add_magic_item(&mut self, item: &MagicItem, abilities: &Abilities) -> &mut Self {
+        let source = Source::Item(item.name.clone());
+        for effect in item.effects.iter() {
+            let (target, modifier) = match *effect {
+                MagicItemEffect::IncreaseAbilityScore {
+                    ability,
+                    amount,
+                    maximum,
+                } => {
+                    let room = maximum.value().saturating_sub(abilities[ability].value());
+                    (
+                        Target::AbilityScore(ability),
+                        Modifier::Flat(amount.min(room).into()),
+                    )
+                }
+                MagicItemEffect::SetAbilityScore(ability, score) => {
+                    if abilities[ability] >= score {
+                        continue;
+                    }
+                    (
+                        Target::AbilityScore(ability),
+                        Modifier::SetTo(score.value().into()),
+                    )
+                }
+                MagicItemEffect::SavingThrows(abilities, bonus) => {
+                    for ability in abilities {
+                        self.add(
+                            source.clone(),
+                            Target::SavingThrow(ability),
+                            Modifier::Flat(bonus.into()),
+                        );
+                    }
+                    continue;
+                }
+                MagicItemEffect::ArmorClass(bonus) => {
+                    (Target::ArmorClass, Modifier::Flat(bonus.into()))
+                }
+                MagicItemEffect::SpellAttackRolls(bonus) => {
+                    (Target::SpellAttackRolls, Modifier::Flat(bonus.into()))
+                }
+                MagicItemEffect::SpellSaveDc(bonus) => {
+                    (Target::SpellSaveDc, Modifier::Flat(bonus.into()))
+                }
+                MagicItemEffect::AttackRolls(_) | MagicItemEffect::DamageRolls(_) => continue,
+            };
+            self.add(source.clone(), target, modifier);
+        }
+        self
+    }
+
+    /// Applies every effect on any of `targets` to `breakdown`.
+    ///
+    /// Of several flat bonuses, dice, or set values from the same source, only the most potent
+    /// applies, even if they target different entries of `targets`.
+    pub fn apply(&self, targets: &[Target], breakdown: &mut Breakdown) {
+        let matching: Vec<&Effect> = self
+            .effects
+            .iter()
+            .filter(|effect| targets.contains(&effect.target))
+            .collect();
+        for (index, effect) in matching.iter().enumerate() {
+            let superseded = matching.iter().enumerate().any(|(other_index, other)| {
+                other.source == effect.source
+                    && other.modifier.is_same_kind(effect.modifier)
+                    && (other.modifier.outranks(effect.modifier)
+                        || (other_index < index && other.modifier == effect.modifier))
+            });
+            if superseded {
+                continue;
+            }
+            let source = effect.source.clone();
+            match effect.modifier {
+                Modifier::Flat(value) => {
+                    breakdown.add(source, value);
+                }
+                Modifier::Dice(dice) => {
+                    breakdown.add_dice(source, dice);
+                }
+                Modifier::Advantage => {
+                    breakdown.grant_advantage(source);
+                }
+                Modifier::Disadvantage => {
+                    breakdown.impose_disadvantage(source);
+                }
+                Modifier::SetTo(value) => {
+                    breakdown.set_to(source, value);
+                }
+                Modifier::MinimumRoll(value) => {
+                    breakdown.set_minimum_roll(source, value);
+                }
+                Modifier::ProficiencyBonus => {}
+            }
+        }
+    }
+}
+
+impl Extend<Effect> for Effects {
+    fn extend<T: IntoIterator<Item = Effect>>(&mut self, iter: T) {
+        self.effects.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{AbilityScore, Dice, Die, RollMode, Skill};
+
+    #[test]
+    fn same_source_does_not_stack() {
+        let bless = Source::Spell("Bless".into());
+        let mut effects = Effects::new();
+        effects
+            .add(
+                bless.clone(),
+                Target::AllSavingThrows,
+                Modifier::Dice(Dice::new(1, Die::D4)),
+            )
+            .add(
+                bless.clone(),
+                Target::SavingThrow(Ability::Wisdom),
+                Modifier::Dice(Dice::new(1, Die::D4)),
+            )
+            .add(
+                Source::Feature("Aura of Protection".into()),
+                Target::AllSavingThrows,
+                Modifier::Flat(3),
+            )
+            .add(
+                Source::Item("Cloak of Protection".into()),
+                Target::AllSavingThrows,
+                Modifier::Flat(1),
+            );
+
+        let mut breakdown = Breakdown::new();
+        effects.apply(&Target::saving_throw(Ability::Wisdom), &mut breakdown);
+        assert_eq!(breakdown.dice().len(), 1);
+        assert_eq!(breakdown.total(), 4);
+    }
+
+    #[test]
+    fn exhaustion() {
+        let mut effects = Effects::new();
+        effects.add_exhaustion(3, 25, RulesVersion::Phb2014);
+        let mut speed = Breakdown::new();
+        speed.add(Source::Base, 25);
+        effects.apply(&[Target::Speed], &mut speed);
+        assert_eq!(speed.total(), 12);
+        let mut save = Breakdown::new();
+        effects.apply(&Target::saving_throw(Ability::Constitution), &mut save);
+        assert_eq!(save.mode(), RollMode::Disadvantage);
+
+        let mut effects = Effects::new();
+        effects.add_exhaustion(3, 30, RulesVersion::Phb2024);
+        let mut check = Breakdown::new();
+        effects.apply(&Target::skill_check(Skill::Stealth), &mut check);
+        assert_eq!((check.total(), check.mode()), (-6, RollMode::Normal));
+        let mut speed = Breakdown::new();
+        speed.add(Source::Base, 30);
+        effects.apply(&[Target::Speed], &mut speed);
+        assert_eq!(speed.total(), 15);
+
+        let mut effects = Effects::new();
+        effects.add_exhaustion(0, 30, RulesVersion::Phb2024);
+        effects.add_exhaustion(0, 30, RulesVersion::Phb2014);
+        assert_eq!(effects, Effects::new());
+    }
+
+    #[test]
+    fn most_potent_of_same_source() {
+        let rage = Source::Feature("Rage".into());
+        let mut effects = Effects::new();
+        effects
+            .add(rage.clone(), Target::DamageRolls, Modifier::Flat(2))
+            .add(rage.clone(), Target::DamageRolls, Modifier::Flat(3))
+            .add(
+                rage,
+                Target::AbilityCheck(Ability::Strength),
+                Modifier::Advantage,
+            )
+            .add(
+                Source::Feature("Reliable Talent".into()),
+                Target::AllAbilityChecks,
+                Modifier::MinimumRoll(10),
+            );
+
+        let mut damage = Breakdown::new();
+        effects.apply(&[Target::DamageRolls], &mut damage);
+        assert_eq!(damage.total(), 3);
+
+        let curse = Source::Feature("Curse".into());
+        effects
+            .add(curse.clone(), Target::AttackRolls, Modifier::Flat(-2))
+            .add(curse, Target::AttackRolls, Modifier::Flat(-4));
+        let mut attack = Breakdown::new();
+        effects.apply(&[Target::AttackRolls], &mut attack);
+        assert_eq!(attack.total(), -4);
+
+        let mut athletics = Breakdown::new();
+        effects.apply(&Target::skill_check(Skill::Athletics), &mut athletics);
+        assert_eq!(athletics.mode(), RollMode::Advantage);
+        assert_eq!(athletics.minimum_roll().map(|(_, roll)| *roll), Some(10));
+    }
+
+    #[test]
+    fn conditions() {
+        let mut effects = Effects::new();
+        effects.add_conditions(Condition::Restrained | Condition::Invisible);
+
+        let mut speed = Breakdown::new();
+        speed.add(Source::Base, 30);
+        effects.apply(&[Target::Speed], &mut speed);
+        assert_eq!(speed.total(), 0);
+
+        let mut attack = Breakdown::new();
+        effects.apply(&[Target::AttackRolls], &mut attack);
+        assert_eq!(attack.mode(), RollMode::Normal);
+
+        let mut dexterity = Breakdown::new();
+        effects.apply(&Target::saving_throw(Ability::Dexterity), &mut dexterity);
+        assert_eq!(dexterity.mode(), RollMode::Disadvantage);
+    }
+
+    #[test]
+    fn magic_items() {
+        let mut abilities = Abilities::new();
+        abilities.strength = AbilityScore::new(20);
+        let mut effects = Effects::new();
+        effects
+            .add_magic_item(&MagicItem::GAUNTLETS_OF_OGRE_POWER, &abilities)
+            .add_magic_item(&MagicItem::RING_OF_PROTECTION, &abilities);
+
+        let mut strength = Breakdown::new();
+        effects.apply(&[Target::AbilityScore(Ability::Strength)], &mut strength);
+        assert!(strength.set().is_none());
+
+        let mut armor_class = Breakdown::new();
+        effects.apply(&[Target::ArmorClass], &mut armor_class);
+        assert_eq!(armor_class.total(), 1);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::core::{Dice, ProficiencyBonus};
+
+/// How an effect changes the statistic or roll it targets.
+///
+/// When several effects apply to the same target, they combine as follows:
+///
+/// - Flat bonuses and dice from different sources stack, but several from the same source
+///   (such as two castings of *bless*) don't; only the most potent applies, which is the one
+///   furthest from 0, so the largest penalty applies as well as the largest bonus.
+/// - Advantage and disadvantage don't stack, and cancel each other out.
+/// - If more than one effect sets the value, or a minimum roll, the highest applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Modifier {
+    /// Adds a fixed amount, which may be negative.
+    Flat(i16),
+
+    /// Adds the result of rolling dice, such as the d4 from *bless*.
+    Dice(Dice),
+
+    /// Grants advantage on the roll.
+    Advantage,
+
+    /// Imposes disadvantage on the roll.
+    Disadvantage,
+
+    /// Sets the value, ignoring every other bonus, such as a speed of 0 while grappled.
+    SetTo(i16),
+
+    /// Treats any d20 roll lower than this as this number, such as Reliable Talent.
+    MinimumRoll(u8),
+
+    /// Adds the creature's proficiency bonus, such as the Alert feat under the 2024 rules.
+    ///
+    /// It becomes a flat bonus once the proficiency bonus is known, with [`Self::resolve`], as a
+    /// [`Character`](crate::character::Character) does for its effects; until then,
+    /// [`Effects::apply`](crate::effect::Effects::apply) ignores it.
+    ProficiencyBonus,
+}
+
+impl Modifier {
+    /// Returns whether this modifier is more potent than `other`, if they are of the same kind.
+    pub(crate) fn outranks(self, other: Modifier) -> bool {
+        match (self, other) {
+            (Modifier::Flat(a), Modifier::Flat(b)) => a.unsigned_abs() > b.unsigned_abs(),
+            (Modifier::Dice(a), Modifier::Dice(b)) => {
+                a.average().unsigned_abs() > b.average().unsigned_abs()
+            }
+            (Modifier::SetTo(a), Modifier::SetTo(b)) => a > b,
+            (Modifier::MinimumRoll(a), Modifier::MinimumRoll(b)) => a > b,
+            _ => false,
+        }
+    }
+
+    /// Returns this modifier with a [`Self::ProficiencyBonus`] replaced by a flat bonus of
+    /// `proficiency_bonus`.
+    #[must_use]
+    pub fn resolve(self, proficiency_bonus: ProficiencyBonus) -> Self {
+        match self {
+            Modifier::ProficiencyBonus => Modifier::Flat(proficiency_bonus.value().into()),
+            _ => self,
+        }
+    }
+
+    /// Returns whether this modifier is of the same kind as `other`.
+    pub(crate) fn is_same_kind(self, other: Modifier) -> bool {
+        core::mem::discriminant(&self) == core::mem::discriminant(&other)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::core::{Ability, Skill};
+
+/// A statistic or roll that a [`Modifier`][crate::effect::Modifier] can affect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Target {
+    /// An ability score.
+    AbilityScore(Ability),
+
+    /// Every ability check, including skill checks and initiative.
+    AllAbilityChecks,
+
+    /// Ability checks with an ability, including skill checks that use it.
+    AbilityCheck(Ability),
+
+    /// Ability checks with a skill.
+    Skill(Skill),
+
+    /// Every saving throw.
+    AllSavingThrows,
+
+    /// Saving throws with an ability.
+    SavingThrow(Ability),
+
+    /// Armor Class.
+    ArmorClass,
+
+    /// Attack rolls, including spell attacks.
+    AttackRolls,
+
+    /// Damage rolls.
+    DamageRolls,
+
+    /// Initiative rolls, in addition to any effects on Dexterity checks.
+    Initiative,
+
+    /// Walking speed.
+    Speed,
+
+    /// Spell attack rolls, in addition to any effects on attack rolls.
+    SpellAttackRolls,
+
+    /// The saving throw DC of spells.
+    SpellSaveDc,
+}
+
+impl Target {
+    /// Returns the targets that affect a check with `skill`.
+    #[must_use]
+    pub const fn skill_check(skill: Skill) -> [Target; 3] {
+        Self::skill_check_with(skill.ability(), skill)
+    }
+
+    /// Returns the targets that affect a check with `skill`, using `ability` in place of the
+    /// skill's usual ability.
+    #[must_use]
+    pub const fn skill_check_with(ability: Ability, skill: Skill) -> [Target; 3] {
+        [
+            Target::AllAbilityChecks,
+            Target::AbilityCheck(ability),
+            Target::Skill(skill),
+        ]
+    }
+
+    /// Returns the targets that affect a check with `ability` that doesn't use a skill.
+    #[must_use]
+    pub const fn ability_check(ability: Ability) -> [Target; 2] {
+        [Target::AllAbilityChecks, Target::AbilityCheck(ability)]
+    }
+
+    /// Returns the targets that affect a saving throw with `ability`.
+    #[must_use]
+    pub const fn saving_throw(ability: Ability) -> [Target; 2] {
+        [Target::AllSavingThrows, Target::SavingThrow(ability)]
+    }
+}
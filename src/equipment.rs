@@ -0,0 +1,39 @@
+mod armor;
+pub use armor::{Armor, ArmorCategory};
+
+#[cfg(feature = "alloc")]
+mod attunement;
+#[cfg(feature = "alloc")]
+pub use attunement::{Attunee, Attunement, AttunementRequirement, Attunements};
+
+mod carrying_capacity;
+pub use carrying_capacity::{CarryingCapacity, Encumbrance, EncumbranceRule};
+
+mod charges;
+pub use charges::{Charges, Regain, RegainAmount};
+
+mod currency;
+pub use currency::{Coin, Currency, Wallet};
+
+#[cfg(feature = "alloc")]
+mod equipped;
+#[cfg(feature = "alloc")]
+pub use equipped::{Equipped, Hand};
+
+#[cfg(feature = "alloc")]
+mod inventory;
+#[cfg(feature = "alloc")]
+pub use inventory::{Container, Gear, Inventory, Item, ItemStack};
+
+#[cfg(feature = "alloc")]
+mod magic_item;
+#[cfg(feature = "alloc")]
+pub use magic_item::{MagicItem, MagicItemEffect, MagicItemKind, Rarity};
+
+mod weapon;
+pub use weapon::{
+    Grip, Weapon, WeaponCategory, WeaponKind, WeaponMastery, WeaponProperty, WeaponRange,
+};
+
+mod weight;
+pub use weight::Weight;
@@ -0,0 +1,361 @@
+use crate::{
+    core::{Abilities, AbilityScore},
+    equipment::{Coin, Currency, Weight},
+};
+use core::{fmt::Display, str::FromStr};
+use enumflags2::bitflags;
+
+/// The training needed to wear armor effectively, which also determines how Dexterity
+/// contributes to Armor Class.
+///
+/// Armor proficiencies are represented as a `BitFlags<ArmorCategory>`.
+#[bitflags]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum ArmorCategory {
+    /// Adds the full Dexterity modifier to Armor Class.
+    Light,
+
+    /// Adds up to +2 of the Dexterity modifier to Armor Class.
+    Medium,
+
+    /// Does not add the Dexterity modifier to Armor Class.
+    Heavy,
+
+    /// Carried in one hand, adding +2 to Armor Class.
+    Shield,
+}
+
+impl ArmorCategory {
+    /// Returns the name of the category, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ArmorCategory::Light => "Light",
+            ArmorCategory::Medium => "Medium",
+            ArmorCategory::Heavy => "Heavy",
+            ArmorCategory::Shield => "Shield",
+        }
+    }
+}
+
+impl Display for ArmorCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Armor or a shield from the System Reference Document.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Abilities, AbilityScore};
+/// use dnd::equipment::{Armor, ArmorCategory};
+///
+/// let mut abilities = Abilities::new();
+/// abilities.dexterity = AbilityScore::new(16);
+///
+/// // Light armor adds the full Dexterity modifier.
+/// assert_eq!(Armor::StuddedLeather.armor_class(&abilities), 15);
+///
+/// // Medium armor adds at most +2.
+/// assert_eq!(Armor::Breastplate.armor_class(&abilities), 16);
+///
+/// // Heavy armor ignores Dexterity, but has a Strength requirement.
+/// assert_eq!(Armor::Plate.armor_class(&abilities), 18);
+/// assert_eq!(Armor::Plate.strength_requirement(), Some(AbilityScore::new(15)));
+/// assert_eq!(Armor::Plate.category(), ArmorCategory::Heavy);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Armor {
+    /// Light armor.
+    Padded,
+
+    /// Light armor.
+    Leather,
+
+    /// Light armor.
+    StuddedLeather,
+
+    /// Medium armor.
+    Hide,
+
+    /// Medium armor.
+    ChainShirt,
+
+    /// Medium armor.
+    ScaleMail,
+
+    /// Medium armor.
+    Breastplate,
+
+    /// Medium armor.
+    HalfPlate,
+
+    /// Heavy armor.
+    RingMail,
+
+    /// Heavy armor.
+    ChainMail,
+
+    /// Heavy armor.
+    Splint,
+
+    /// Heavy armor.
+    Plate,
+
+    /// A shield, carried in one hand.
+    Shield,
+}
+
+impl Armor {
+    /// Returns an array containing all possible [`Armor`] variants.
+    ///
+    /// The elements are ordered as in the armor table: light, medium, and heavy armor, and then
+    /// the shield.
+    #[must_use]
+    pub const fn all() -> &'static [Armor] {
+        &[
+            Armor::Padded,
+            Armor::Leather,
+            Armor::StuddedLeather,
+            Armor::Hide,
+            Armor::ChainShirt,
+            Armor::ScaleMail,
+            Armor::Breastplate,
+            Armor::HalfPlate,
+            Armor::RingMail,
+            Armor::ChainMail,
+            Armor::Splint,
+            Armor::Plate,
+            Armor::Shield,
+        ]
+    }
+
+    /// Returns the name of the armor, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Armor::Padded => "Padded Armor",
+            Armor::Leather => "Leather Armor",
+            Armor::StuddedLeather => "Studded Leather Armor",
+            Armor::Hide => "Hide Armor",
+            Armor::ChainShirt => "Chain Shirt",
+            Armor::ScaleMail => "Scale Mail",
+            Armor::Breastplate => "Breastplate",
+            Armor::HalfPlate => "Half Plate Armor",
+            Armor::RingMail => "Ring Mail",
+            Armor::ChainMail => "Chain Mail",
+            Armor::Splint => "Splint Armor",
+            Armor::Plate => "Plate Armor",
+            Armor::Shield => "Shield",
+        }
+    }
+
+    /// Returns the category of the armor.
+    #[must_use]
+    pub const fn category(&self) -> ArmorCategory {
+        match self {
+            Armor::Padded | Armor::Leather | Armor::StuddedLeather => ArmorCategory::Light,
+            Armor::Hide
+            | Armor::ChainShirt
+            | Armor::ScaleMail
+            | Armor::Breastplate
+            | Armor::HalfPlate => ArmorCategory::Medium,
+            Armor::RingMail | Armor::ChainMail | Armor::Splint | Armor::Plate => {
+                ArmorCategory::Heavy
+            }
+            Armor::Shield => ArmorCategory::Shield,
+        }
+    }
+
+    /// Returns the Armor Class of the armor before any Dexterity modifier is added.
+    ///
+    /// For a [`Armor::Shield`], this is the bonus added to Armor Class.
+    #[must_use]
+    pub const fn base_armor_class(&self) -> u8 {
+        match self {
+            Armor::Shield => 2,
+            Armor::Padded | Armor::Leather => 11,
+            Armor::StuddedLeather | Armor::Hide => 12,
+            Armor::ChainShirt => 13,
+            Armor::ScaleMail | Armor::Breastplate | Armor::RingMail => 14,
+            Armor::HalfPlate => 15,
+            Armor::ChainMail => 16,
+            Armor::Splint => 17,
+            Armor::Plate => 18,
+        }
+    }
+
+    /// Returns the Armor Class of a creature wearing the armor.
+    ///
+    /// For a [`Armor::Shield`], this is the bonus added to Armor Class.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn armor_class(&self, abilities: &Abilities) -> u8 {
+        let dexterity = abilities.dexterity.modifier().value();
+        let bonus = match self.category() {
+            ArmorCategory::Light => dexterity,
+            ArmorCategory::Medium => dexterity.min(2),
+            ArmorCategory::Heavy | ArmorCategory::Shield => 0,
+        };
+        self.base_armor_class().saturating_add_signed(bonus)
+    }
+
+    /// Returns the Strength score needed to wear the armor without reducing speed by 10 feet.
+    #[must_use]
+    pub const fn strength_requirement(&self) -> Option<AbilityScore> {
+        match self {
+            Armor::ChainMail => Some(AbilityScore::new(13)),
+            Armor::Splint | Armor::Plate => Some(AbilityScore::new(15)),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the wearer has disadvantage on Dexterity (Stealth) checks.
+    #[must_use]
+    pub const fn stealth_disadvantage(&self) -> bool {
+        matches!(
+            self,
+            Armor::Padded
+                | Armor::ScaleMail
+                | Armor::HalfPlate
+                | Armor::RingMail
+                | Armor::ChainMail
+                | Armor::Splint
+                | Armor::Plate
+        )
+    }
+
+    /// Returns the cost of the armor.
+    #[must_use]
+    pub const fn cost(&self) -> Currency {
+        Currency::new(
+            match self {
+                Armor::Padded => 5,
+                Armor::Leather | Armor::Hide | Armor::Shield => 10,
+                Armor::RingMail => 30,
+                Armor::StuddedLeather => 45,
+                Armor::ChainShirt | Armor::ScaleMail => 50,
+                Armor::ChainMail => 75,
+                Armor::Splint => 200,
+                Armor::Breastplate => 400,
+                Armor::HalfPlate => 750,
+                Armor::Plate => 1500,
+            },
+            Coin::Gold,
+        )
+    }
+
+    /// Returns the weight of the armor.
+    #[must_use]
+    pub const fn weight(&self) -> Weight {
+        Weight::from_pounds(match self {
+            Armor::Shield => 6,
+            Armor::Padded => 8,
+            Armor::Leather => 10,
+            Armor::Hide => 12,
+            Armor::StuddedLeather => 13,
+            Armor::ChainShirt | Armor::Breastplate => 20,
+            Armor::HalfPlate | Armor::RingMail => 40,
+            Armor::ScaleMail => 45,
+            Armor::ChainMail => 55,
+            Armor::Splint => 60,
+            Armor::Plate => 65,
+        })
+    }
+}
+
+impl Display for Armor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl AsRef<str> for Armor {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl FromStr for Armor {
+    type Err = &'static str;
+
+    /// Parses the name of the armor, with or without the trailing " Armor", ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Armor::all()
+            .iter()
+            .find(|armor| {
+                let name = armor.name();
+                name.eq_ignore_ascii_case(s)
+                    || name
+                        .strip_suffix(" Armor")
+                        .is_some_and(|name| name.eq_ignore_ascii_case(s))
+            })
+            .copied()
+            .ok_or("Unknown armor")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        for armor in Armor::all() {
+            assert_eq!(armor.to_string().parse(), Ok(*armor));
+        }
+        assert_eq!("studded leather".parse(), Ok(Armor::StuddedLeather));
+        assert_eq!("Plate".parse(), Ok(Armor::Plate));
+        assert_eq!("Mithral".parse::<Armor>(), Err("Unknown armor"));
+    }
+
+    #[test]
+    fn armor_class_low_dexterity() {
+        let mut abilities = Abilities::new();
+        abilities.dexterity = AbilityScore::new(6);
+        assert_eq!(Armor::Leather.armor_class(&abilities), 9);
+        assert_eq!(Armor::Hide.armor_class(&abilities), 10);
+        assert_eq!(Armor::ChainMail.armor_class(&abilities), 16);
+        assert_eq!(Armor::Shield.armor_class(&abilities), 2);
+    }
+
+    #[test]
+    fn categories() {
+        let count = |category| {
+            Armor::all()
+                .iter()
+                .filter(|armor| armor.category() == category)
+                .count()
+        };
+        assert_eq!(count(ArmorCategory::Light), 3);
+        assert_eq!(count(ArmorCategory::Medium), 5);
+        assert_eq!(count(ArmorCategory::Heavy), 4);
+        assert_eq!(count(ArmorCategory::Shield), 1);
+    }
+
+    #[test]
+    fn cost_and_weight() {
+        assert_eq!(Armor::Plate.cost(), Currency::new(1500, Coin::Gold));
+        assert_eq!(Armor::Plate.weight(), Weight::from_pounds(65));
+        assert!(Armor::Plate.stealth_disadvantage());
+        assert!(!Armor::Breastplate.stealth_disadvantage());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let serialized = serde_json::to_string(&Armor::HalfPlate).unwrap();
+        assert_eq!(serialized, "\"HalfPlate\"");
+
+        let deserialized: Armor = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, Armor::HalfPlate);
+    }
+}
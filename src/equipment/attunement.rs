@@ -0,0 +1,341 @@
+use crate::{character::Class, creature::Alignment, equipment::MagicItem};
+use alloc::{borrow::Cow, vec::Vec};
+
+/// A condition a creature must meet to attune to a magic item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AttunementRequirement {
+    /// Must have a level in one of the given classes, such as "by a sorcerer, warlock, or wizard".
+    Class(Cow<'static, [Class]>),
+
+    /// Must be of the named species, such as "by a dwarf".
+    Species(Cow<'static, str>),
+
+    /// Must have one of the given alignments, such as "by a creature of good alignment".
+    Alignment(Cow<'static, [Alignment]>),
+
+    /// Must be able to cast at least one spell.
+    Spellcaster,
+}
+
+impl AttunementRequirement {
+    /// Returns whether `attunee` meets this requirement.
+    #[must_use]
+    pub fn is_met_by(&self, attunee: &Attunee<'_>) -> bool {
+        match self {
+            AttunementRequirement::Class(classes) => {
+                attunee.classes.iter().any(|class| classes.contains(class))
+            }
+            AttunementRequirement::Species(species) => attunee
+                .species
+                .is_some_and(|name| name.eq_ignore_ascii_case(species)),
+            AttunementRequirement::Alignment(alignments) => attunee
+                .alignment
+                .is_some_and(|alignment| alignments.contains(&alignment)),
+            AttunementRequirement::Spellcaster => attunee.spellcaster,
+        }
+    }
+}
+
+/// Whether a magic item requires attunement before its magic can be used.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Attunement {
+    /// The magic can be used without attuning to the item.
+    #[default]
+    NotRequired,
+
+    /// Any creature can attune to the item.
+    Required,
+
+    /// Only a creature that meets every one of the requirements can attune to the item.
+    RequiredBy(Cow<'static, [AttunementRequirement]>),
+}
+
+impl Attunement {
+    /// Returns whether attunement is required.
+    #[must_use]
+    pub const fn is_required(&self) -> bool {
+        !matches!(self, Attunement::NotRequired)
+    }
+
+    /// Returns whether `attunee` is allowed to attune to an item with this attunement.
+    ///
+    /// Returns `false` if attunement is not required at all.
+    #[must_use]
+    pub fn allows(&self, attunee: &Attunee<'_>) -> bool {
+        match self {
+            Attunement::NotRequired => false,
+            Attunement::Required => true,
+            Attunement::RequiredBy(requirements) => requirements
+                .iter()
+                .all(|requirement| requirement.is_met_by(attunee)),
+        }
+    }
+}
+
+/// A description of a creature trying to attune to a magic item.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Attunee<'a> {
+    /// Classes the creature has at least one level in.
+    pub classes: &'a [Class],
+
+    /// The name of the creature's species, if any.
+    pub species: Option<&'a str>,
+
+    /// The creature's alignment, if known.
+    pub alignment: Option<Alignment>,
+
+    /// Whether the creature can cast at least one spell.
+    pub spellcaster: bool,
+}
+
+/// The magic items a creature is attuned to, which enforces the limit on attuned items.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::character::Class;
+/// use dnd::equipment::{Attunee, Attunements, MagicItem};
+///
+/// let fighter = Attunee {
+///     classes: &[Class::Fighter],
+///     ..Attunee::default()
+/// };
+///
+/// let mut attunements = Attunements::new();
+/// attunements.attune(MagicItem::GAUNTLETS_OF_OGRE_POWER, &fighter).unwrap();
+/// attunements.attune(MagicItem::CLOAK_OF_PROTECTION, &fighter).unwrap();
+/// attunements.attune(MagicItem::RING_OF_PROTECTION, &fighter).unwrap();
+///
+/// // No more than three items at a time.
+/// assert!(attunements.is_full());
+/// assert!(attunements.attune(MagicItem::BELT_OF_HILL_GIANT_STRENGTH, &fighter).is_err());
+///
+/// // Some items can only be attuned to by certain creatures.
+/// attunements.end_attunement("Cloak of Protection");
+/// assert!(attunements.attune(MagicItem::HOLY_AVENGER, &fighter).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Attunements {
+    limit: u8,
+    items: Vec<MagicItem>,
+}
+
+impl Attunements {
+    /// The number of items a creature can be attuned to at once.
+    pub const DEFAULT_LIMIT: u8 = 3;
+
+    /// Creates a new `Attunements` with no items and the default limit of 3.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_limit(Self::DEFAULT_LIMIT)
+    }
+
+    /// Creates a new `Attunements` with no items and the given limit.
+    ///
+    /// Some features allow a creature to attune to more items than usual.
+    #[must_use]
+    pub const fn with_limit(limit: u8) -> Self {
+        Self {
+            limit,
+            items: Vec::new(),
+        }
+    }
+
+    /// Returns the number of items that can be attuned to at once.
+    #[must_use]
+    pub const fn limit(&self) -> u8 {
+        self.limit
+    }
+
+    /// Returns the number of items currently attuned to.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns whether no items are attuned to.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns whether the limit of attuned items has been reached.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.items.len() >= usize::from(self.limit)
+    }
+
+    /// Returns whether an item with the given name is attuned to.
+    #[must_use]
+    pub fn is_attuned(&self, name: &str) -> bool {
+        self.items.iter().any(|item| item.name == name)
+    }
+
+    /// Returns an iterator over the items attuned to.
+    pub fn iter(&self) -> impl Iterator<Item = &MagicItem> {
+        self.items.iter()
+    }
+
+    /// Attunes to `item` on behalf of `attunee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the attuned items unchanged, if:
+    ///
+    /// - The item does not require attunement.
+    /// - A copy of the item is already attuned to.
+    /// - The limit of attuned items has been reached.
+    /// - The attunee does not meet the item's requirements.
+    pub fn attune(
+        &mut self,
+        item: MagicItem,
+        attunee: &Attunee<'_>,
+    ) -> Result<&mut Self, &'static str> {
+        if !item.attunement.is_required() {
+            Err("Item does not require attunement")
+        } else if self.is_attuned(&item.name) {
+            Err("Already attuned to a copy of this item")
+        } else if self.is_full() {
+            Err("Cannot attune to any more items")
+        } else if !item.attunement.allows(attunee) {
+            Err("Attunement requirements are not met")
+        } else {
+            self.items.push(item);
+            Ok(self)
+        }
+    }
+
+    /// Ends attunement to the item with the given name, returning it if it was attuned to.
+    pub fn end_attunement(&mut self, name: &str) -> Option<MagicItem> {
+        let index = self.items.iter().position(|item| item.name == name)?;
+        Some(self.items.remove(index))
+    }
+}
+
+impl Default for Attunements {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PALADIN: Attunee<'static> = Attunee {
+        classes: &[Class::Fighter, Class::Paladin],
+        species: Some("Dwarf"),
+        alignment: Some(Alignment::LawfulGood),
+        spellcaster: true,
+    };
+
+    #[test]
+    fn requirement_class() {
+        let requirement = AttunementRequirement::Class(Cow::Borrowed(&[Class::Paladin]));
+        assert!(requirement.is_met_by(&PALADIN));
+        assert!(!requirement.is_met_by(&Attunee::default()));
+    }
+
+    #[test]
+    fn requirement_species() {
+        let requirement = AttunementRequirement::Species(Cow::Borrowed("dwarf"));
+        assert!(requirement.is_met_by(&PALADIN));
+        assert!(!requirement.is_met_by(&Attunee {
+            species: Some("Elf"),
+            ..PALADIN
+        }));
+    }
+
+    #[test]
+    fn requirement_alignment() {
+        let requirement = AttunementRequirement::Alignment(Cow::Borrowed(&[
+            Alignment::LawfulGood,
+            Alignment::NeutralGood,
+            Alignment::ChaoticGood,
+        ]));
+        assert!(requirement.is_met_by(&PALADIN));
+        assert!(!requirement.is_met_by(&Attunee {
+            alignment: Some(Alignment::Neutral),
+            ..PALADIN
+        }));
+        assert!(!requirement.is_met_by(&Attunee::default()));
+    }
+
+    #[test]
+    fn requirement_spellcaster() {
+        assert!(AttunementRequirement::Spellcaster.is_met_by(&PALADIN));
+        assert!(!AttunementRequirement::Spellcaster.is_met_by(&Attunee::default()));
+    }
+
+    #[test]
+    fn attunement_allows() {
+        assert!(!Attunement::NotRequired.allows(&PALADIN));
+        assert!(Attunement::Required.allows(&Attunee::default()));
+        let attunement = Attunement::RequiredBy(Cow::Borrowed(&[
+            AttunementRequirement::Spellcaster,
+            AttunementRequirement::Species(Cow::Borrowed("Elf")),
+        ]));
+        assert!(!attunement.allows(&PALADIN));
+    }
+
+    #[test]
+    fn attune() {
+        let mut attunements = Attunements::new();
+        attunements
+            .attune(MagicItem::HOLY_AVENGER, &PALADIN)
+            .unwrap();
+        assert!(attunements.is_attuned("Holy Avenger"));
+        assert_eq!(attunements.len(), 1);
+    }
+
+    #[test]
+    fn attune_not_required() {
+        let mut attunements = Attunements::new();
+        assert_eq!(
+            attunements.attune(MagicItem::WAND_OF_MAGIC_MISSILES, &PALADIN),
+            Err("Item does not require attunement")
+        );
+        assert!(attunements.is_empty());
+    }
+
+    #[test]
+    fn attune_duplicate() {
+        let mut attunements = Attunements::new();
+        attunements
+            .attune(MagicItem::RING_OF_PROTECTION, &PALADIN)
+            .unwrap();
+        assert_eq!(
+            attunements.attune(MagicItem::RING_OF_PROTECTION, &PALADIN),
+            Err("Already attuned to a copy of this item")
+        );
+    }
+
+    #[test]
+    fn attune_limit() {
+        let mut attunements = Attunements::with_limit(1);
+        attunements
+            .attune(MagicItem::RING_OF_PROTECTION, &PALADIN)
+            .unwrap();
+        assert_eq!(
+            attunements.attune(MagicItem::CLOAK_OF_PROTECTION, &PALADIN),
+            Err("Cannot attune to any more items")
+        );
+    }
+
+    #[test]
+    fn end_attunement() {
+        let mut attunements = Attunements::new();
+        attunements
+            .attune(MagicItem::RING_OF_PROTECTION, &PALADIN)
+            .unwrap();
+        assert_eq!(
+            attunements.end_attunement("Ring of Protection"),
+            Some(MagicItem::RING_OF_PROTECTION)
+        );
+        assert_eq!(attunements.end_attunement("Ring of Protection"), None);
+        assert!(attunements.is_empty());
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{
+    core::{Ability, AbilityScore},
+    creature::Size,
+    equipment::Weight,
+};
+use enumflags2::BitFlags;
+
+/// Which rule is used to determine whether a creature is slowed by what it carries.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum EncumbranceRule {
+    /// A creature is only slowed once it exceeds its carrying capacity.
+    #[default]
+    Standard,
+
+    /// A creature is slowed once it carries more than 5 times its Strength score, and slowed
+    /// further once it carries more than 10 times its Strength score.
+    Variant,
+}
+
+/// How much a creature is slowed by the weight it carries, pushes, or drags.
+///
+/// Ordered from least to most encumbered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Encumbrance {
+    /// Not slowed at all.
+    Unencumbered,
+
+    /// Speed drops by 10 feet ([`EncumbranceRule::Variant`] only).
+    Encumbered,
+
+    /// Speed drops by 20 feet, and has disadvantage on ability checks, attack rolls, and saving
+    /// throws that use Strength, Dexterity, or Constitution ([`EncumbranceRule::Variant`] only).
+    HeavilyEncumbered,
+
+    /// Exceeds carrying capacity, but can still push or drag the weight at a speed of 5 feet.
+    OverCapacity,
+
+    /// Exceeds the push, drag, or lift limit, and cannot move the weight at all.
+    Immobile,
+}
+
+impl Encumbrance {
+    /// Returns the speed, in feet, of a creature with the given base `speed`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dnd::equipment::Encumbrance;
+    ///
+    /// assert_eq!(Encumbrance::Unencumbered.speed(30), 30);
+    /// assert_eq!(Encumbrance::Encumbered.speed(30), 20);
+    /// assert_eq!(Encumbrance::HeavilyEncumbered.speed(30), 10);
+    /// assert_eq!(Encumbrance::OverCapacity.speed(30), 5);
+    /// ```
+    #[must_use]
+    pub const fn speed(&self, speed: u16) -> u16 {
+        match self {
+            Encumbrance::Unencumbered => speed,
+            Encumbrance::Encumbered => speed.saturating_sub(10),
+            Encumbrance::HeavilyEncumbered => speed.saturating_sub(20),
+            Encumbrance::OverCapacity => {
+                if speed < 5 {
+                    speed
+                } else {
+                    5
+                }
+            }
+            Encumbrance::Immobile => 0,
+        }
+    }
+
+    /// Returns the abilities for which ability checks, attack rolls, and saving throws are made
+    /// with disadvantage.
+    #[must_use]
+    pub fn disadvantage(&self) -> BitFlags<Ability> {
+        match self {
+            Encumbrance::HeavilyEncumbered => {
+                Ability::Strength | Ability::Dexterity | Ability::Constitution
+            }
+            _ => BitFlags::EMPTY,
+        }
+    }
+}
+
+/// How much weight a creature can carry, push, drag, or lift.
+///
+/// Capacity is derived from the Strength [`AbilityScore`], and is halved for a Tiny creature
+/// and doubled for each size category above Medium.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Abilities, Ability, AbilityScore};
+/// use dnd::creature::Size;
+/// use dnd::equipment::{CarryingCapacity, Encumbrance, EncumbranceRule, Weight};
+///
+/// let mut abilities = Abilities::new();
+/// abilities[Ability::Strength] = AbilityScore::new(15);
+///
+/// let capacity = CarryingCapacity::new(abilities[Ability::Strength], Size::Medium);
+/// assert_eq!(capacity.carry(), Weight::from_pounds(225));
+/// assert_eq!(capacity.push_drag_lift(), Weight::from_pounds(450));
+///
+/// let load = Weight::from_pounds(100);
+/// assert_eq!(capacity.encumbrance(load, EncumbranceRule::Standard), Encumbrance::Unencumbered);
+/// assert_eq!(capacity.encumbrance(load, EncumbranceRule::Variant), Encumbrance::Encumbered);
+/// ```
+///
+/// # Powerful Build
+///
+/// A creature with Powerful Build counts as one size larger, which doubles the capacity of a
+/// Medium creature:
+///
+/// ```rust
+/// use dnd::core::AbilityScore;
+/// use dnd::creature::Size;
+/// use dnd::equipment::{CarryingCapacity, Weight};
+///
+/// let capacity = CarryingCapacity::new(AbilityScore::new(15), Size::Medium).with_powerful_build();
+/// assert_eq!(capacity.carry(), Weight::from_pounds(450));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CarryingCapacity {
+    strength: AbilityScore,
+    size: Size,
+}
+
+impl CarryingCapacity {
+    /// Creates a new `CarryingCapacity` for a creature of the given Strength score and size.
+    #[must_use]
+    pub const fn new(strength: AbilityScore, size: Size) -> Self {
+        Self { strength, size }
+    }
+
+    /// Returns a copy that counts the creature as one size larger, as with Powerful Build.
+    #[must_use]
+    pub const fn with_powerful_build(self) -> Self {
+        Self {
+            size: self.size.larger(),
+            ..self
+        }
+    }
+
+    /// Returns the Strength score the capacity is derived from.
+    #[must_use]
+    pub const fn strength(&self) -> AbilityScore {
+        self.strength
+    }
+
+    /// Returns the effective size of the creature, including Powerful Build.
+    #[must_use]
+    pub const fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the Strength score multiplied by `factor`, adjusted for size.
+    const fn scaled(self, factor: u32) -> Weight {
+        let pounds = self.strength.value() as u32 * factor;
+        Weight::from_hundredths(pounds * self.size.capacity_percentage())
+    }
+
+    /// Returns the weight that can be carried without exceeding capacity.
+    #[must_use]
+    pub const fn carry(&self) -> Weight {
+        self.scaled(15)
+    }
+
+    /// Returns the weight that can be pushed, dragged, or lifted, which is twice [`Self::carry`].
+    #[must_use]
+    pub const fn push_drag_lift(&self) -> Weight {
+        self.scaled(30)
+    }
+
+    /// Returns how encumbered the creature is while carrying `load` under the given `rule`.
+    #[must_use]
+    pub fn encumbrance(&self, load: Weight, rule: EncumbranceRule) -> Encumbrance {
+        if load > self.push_drag_lift() {
+            Encumbrance::Immobile
+        } else if load > self.carry() {
+            Encumbrance::OverCapacity
+        } else if rule == EncumbranceRule::Standard || load <= self.scaled(5) {
+            Encumbrance::Unencumbered
+        } else if load <= self.scaled(10) {
+            Encumbrance::Encumbered
+        } else {
+            Encumbrance::HeavilyEncumbered
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn capacity(strength: u8, size: Size) -> CarryingCapacity {
+        CarryingCapacity::new(AbilityScore::new(strength), size)
+    }
+
+    #[test]
+    fn carry() {
+        assert_eq!(capacity(10, Size::Medium).carry(), Weight::from_pounds(150));
+        assert_eq!(capacity(10, Size::Small).carry(), Weight::from_pounds(150));
+        assert_eq!(capacity(10, Size::Tiny).carry(), Weight::from_pounds(75));
+        assert_eq!(capacity(10, Size::Large).carry(), Weight::from_pounds(300));
+        assert_eq!(capacity(10, Size::Huge).carry(), Weight::from_pounds(600));
+        assert_eq!(
+            capacity(10, Size::Gargantuan).carry(),
+            Weight::from_pounds(1200)
+        );
+    }
+
+    #[test]
+    fn tiny_odd_strength() {
+        assert_eq!(
+            capacity(3, Size::Tiny).carry(),
+            Weight::from_hundredths(2250)
+        );
+    }
+
+    #[test]
+    fn push_drag_lift() {
+        assert_eq!(
+            capacity(18, Size::Medium).push_drag_lift(),
+            Weight::from_pounds(540)
+        );
+    }
+
+    #[test]
+    fn powerful_build() {
+        let capacity = capacity(18, Size::Medium).with_powerful_build();
+        assert_eq!(capacity.size(), Size::Large);
+        assert_eq!(capacity.carry(), Weight::from_pounds(540));
+        assert_eq!(capacity.push_drag_lift(), Weight::from_pounds(1080));
+    }
+
+    #[test]
+    fn encumbrance_standard() {
+        let capacity = capacity(10, Size::Medium);
+        let cases = [
+            (0, Encumbrance::Unencumbered),
+            (150, Encumbrance::Unencumbered),
+            (151, Encumbrance::OverCapacity),
+            (300, Encumbrance::OverCapacity),
+            (301, Encumbrance::Immobile),
+        ];
+        for (pounds, expected) in cases {
+            assert_eq!(
+                capacity.encumbrance(Weight::from_pounds(pounds), EncumbranceRule::Standard),
+                expected,
+                "{pounds} lb."
+            );
+        }
+    }
+
+    #[test]
+    fn encumbrance_variant() {
+        let capacity = capacity(10, Size::Medium);
+        let cases = [
+            (50, Encumbrance::Unencumbered),
+            (51, Encumbrance::Encumbered),
+            (100, Encumbrance::Encumbered),
+            (101, Encumbrance::HeavilyEncumbered),
+            (150, Encumbrance::HeavilyEncumbered),
+            (151, Encumbrance::OverCapacity),
+        ];
+        for (pounds, expected) in cases {
+            assert_eq!(
+                capacity.encumbrance(Weight::from_pounds(pounds), EncumbranceRule::Variant),
+                expected,
+                "{pounds} lb."
+            );
+        }
+    }
+
+    #[test]
+    fn encumbrance_variant_large() {
+        let capacity = capacity(10, Size::Large);
+        assert_eq!(
+            capacity.encumbrance(Weight::from_pounds(100), EncumbranceRule::Variant),
+            Encumbrance::Unencumbered
+        );
+    }
+
+    #[test]
+    fn speed() {
+        assert_eq!(Encumbrance::HeavilyEncumbered.speed(15), 0);
+        assert_eq!(Encumbrance::OverCapacity.speed(0), 0);
+        assert_eq!(Encumbrance::Immobile.speed(30), 0);
+    }
+
+    #[test]
+    fn disadvantage() {
+        assert!(Encumbrance::Encumbered.disadvantage().is_empty());
+        assert_eq!(
+            Encumbrance::HeavilyEncumbered.disadvantage(),
+            Ability::Strength | Ability::Dexterity | Ability::Constitution
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use crate::core::{Dice, Die, Recharge};
+use core::{fmt::Display, str::FromStr};
+
+/// How many charges are regained when an item recharges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RegainAmount {
+    /// All expended charges are regained.
+    All,
+
+    /// A rolled number of charges are regained, such as `1d6+1`.
+    Dice(Dice),
+}
+
+/// How many charges an item regains, and when, such as "1d6+1 at dawn".
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Dice, Die, Recharge};
+/// use dnd::equipment::{Regain, RegainAmount};
+///
+/// let regain: Regain = "1d6+1 at dawn".parse().unwrap();
+/// assert_eq!(regain.amount, RegainAmount::Dice(Dice::new(1, Die::D6).with_modifier(1)));
+/// assert_eq!(regain.when, Recharge::Dawn);
+/// assert_eq!(regain.to_string(), "1d6+1 at dawn");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Regain {
+    /// How many charges are regained.
+    pub amount: RegainAmount,
+
+    /// When the charges are regained.
+    pub when: Recharge,
+}
+
+impl Display for Regain {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.amount {
+            RegainAmount::All => write!(f, "all")?,
+            RegainAmount::Dice(dice) => write!(f, "{dice}")?,
+        }
+        match self.when {
+            Recharge::ShortRest => write!(f, " after a short rest"),
+            Recharge::LongRest => write!(f, " after a long rest"),
+            Recharge::Dawn => write!(f, " at dawn"),
+            Recharge::Dusk => write!(f, " at dusk"),
+        }
+    }
+}
+
+impl FromStr for Regain {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (amount, when) = s
+            .split_once(" at ")
+            .or_else(|| s.split_once(" after "))
+            .ok_or("Regain must be of the form \"<amount> at <time>\"")?;
+        let amount = match amount.trim() {
+            "all" | "All" => RegainAmount::All,
+            dice => RegainAmount::Dice(dice.parse()?),
+        };
+        let when = when.trim();
+        let when = when
+            .strip_prefix("a ")
+            .or_else(|| when.strip_prefix("an "))
+            .unwrap_or(when);
+        Ok(Self {
+            amount,
+            when: when.parse()?,
+        })
+    }
+}
+
+/// The charges of a magic item, which are expended to use its properties.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::equipment::Charges;
+///
+/// let charges = Charges::new(7).with_regain("1d6+1 at dawn".parse().unwrap());
+///
+/// // With 2 charges left, rolling a 6 regains 7, but never more than the maximum.
+/// assert_eq!(charges.recharged(2, |_| 6), 7);
+/// assert_eq!(charges.recharged(2, |_| 1), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Charges {
+    /// The maximum number of charges.
+    pub maximum: u8,
+
+    /// How expended charges are regained, if at all.
+    pub regain: Option<Regain>,
+}
+
+impl Charges {
+    /// Creates a new `Charges` with the given maximum that are never regained.
+    #[must_use]
+    pub const fn new(maximum: u8) -> Self {
+        Self {
+            maximum,
+            regain: None,
+        }
+    }
+
+    /// Returns a copy that regains charges as described by `regain`.
+    #[must_use]
+    pub const fn with_regain(self, regain: Regain) -> Self {
+        Self {
+            regain: Some(regain),
+            ..self
+        }
+    }
+
+    /// Returns the number of charges after recharging from `current` charges.
+    ///
+    /// Any dice are rolled using `roll`; see [`Dice::roll`].
+    pub fn recharged(&self, current: u8, roll: impl FnMut(Die) -> u8) -> u8 {
+        let regained = match self.regain.map(|regain| regain.amount) {
+            None => 0,
+            Some(RegainAmount::All) => self.maximum,
+            Some(RegainAmount::Dice(dice)) => {
+                u8::try_from(dice.roll(roll).max(0)).unwrap_or(u8::MAX)
+            }
+        };
+        current.saturating_add(regained).min(self.maximum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_regain() {
+        let cases = [
+            (
+                "1d6+1 at dawn",
+                RegainAmount::Dice(Dice::new(1, Die::D6).with_modifier(1)),
+                Recharge::Dawn,
+            ),
+            ("all at dusk", RegainAmount::All, Recharge::Dusk),
+            (
+                "1d4 after a long rest",
+                RegainAmount::Dice(Dice::new(1, Die::D4)),
+                Recharge::LongRest,
+            ),
+            (
+                "2 after a short rest",
+                RegainAmount::Dice(Dice::flat(2)),
+                Recharge::ShortRest,
+            ),
+        ];
+        for (input, amount, when) in cases {
+            assert_eq!(input.parse(), Ok(Regain { amount, when }), "{input}");
+        }
+        assert!("1d6+1".parse::<Regain>().is_err());
+        assert!("1d6 at noon".parse::<Regain>().is_err());
+    }
+
+    #[test]
+    fn display_regain() {
+        for input in [
+            "1d6+1 at dawn",
+            "all at dusk",
+            "1d20 after a long rest",
+            "2 after a short rest",
+        ] {
+            assert_eq!(input.parse::<Regain>().unwrap().to_string(), input);
+        }
+    }
+
+    #[test]
+    fn recharged() {
+        let charges = Charges::new(3).with_regain(Regain {
+            amount: RegainAmount::All,
+            when: Recharge::Dawn,
+        });
+        assert_eq!(charges.recharged(0, |_| unreachable!()), 3);
+        assert_eq!(Charges::new(3).recharged(1, |_| unreachable!()), 1);
+    }
+
+    #[test]
+    fn recharged_negative_roll() {
+        let charges = Charges::new(3).with_regain(Regain {
+            amount: RegainAmount::Dice(Dice::new(1, Die::D4).with_modifier(-2)),
+            when: Recharge::Dawn,
+        });
+        assert_eq!(charges.recharged(1, |_| 1), 1);
+    }
+}
@@ -0,0 +1,727 @@
+use crate::equipment::Weight;
+use core::{
+    fmt::Display,
+    iter::Sum,
+    ops::{Add, AddAssign, Index, IndexMut, Mul},
+    str::FromStr,
+};
+
+/// A common coin, from copper to platinum.
+///
+/// Coins are ordered from least to most valuable.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::equipment::Coin;
+///
+/// assert_eq!(Coin::Gold.abbr(), "gp");
+/// assert_eq!(Coin::Gold.value(), 100);
+/// assert_eq!("sp".parse(), Ok(Coin::Silver));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Coin {
+    /// Copper piece (cp).
+    Copper,
+
+    /// Silver piece (sp), worth 10 copper pieces.
+    Silver,
+
+    /// Electrum piece (ep), worth 5 silver pieces.
+    Electrum,
+
+    /// Gold piece (gp), worth 10 silver pieces.
+    Gold,
+
+    /// Platinum piece (pp), worth 10 gold pieces.
+    Platinum,
+}
+
+impl Coin {
+    /// Returns an array containing all possible [`Coin`] variants, from least to most valuable.
+    #[must_use]
+    pub const fn all() -> &'static [Coin] {
+        &[
+            Coin::Copper,
+            Coin::Silver,
+            Coin::Electrum,
+            Coin::Gold,
+            Coin::Platinum,
+        ]
+    }
+
+    /// Returns the full name of the coin, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Coin::Copper => "Copper",
+            Coin::Silver => "Silver",
+            Coin::Electrum => "Electrum",
+            Coin::Gold => "Gold",
+            Coin::Platinum => "Platinum",
+        }
+    }
+
+    /// Returns the abbreviation of the coin, in all lowercase letters.
+    #[must_use]
+    pub const fn abbr(&self) -> &'static str {
+        match self {
+            Coin::Copper => "cp",
+            Coin::Silver => "sp",
+            Coin::Electrum => "ep",
+            Coin::Gold => "gp",
+            Coin::Platinum => "pp",
+        }
+    }
+
+    /// Returns the value of the coin, in copper pieces.
+    #[must_use]
+    pub const fn value(&self) -> u64 {
+        match self {
+            Coin::Copper => 1,
+            Coin::Silver => 10,
+            Coin::Electrum => 50,
+            Coin::Gold => 100,
+            Coin::Platinum => 1000,
+        }
+    }
+}
+
+impl Display for Coin {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.abbr())
+    }
+}
+
+impl FromStr for Coin {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Coin::all()
+            .iter()
+            .find(|coin| coin.abbr().eq_ignore_ascii_case(s) || coin.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or("Unknown coin")
+    }
+}
+
+/// An amount of money, stored exactly in copper pieces.
+///
+/// Prices are formatted and parsed using gold, silver, and copper pieces as in the equipment
+/// tables, although electrum and platinum pieces are also accepted when parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::equipment::{Coin, Currency};
+///
+/// let price: Currency = "2 sp 5 cp".parse().unwrap();
+/// assert_eq!(price.copper(), 25);
+/// assert_eq!(price.to_string(), "2 sp 5 cp");
+///
+/// let price = Currency::new(15, Coin::Gold);
+/// assert_eq!(price.to_string(), "15 gp");
+/// assert_eq!(price.convert(Coin::Platinum), (1, Currency::new(5, Coin::Gold)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Currency(u64);
+
+impl Currency {
+    /// No money at all.
+    pub const ZERO: Self = Self(0);
+
+    /// Creates a new `Currency` worth `count` of the given coin.
+    ///
+    /// In debug mode, this will panic if the value in copper pieces doesn't fit in a `u64`.
+    ///
+    /// In release mode, it will saturate at the largest value instead.
+    #[must_use]
+    pub const fn new(count: u64, coin: Coin) -> Self {
+        debug_assert!(
+            count.checked_mul(coin.value()).is_some(),
+            "Amount of money is too large"
+        );
+        Self(count.saturating_mul(coin.value()))
+    }
+
+    /// Creates a new `Currency` worth `count` of the given coin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value in copper pieces doesn't fit in a `u64`.
+    pub const fn try_new(count: u64, coin: Coin) -> Result<Self, &'static str> {
+        match count.checked_mul(coin.value()) {
+            Some(copper) => Ok(Self(copper)),
+            None => Err("Amount of money is too large"),
+        }
+    }
+
+    /// Creates a new `Currency` worth the given number of copper pieces.
+    #[must_use]
+    pub const fn from_copper(copper: u64) -> Self {
+        Self(copper)
+    }
+
+    /// Returns the value in copper pieces.
+    #[must_use]
+    pub const fn copper(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns how many whole coins of the given kind this amount is worth, and the remainder.
+    #[must_use]
+    pub const fn convert(&self, coin: Coin) -> (u64, Currency) {
+        (self.0 / coin.value(), Self(self.0 % coin.value()))
+    }
+
+    /// Adds `rhs`, returning `None` if the result would overflow.
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(copper) => Some(Self(copper)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs`, returning `None` if the result would be negative.
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(copper) => Some(Self(copper)),
+            None => None,
+        }
+    }
+}
+
+impl Add for Currency {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl AddAssign for Currency {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Mul<u64> for Currency {
+    type Output = Self;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        Self(self.0.saturating_mul(rhs))
+    }
+}
+
+impl Sum for Currency {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "0 cp");
+        }
+        let mut remainder = *self;
+        let mut separator = "";
+        for coin in [Coin::Gold, Coin::Silver, Coin::Copper] {
+            let (count, rest) = remainder.convert(coin);
+            if count > 0 {
+                write!(f, "{separator}{count} {coin}")?;
+                separator = " ";
+            }
+            remainder = rest;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut total = Self::ZERO;
+        let mut parts = s.split_whitespace();
+        let mut any = false;
+        while let Some(part) = parts.next() {
+            let digits = part
+                .find(|c: char| !c.is_ascii_digit() && c != ',')
+                .unwrap_or(part.len());
+            let (count, coin) = match part.split_at(digits) {
+                (count, "") => (count, parts.next().ok_or("Missing coin")?),
+                (count, coin) => (count, coin),
+            };
+            if !count.chars().any(|c| c.is_ascii_digit()) {
+                return Err("Invalid coin count");
+            }
+            let count = count
+                .chars()
+                .filter(|c| *c != ',')
+                .try_fold(0u64, |count, c| {
+                    count
+                        .checked_mul(10)?
+                        .checked_add(u64::from(c.to_digit(10)?))
+                })
+                .ok_or("Invalid coin count")?;
+            total = total
+                .checked_add(Currency::try_new(count, coin.parse()?)?)
+                .ok_or("Amount of money is too large")?;
+            any = true;
+        }
+        if any { Ok(total) } else { Err("Empty price") }
+    }
+}
+
+/// A purse of coins, tracked by how many of each coin it holds.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::equipment::{Coin, Currency, Wallet, Weight};
+///
+/// let mut wallet = Wallet {
+///     gold: 10,
+///     silver: 3,
+///     ..Wallet::new()
+/// };
+/// assert_eq!(wallet.value(), Currency::new(1030, Coin::Copper));
+/// assert_eq!(wallet.weight(), Weight::from_hundredths(26));
+///
+/// // Pay with exact coins where possible, and receive the fewest coins as change.
+/// wallet.spend("2 sp 5 cp".parse().unwrap()).unwrap();
+/// assert_eq!(wallet[Coin::Silver], 0);
+/// assert_eq!(wallet[Coin::Copper], 5);
+/// assert_eq!(wallet.value(), Currency::from_copper(1005));
+/// ```
+///
+/// # Making Change
+///
+/// An amount can be converted into the fewest coins possible:
+///
+/// ```rust
+/// use dnd::equipment::{Currency, Wallet};
+///
+/// let wallet = Wallet::fewest_coins(Currency::from_copper(1_165)).unwrap();
+/// assert_eq!(wallet.to_string(), "1 pp 1 gp 1 ep 1 sp 5 cp");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wallet {
+    /// Copper pieces (cp).
+    ///
+    /// To read or write to this field with indexing, use [`Coin::Copper`].
+    pub copper: u32,
+
+    /// Silver pieces (sp).
+    ///
+    /// To read or write to this field with indexing, use [`Coin::Silver`].
+    pub silver: u32,
+
+    /// Electrum pieces (ep).
+    ///
+    /// To read or write to this field with indexing, use [`Coin::Electrum`].
+    pub electrum: u32,
+
+    /// Gold pieces (gp).
+    ///
+    /// To read or write to this field with indexing, use [`Coin::Gold`].
+    pub gold: u32,
+
+    /// Platinum pieces (pp).
+    ///
+    /// To read or write to this field with indexing, use [`Coin::Platinum`].
+    pub platinum: u32,
+}
+
+impl Wallet {
+    /// The number of coins that weigh one pound.
+    pub const COINS_PER_POUND: u32 = 50;
+
+    /// Creates a new, empty `Wallet`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            copper: 0,
+            silver: 0,
+            electrum: 0,
+            gold: 0,
+            platinum: 0,
+        }
+    }
+
+    /// Creates a new `Wallet` holding `amount` in the fewest coins possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if more platinum pieces are needed than a wallet can hold.
+    pub fn fewest_coins(amount: Currency) -> Result<Self, &'static str> {
+        let mut wallet = Self::new();
+        let mut remainder = amount;
+        for &coin in Coin::all().iter().rev() {
+            let (count, rest) = remainder.convert(coin);
+            wallet[coin] = u32::try_from(count).map_err(|_| "Too many coins")?;
+            remainder = rest;
+        }
+        Ok(wallet)
+    }
+
+    /// Returns an iterator of each coin and how many of it the wallet holds.
+    ///
+    /// The coins are returned from least to most valuable.
+    pub fn iter(&self) -> impl Iterator<Item = (Coin, u32)> {
+        Coin::all().iter().map(move |&coin| (coin, self[coin]))
+    }
+
+    /// Returns the total number of coins held.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.iter().map(|(_, count)| u64::from(count)).sum()
+    }
+
+    /// Returns the total value of all coins held.
+    #[must_use]
+    pub fn value(&self) -> Currency {
+        self.iter()
+            .map(|(coin, count)| Currency::new(u64::from(count), coin))
+            .sum()
+    }
+
+    /// Returns the weight of all coins held, at [`Self::COINS_PER_POUND`].
+    #[must_use]
+    pub fn weight(&self) -> Weight {
+        let hundredths = self.count().saturating_mul(100) / u64::from(Self::COINS_PER_POUND);
+        Weight::from_hundredths(u32::try_from(hundredths).unwrap_or(u32::MAX))
+    }
+
+    /// Adds the coins of `other` to this wallet.
+    pub fn deposit(&mut self, other: &Wallet) -> &mut Self {
+        for (coin, count) in other.iter() {
+            self[coin] = self[coin].saturating_add(count);
+        }
+        self
+    }
+
+    /// Replaces the coins held with the fewest coins of the same total value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the wallet unchanged, if more platinum pieces are needed than a
+    /// wallet can hold.
+    pub fn consolidate(&mut self) -> Result<&mut Self, &'static str> {
+        *self = Self::fewest_coins(self.value())?;
+        Ok(self)
+    }
+
+    /// Pays `amount` from the wallet.
+    ///
+    /// Coins are paid from most to least valuable without exceeding `amount`. If that does not
+    /// pay the full amount, the least valuable coin that covers the rest is paid, and the change
+    /// is returned to the wallet in the fewest coins possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the wallet unchanged, if it holds less than `amount`.
+    pub fn spend(&mut self, amount: Currency) -> Result<&mut Self, &'static str> {
+        if self.value() < amount {
+            return Err("Not enough money");
+        }
+        let mut remainder = amount.copper();
+        for &coin in Coin::all().iter().rev() {
+            let count = u64::from(self[coin]).min(remainder / coin.value());
+            remainder -= count * coin.value();
+            self[coin] -= u32::try_from(count).unwrap_or(u32::MAX);
+        }
+        if remainder > 0 {
+            // Every coin still held is now worth more than the remainder.
+            if let Some(&coin) = Coin::all().iter().find(|&&coin| self[coin] > 0) {
+                let change = Self::fewest_coins(Currency::from_copper(coin.value() - remainder))?;
+                self[coin] -= 1;
+                self.deposit(&change);
+            }
+        }
+        Ok(self)
+    }
+}
+
+impl Index<Coin> for Wallet {
+    type Output = u32;
+
+    /// Returns how many of the given coin the wallet holds.
+    fn index(&self, coin: Coin) -> &Self::Output {
+        match coin {
+            Coin::Copper => &self.copper,
+            Coin::Silver => &self.silver,
+            Coin::Electrum => &self.electrum,
+            Coin::Gold => &self.gold,
+            Coin::Platinum => &self.platinum,
+        }
+    }
+}
+
+impl IndexMut<Coin> for Wallet {
+    /// Returns a mutable reference to how many of the given coin the wallet holds.
+    fn index_mut(&mut self, coin: Coin) -> &mut Self::Output {
+        match coin {
+            Coin::Copper => &mut self.copper,
+            Coin::Silver => &mut self.silver,
+            Coin::Electrum => &mut self.electrum,
+            Coin::Gold => &mut self.gold,
+            Coin::Platinum => &mut self.platinum,
+        }
+    }
+}
+
+impl Display for Wallet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut separator = "";
+        for &coin in Coin::all().iter().rev() {
+            if self[coin] > 0 {
+                write!(f, "{separator}{} {coin}", self[coin])?;
+                separator = " ";
+            }
+        }
+        if separator.is_empty() {
+            write!(f, "0 cp")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn coin_parse() {
+        for coin in Coin::all() {
+            assert_eq!(coin.abbr().parse(), Ok(*coin));
+            assert_eq!(coin.name().parse(), Ok(*coin));
+        }
+        assert_eq!("GP".parse(), Ok(Coin::Gold));
+        assert!("zp".parse::<Coin>().is_err());
+    }
+
+    #[test]
+    fn currency_convert() {
+        let amount = Currency::from_copper(1234);
+        assert_eq!(amount.convert(Coin::Gold), (12, Currency::from_copper(34)));
+        assert_eq!(
+            amount.convert(Coin::Electrum),
+            (24, Currency::from_copper(34))
+        );
+        assert_eq!(amount.convert(Coin::Copper), (1234, Currency::ZERO));
+    }
+
+    #[test]
+    fn currency_display() {
+        let cases = [
+            (0, "0 cp"),
+            (5, "5 cp"),
+            (25, "2 sp 5 cp"),
+            (1500, "15 gp"),
+            (100_001, "1000 gp 1 cp"),
+        ];
+        for (copper, expected) in cases {
+            assert_eq!(Currency::from_copper(copper).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn currency_parse() {
+        let cases = [
+            ("15 gp", 1500),
+            ("2 sp 5 cp", 25),
+            ("2sp 5cp", 25),
+            ("1 pp 1 ep", 1050),
+            ("1,000 gp", 100_000),
+            ("3 Gold", 300),
+        ];
+        for (input, copper) in cases {
+            assert_eq!(input.parse(), Ok(Currency::from_copper(copper)), "{input}");
+        }
+        for input in ["", "gp", "15", "15 zp", "-1 gp", "1.5 gp", ", gp", ",gp"] {
+            assert!(input.parse::<Currency>().is_err(), "{input}");
+        }
+        assert_eq!(
+            "1000000000000000000 gp".parse::<Currency>(),
+            Err("Amount of money is too large")
+        );
+        assert_eq!(
+            "18446744073709551615 cp 1 cp".parse::<Currency>(),
+            Err("Amount of money is too large")
+        );
+    }
+
+    #[test]
+    fn currency_arithmetic() {
+        let gp = Currency::new(1, Coin::Gold);
+        let sp = Currency::new(1, Coin::Silver);
+        assert_eq!(gp + sp, Currency::from_copper(110));
+        assert_eq!(sp * 3, Currency::from_copper(30));
+        assert_eq!(gp.checked_sub(sp), Some(Currency::from_copper(90)));
+        assert_eq!(sp.checked_sub(gp), None);
+        assert_eq!([gp, sp].into_iter().sum::<Currency>(), gp + sp);
+        assert_eq!(gp.checked_add(sp), Some(gp + sp));
+        assert_eq!(Currency::from_copper(u64::MAX).checked_add(sp), None);
+        assert_eq!(
+            Currency::try_new(u64::MAX, Coin::Gold),
+            Err("Amount of money is too large")
+        );
+    }
+
+    #[test]
+    fn fewest_coins() {
+        let wallet = Wallet::fewest_coins(Currency::from_copper(1_165));
+        assert_eq!(
+            wallet,
+            Ok(Wallet {
+                copper: 5,
+                silver: 1,
+                electrum: 1,
+                gold: 1,
+                platinum: 1,
+            })
+        );
+        assert_eq!(
+            Wallet::fewest_coins(Currency::from_copper(u64::MAX)),
+            Err("Too many coins")
+        );
+    }
+
+    #[test]
+    fn fewest_coins_is_minimal() {
+        // Compare the greedy result against a dynamic programming solution.
+        let mut best = [0u64; 2001];
+        for amount in 1..best.len() {
+            best[amount] = Coin::all()
+                .iter()
+                .filter_map(|coin| {
+                    let value = usize::try_from(coin.value()).unwrap();
+                    amount.checked_sub(value).map(|rest| best[rest] + 1)
+                })
+                .min()
+                .unwrap();
+        }
+        for (amount, &expected) in best.iter().enumerate() {
+            let wallet = Wallet::fewest_coins(Currency::from_copper(amount as u64)).unwrap();
+            assert_eq!(wallet.count(), expected, "{amount} cp");
+        }
+    }
+
+    #[test]
+    fn weight() {
+        let wallet = Wallet {
+            gold: 50,
+            ..Wallet::new()
+        };
+        assert_eq!(wallet.weight(), Weight::from_pounds(1));
+        assert_eq!(Wallet::new().weight(), Weight::ZERO);
+    }
+
+    #[test]
+    fn spend_exact() {
+        let mut wallet = Wallet {
+            gold: 2,
+            silver: 5,
+            ..Wallet::new()
+        };
+        wallet.spend(Currency::from_copper(130)).unwrap();
+        assert_eq!(
+            wallet,
+            Wallet {
+                gold: 1,
+                silver: 2,
+                ..Wallet::new()
+            }
+        );
+    }
+
+    #[test]
+    fn spend_with_change() {
+        let mut wallet = Wallet {
+            platinum: 1,
+            copper: 3,
+            ..Wallet::new()
+        };
+        wallet.spend(Currency::from_copper(25)).unwrap();
+        assert_eq!(wallet.value(), Currency::from_copper(978));
+        assert_eq!(
+            wallet,
+            Wallet {
+                copper: 8,
+                silver: 2,
+                electrum: 1,
+                gold: 9,
+                ..Wallet::new()
+            }
+        );
+    }
+
+    #[test]
+    fn spend_insufficient() {
+        let mut wallet = Wallet {
+            silver: 9,
+            ..Wallet::new()
+        };
+        assert_eq!(
+            wallet.spend(Currency::new(1, Coin::Gold)),
+            Err("Not enough money")
+        );
+        assert_eq!(wallet.silver, 9);
+    }
+
+    #[test]
+    fn deposit_and_consolidate() {
+        let mut wallet = Wallet {
+            copper: 150,
+            ..Wallet::new()
+        };
+        wallet.deposit(&Wallet {
+            copper: 50,
+            silver: 5,
+            ..Wallet::new()
+        });
+        assert_eq!(wallet.value(), Currency::from_copper(250));
+        wallet.consolidate().unwrap();
+        assert_eq!(wallet.to_string(), "2 gp 1 ep");
+
+        let mut wallet = Wallet {
+            platinum: u32::MAX,
+            gold: 10,
+            ..Wallet::new()
+        };
+        assert_eq!(wallet.consolidate().unwrap_err(), "Too many coins");
+        assert_eq!(wallet.gold, 10);
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut wallet = Wallet::new();
+        for (count, &coin) in (1..).zip(Coin::all()) {
+            wallet[coin] = count;
+        }
+        assert_eq!(wallet.to_string(), "5 pp 4 gp 3 ep 2 sp 1 cp");
+        assert_eq!(Wallet::new().to_string(), "0 cp");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let price = Currency::new(15, Coin::Gold);
+        let serialized = serde_json::to_string(&price).unwrap();
+        assert_eq!(serialized, "1500");
+
+        let deserialized: Currency = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, price);
+    }
+}
@@ -0,0 +1,412 @@
+use crate::{
+    core::{Abilities, Ability},
+    equipment::{Armor, ArmorCategory, Grip, Item, Weapon, WeaponProperty, Weight},
+};
+use alloc::vec::Vec;
+use enumflags2::BitFlags;
+
+/// One of a creature's two hands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Hand {
+    /// The hand used for attacks with a single weapon.
+    Main,
+
+    /// The other hand, which can hold a light weapon or a shield.
+    Off,
+}
+
+/// What a creature is wielding in its hands and wearing on its body.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Abilities, AbilityScore};
+/// use dnd::equipment::{Armor, Equipped, Hand, Weapon};
+///
+/// let mut equipped = Equipped::new();
+/// equipped.wield(Weapon::Longsword, Hand::Main).unwrap();
+/// equipped.don(Armor::ChainMail).unwrap();
+/// equipped.don(Armor::Shield).unwrap();
+/// assert_eq!(equipped.armor_class(&Abilities::new()), 18);
+///
+/// // Only one suit of armor can be worn at a time.
+/// assert!(equipped.don(Armor::Plate).is_err());
+///
+/// // A two-handed weapon needs both hands free.
+/// equipped.stow(Hand::Main);
+/// assert!(equipped.wield_two_handed(Weapon::Greatsword).is_err());
+/// equipped.doff_shield();
+/// equipped.wield_two_handed(Weapon::Greatsword).unwrap();
+/// ```
+///
+/// # Proficiency
+///
+/// Armor can be worn without proficiency, but at a cost:
+///
+/// ```rust
+/// use dnd::core::Ability;
+/// use dnd::equipment::{Armor, ArmorCategory, Equipped};
+///
+/// let mut equipped = Equipped::new();
+/// equipped.don(Armor::Plate).unwrap();
+///
+/// let proficiencies = ArmorCategory::Light | ArmorCategory::Medium;
+/// assert!(!equipped.is_proficient(proficiencies));
+/// assert!(!equipped.can_cast_spells(proficiencies));
+/// assert_eq!(
+///     equipped.disadvantage(proficiencies),
+///     Ability::Strength | Ability::Dexterity,
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Equipped {
+    main_hand: Option<Weapon>,
+    off_hand: Option<Weapon>,
+    grip: Grip,
+    armor: Option<Armor>,
+    shield: bool,
+    worn: Vec<Item>,
+}
+
+impl Equipped {
+    /// Creates a new `Equipped` with empty hands and nothing worn.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            main_hand: None,
+            off_hand: None,
+            grip: Grip::OneHanded,
+            armor: None,
+            shield: false,
+            worn: Vec::new(),
+        }
+    }
+
+    /// Returns the weapon held in `hand`.
+    ///
+    /// A weapon held in both hands is returned for [`Hand::Main`].
+    #[must_use]
+    pub const fn wielding(&self, hand: Hand) -> Option<Weapon> {
+        match hand {
+            Hand::Main => self.main_hand,
+            Hand::Off => self.off_hand,
+        }
+    }
+
+    /// Returns how the weapon in the main hand is held.
+    #[must_use]
+    pub const fn grip(&self) -> Grip {
+        self.grip
+    }
+
+    /// Returns the suit of armor worn, if any.
+    #[must_use]
+    pub const fn armor(&self) -> Option<Armor> {
+        self.armor
+    }
+
+    /// Returns whether a shield is carried.
+    #[must_use]
+    pub const fn has_shield(&self) -> bool {
+        self.shield
+    }
+
+    /// Returns the other items worn, such as cloaks and rings.
+    #[must_use]
+    pub fn worn(&self) -> &[Item] {
+        &self.worn
+    }
+
+    /// Returns whether `hand` is empty.
+    #[must_use]
+    pub const fn is_free(&self, hand: Hand) -> bool {
+        match hand {
+            Hand::Main => self.main_hand.is_none(),
+            Hand::Off => {
+                self.off_hand.is_none() && !self.shield && matches!(self.grip, Grip::OneHanded)
+            }
+        }
+    }
+
+    /// Wields `weapon` in `hand`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving what is equipped unchanged, if:
+    ///
+    /// - The weapon has the [`WeaponProperty::TwoHanded`] property; see
+    ///   [`Equipped::wield_two_handed`].
+    /// - The hand is not free.
+    pub fn wield(&mut self, weapon: Weapon, hand: Hand) -> Result<&mut Self, &'static str> {
+        if weapon.has_property(WeaponProperty::TwoHanded) {
+            return Err("Weapon must be held in two hands");
+        }
+        if !self.is_free(hand) {
+            return Err("Hand is not free");
+        }
+        match hand {
+            Hand::Main => self.main_hand = Some(weapon),
+            Hand::Off => self.off_hand = Some(weapon),
+        }
+        Ok(self)
+    }
+
+    /// Wields `weapon` in both hands, such as a greatsword or a versatile longsword.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving what is equipped unchanged, if either hand is not free.
+    pub fn wield_two_handed(&mut self, weapon: Weapon) -> Result<&mut Self, &'static str> {
+        if !self.is_free(Hand::Main) || !self.is_free(Hand::Off) {
+            return Err("Both hands must be free");
+        }
+        self.main_hand = Some(weapon);
+        self.grip = Grip::TwoHanded;
+        Ok(self)
+    }
+
+    /// Stops wielding the weapon in `hand`, returning it.
+    ///
+    /// Stowing the main hand of a weapon held in both hands frees both hands.
+    pub fn stow(&mut self, hand: Hand) -> Option<Weapon> {
+        match hand {
+            Hand::Main => {
+                self.grip = Grip::OneHanded;
+                self.main_hand.take()
+            }
+            Hand::Off => self.off_hand.take(),
+        }
+    }
+
+    /// Dons `armor`, or takes up a shield in the off hand.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving what is equipped unchanged, if:
+    ///
+    /// - A suit of armor is already worn.
+    /// - The armor is a shield, and the off hand is not free.
+    pub fn don(&mut self, armor: Armor) -> Result<&mut Self, &'static str> {
+        if armor == Armor::Shield {
+            if !self.is_free(Hand::Off) {
+                return Err("Hand is not free");
+            }
+            self.shield = true;
+        } else {
+            if self.armor.is_some() {
+                return Err("Already wearing armor");
+            }
+            self.armor = Some(armor);
+        }
+        Ok(self)
+    }
+
+    /// Doffs the suit of armor worn, returning it.
+    pub fn doff_armor(&mut self) -> Option<Armor> {
+        self.armor.take()
+    }
+
+    /// Puts down the shield, returning whether one was carried.
+    pub fn doff_shield(&mut self) -> bool {
+        core::mem::take(&mut self.shield)
+    }
+
+    /// Wears `item`, such as a cloak or a ring.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `item` is a weapon or armor; see [`Equipped::wield`] and
+    /// [`Equipped::don`].
+    pub fn wear(&mut self, item: Item) -> Result<&mut Self, &'static str> {
+        if matches!(item, Item::Weapon(_) | Item::Armor(_)) {
+            return Err("Weapons and armor cannot be worn this way");
+        }
+        self.worn.push(item);
+        Ok(self)
+    }
+
+    /// Removes the first worn item with the given name, returning it.
+    pub fn remove(&mut self, name: &str) -> Option<Item> {
+        let index = self.worn.iter().position(|item| item.name() == name)?;
+        Some(self.worn.remove(index))
+    }
+
+    /// Returns the Armor Class granted by the armor and shield, or 10 plus the Dexterity
+    /// modifier without armor.
+    #[must_use]
+    pub fn armor_class(&self, abilities: &Abilities) -> u8 {
+        let base = match self.armor {
+            Some(armor) => armor.armor_class(abilities),
+            None => 10u8.saturating_add_signed(abilities.dexterity.modifier().value()),
+        };
+        if self.shield {
+            base + Armor::Shield.base_armor_class()
+        } else {
+            base
+        }
+    }
+
+    /// Returns whether the wearer is proficient with the armor and shield, given the
+    /// categories of armor it is proficient with.
+    #[must_use]
+    pub fn is_proficient(&self, proficiencies: BitFlags<ArmorCategory>) -> bool {
+        self.armor
+            .is_none_or(|armor| proficiencies.contains(armor.category()))
+            && (!self.shield || proficiencies.contains(ArmorCategory::Shield))
+    }
+
+    /// Returns the abilities for which ability checks, attack rolls, and saving throws are made
+    /// with disadvantage, due to armor the wearer is not proficient with.
+    #[must_use]
+    pub fn disadvantage(&self, proficiencies: BitFlags<ArmorCategory>) -> BitFlags<Ability> {
+        if self.is_proficient(proficiencies) {
+            BitFlags::EMPTY
+        } else {
+            Ability::Strength | Ability::Dexterity
+        }
+    }
+
+    /// Returns whether spells can be cast, which requires proficiency with the armor worn.
+    #[must_use]
+    pub fn can_cast_spells(&self, proficiencies: BitFlags<ArmorCategory>) -> bool {
+        self.is_proficient(proficiencies)
+    }
+
+    /// Returns whether the wearer has disadvantage on Dexterity (Stealth) checks.
+    #[must_use]
+    pub fn stealth_disadvantage(&self) -> bool {
+        self.armor.is_some_and(|armor| armor.stealth_disadvantage())
+    }
+
+    /// Returns how many feet speed is reduced by, if the wearer does not meet the armor's
+    /// Strength requirement.
+    #[must_use]
+    pub fn speed_penalty(&self, abilities: &Abilities) -> u16 {
+        match self.armor.and_then(|armor| armor.strength_requirement()) {
+            Some(requirement) if abilities.strength < requirement => 10,
+            _ => 0,
+        }
+    }
+
+    /// Returns the total weight of everything wielded and worn.
+    #[must_use]
+    pub fn weight(&self) -> Weight {
+        let shield = self.shield.then_some(Armor::Shield);
+        self.main_hand
+            .into_iter()
+            .chain(self.off_hand)
+            .map(|weapon| weapon.weight())
+            .chain(
+                self.armor
+                    .into_iter()
+                    .chain(shield)
+                    .map(|armor| armor.weight()),
+            )
+            .chain(self.worn.iter().map(Item::weight))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::AbilityScore;
+    use crate::equipment::MagicItem;
+
+    #[test]
+    fn wield_two_weapons() {
+        let mut equipped = Equipped::new();
+        equipped
+            .wield(Weapon::Shortsword, Hand::Main)
+            .unwrap()
+            .wield(Weapon::Dagger, Hand::Off)
+            .unwrap();
+        assert_eq!(equipped.wielding(Hand::Off), Some(Weapon::Dagger));
+        assert_eq!(
+            equipped.wield(Weapon::Dagger, Hand::Off),
+            Err("Hand is not free")
+        );
+        assert_eq!(equipped.don(Armor::Shield), Err("Hand is not free"));
+    }
+
+    #[test]
+    fn two_handed() {
+        let mut equipped = Equipped::new();
+        assert_eq!(
+            equipped.wield(Weapon::Greatsword, Hand::Main),
+            Err("Weapon must be held in two hands")
+        );
+        equipped.wield_two_handed(Weapon::Longsword).unwrap();
+        assert_eq!(equipped.grip(), Grip::TwoHanded);
+        assert!(!equipped.is_free(Hand::Off));
+        assert_eq!(equipped.don(Armor::Shield), Err("Hand is not free"));
+
+        assert_eq!(equipped.stow(Hand::Main), Some(Weapon::Longsword));
+        assert_eq!(equipped.grip(), Grip::OneHanded);
+        assert!(equipped.is_free(Hand::Off));
+    }
+
+    #[test]
+    fn one_suit_of_armor() {
+        let mut equipped = Equipped::new();
+        equipped.don(Armor::Leather).unwrap();
+        assert_eq!(equipped.don(Armor::Hide), Err("Already wearing armor"));
+        assert_eq!(equipped.doff_armor(), Some(Armor::Leather));
+        equipped.don(Armor::Hide).unwrap();
+    }
+
+    #[test]
+    fn armor_class() {
+        let mut abilities = Abilities::new();
+        abilities.dexterity = AbilityScore::new(14);
+        let mut equipped = Equipped::new();
+        assert_eq!(equipped.armor_class(&abilities), 12);
+        equipped.don(Armor::Shield).unwrap();
+        assert_eq!(equipped.armor_class(&abilities), 14);
+        assert!(equipped.doff_shield());
+        assert!(!equipped.doff_shield());
+    }
+
+    #[test]
+    fn proficiency() {
+        let mut equipped = Equipped::new();
+        equipped.don(Armor::Shield).unwrap();
+        assert!(!equipped.is_proficient(ArmorCategory::Light.into()));
+        assert!(equipped.is_proficient(ArmorCategory::Shield.into()));
+        assert_eq!(
+            equipped.disadvantage(ArmorCategory::Shield.into()),
+            BitFlags::EMPTY
+        );
+        assert!(equipped.can_cast_spells(BitFlags::ALL));
+    }
+
+    #[test]
+    fn speed_penalty() {
+        let mut abilities = Abilities::new();
+        abilities.strength = AbilityScore::new(14);
+        let mut equipped = Equipped::new();
+        equipped.don(Armor::ChainMail).unwrap();
+        assert_eq!(equipped.speed_penalty(&abilities), 0);
+        equipped.doff_armor();
+        equipped.don(Armor::Plate).unwrap();
+        assert_eq!(equipped.speed_penalty(&abilities), 10);
+        assert!(equipped.stealth_disadvantage());
+    }
+
+    #[test]
+    fn wear() {
+        let mut equipped = Equipped::new();
+        equipped
+            .wear(MagicItem::CLOAK_OF_PROTECTION.into())
+            .unwrap();
+        assert_eq!(
+            equipped.wear(Weapon::Dagger.into()),
+            Err("Weapons and armor cannot be worn this way")
+        );
+        assert!(equipped.remove("Cloak of Protection").is_some());
+        assert!(equipped.worn().is_empty());
+    }
+}
@@ -0,0 +1,465 @@
+use crate::equipment::{Armor, Coin, Currency, Equipped, MagicItem, Weapon, Weight};
+use alloc::{borrow::Cow, vec::Vec};
+
+/// Mundane adventuring gear, such as rope or rations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gear {
+    /// The name of the gear, in title case.
+    pub name: Cow<'static, str>,
+
+    /// The cost of one unit of the gear.
+    pub cost: Currency,
+
+    /// The weight of one unit of the gear.
+    pub weight: Weight,
+}
+
+impl Gear {
+    /// Bedroll.
+    pub const BEDROLL: Self = Self::new("Bedroll", Currency::new(1, Coin::Gold), 7);
+
+    /// Rations for one day.
+    pub const RATIONS: Self = Self::new("Rations", Currency::new(5, Coin::Silver), 2);
+
+    /// Hempen rope, 50 feet.
+    pub const ROPE: Self = Self::new("Rope", Currency::new(1, Coin::Gold), 10);
+
+    /// Torch.
+    pub const TORCH: Self = Self::new("Torch", Currency::new(1, Coin::Copper), 1);
+
+    /// Waterskin, full.
+    pub const WATERSKIN: Self = Self::new("Waterskin", Currency::new(2, Coin::Silver), 5);
+
+    /// Creates a new `Gear` with a weight in whole pounds.
+    #[must_use]
+    pub const fn new(name: &'static str, cost: Currency, pounds: u32) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            cost,
+            weight: Weight::from_pounds(pounds),
+        }
+    }
+}
+
+/// Anything that can be carried in an [`Inventory`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Item {
+    /// A weapon.
+    Weapon(Weapon),
+
+    /// Armor or a shield.
+    Armor(Armor),
+
+    /// Mundane adventuring gear.
+    Gear(Gear),
+
+    /// A magic item.
+    Magic(MagicItem),
+}
+
+impl Item {
+    /// Returns the name of the item.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Item::Weapon(weapon) => weapon.name(),
+            Item::Armor(armor) => armor.name(),
+            Item::Gear(gear) => &gear.name,
+            Item::Magic(item) => &item.name,
+        }
+    }
+
+    /// Returns the weight of one of the item.
+    #[must_use]
+    pub const fn weight(&self) -> Weight {
+        match self {
+            Item::Weapon(weapon) => weapon.weight(),
+            Item::Armor(armor) => armor.weight(),
+            Item::Gear(gear) => gear.weight,
+            Item::Magic(item) => item.weight,
+        }
+    }
+}
+
+impl From<Weapon> for Item {
+    fn from(weapon: Weapon) -> Self {
+        Item::Weapon(weapon)
+    }
+}
+
+impl From<Armor> for Item {
+    fn from(armor: Armor) -> Self {
+        Item::Armor(armor)
+    }
+}
+
+impl From<Gear> for Item {
+    fn from(gear: Gear) -> Self {
+        Item::Gear(gear)
+    }
+}
+
+impl From<MagicItem> for Item {
+    fn from(item: MagicItem) -> Self {
+        Item::Magic(item)
+    }
+}
+
+/// A number of identical items, such as 10 torches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemStack {
+    /// The item in the stack.
+    pub item: Item,
+
+    /// How many of the item are in the stack.
+    pub quantity: u32,
+}
+
+impl ItemStack {
+    /// Returns the total weight of the stack.
+    #[must_use]
+    pub fn weight(&self) -> Weight {
+        self.item.weight() * self.quantity
+    }
+}
+
+/// Adds `quantity` of `item` to `stacks`, merging with an existing stack of the same item.
+fn add_to(stacks: &mut Vec<ItemStack>, item: Item, quantity: u32) {
+    if quantity == 0 {
+        return;
+    }
+    match stacks.iter_mut().find(|stack| stack.item == item) {
+        Some(stack) => stack.quantity = stack.quantity.saturating_add(quantity),
+        None => stacks.push(ItemStack { item, quantity }),
+    }
+}
+
+/// Removes `quantity` of `item` from `stacks`, dropping the stack once it is empty.
+fn remove_from(
+    stacks: &mut Vec<ItemStack>,
+    item: &Item,
+    quantity: u32,
+) -> Result<(), &'static str> {
+    let index = stacks
+        .iter()
+        .position(|stack| stack.item == *item && stack.quantity >= quantity)
+        .ok_or("Not enough of this item")?;
+    stacks[index].quantity -= quantity;
+    if stacks[index].quantity == 0 {
+        stacks.remove(index);
+    }
+    Ok(())
+}
+
+/// Something that holds other items, such as a backpack, up to a weight capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::equipment::{Container, Gear, Weight};
+///
+/// let mut backpack = Container::BACKPACK;
+/// backpack.store(Gear::RATIONS.into(), 10).unwrap();
+/// assert_eq!(backpack.load(), Weight::from_pounds(20));
+/// assert_eq!(backpack.total_weight(), Weight::from_pounds(25));
+///
+/// // A backpack holds at most 30 pounds.
+/// assert!(backpack.store(Gear::ROPE.into(), 2).is_err());
+///
+/// // A Bag of Holding always weighs the same, no matter what it holds.
+/// let mut bag = Container::BAG_OF_HOLDING;
+/// bag.store(Gear::ROPE.into(), 2).unwrap();
+/// assert_eq!(bag.total_weight(), Weight::from_pounds(15));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Container {
+    /// The name of the container, in title case.
+    pub name: Cow<'static, str>,
+
+    /// The weight of the container when empty.
+    pub weight: Weight,
+
+    /// The maximum weight of the items the container can hold.
+    pub capacity: Weight,
+
+    /// Whether the contents are held in an extradimensional space, and so do not add to the
+    /// weight of the container.
+    pub extradimensional: bool,
+
+    contents: Vec<ItemStack>,
+}
+
+impl Container {
+    /// Backpack, which holds up to 30 pounds.
+    pub const BACKPACK: Self = Self::new("Backpack", 5, 30);
+
+    /// Bag of Holding, which holds up to 500 pounds and always weighs 15 pounds.
+    pub const BAG_OF_HOLDING: Self = Self {
+        name: Cow::Borrowed("Bag of Holding"),
+        weight: Weight::from_pounds(15),
+        capacity: Weight::from_pounds(500),
+        extradimensional: true,
+        contents: Vec::new(),
+    };
+
+    /// Chest, which holds up to 300 pounds.
+    pub const CHEST: Self = Self::new("Chest", 25, 300);
+
+    /// Pouch, which holds up to 6 pounds.
+    pub const POUCH: Self = Self::new("Pouch", 1, 6);
+
+    /// Creates a new, empty `Container` weighing `pounds` that holds up to `capacity` pounds.
+    #[must_use]
+    pub const fn new(name: &'static str, pounds: u32, capacity: u32) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            weight: Weight::from_pounds(pounds),
+            capacity: Weight::from_pounds(capacity),
+            extradimensional: false,
+            contents: Vec::new(),
+        }
+    }
+
+    /// Returns the items in the container.
+    #[must_use]
+    pub fn contents(&self) -> &[ItemStack] {
+        &self.contents
+    }
+
+    /// Returns the weight of the items in the container.
+    #[must_use]
+    pub fn load(&self) -> Weight {
+        self.contents.iter().map(ItemStack::weight).sum()
+    }
+
+    /// Returns the weight of the container, including its contents unless it is
+    /// [`extradimensional`](Self::extradimensional).
+    #[must_use]
+    pub fn total_weight(&self) -> Weight {
+        if self.extradimensional {
+            self.weight
+        } else {
+            self.weight + self.load()
+        }
+    }
+
+    /// Puts `quantity` of `item` into the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the contents unchanged, if the items would exceed the capacity.
+    pub fn store(&mut self, item: Item, quantity: u32) -> Result<&mut Self, &'static str> {
+        if self.load() + item.weight() * quantity > self.capacity {
+            return Err("Container does not have enough capacity");
+        }
+        add_to(&mut self.contents, item, quantity);
+        Ok(self)
+    }
+
+    /// Takes `quantity` of `item` out of the container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the contents unchanged, if the container holds fewer than
+    /// `quantity` of the item.
+    pub fn take(&mut self, item: &Item, quantity: u32) -> Result<&mut Self, &'static str> {
+        remove_from(&mut self.contents, item, quantity)?;
+        Ok(self)
+    }
+}
+
+/// Everything a creature carries: loose items, containers, and what it has equipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::equipment::{Armor, Container, Gear, Hand, Inventory, Weapon, Weight};
+///
+/// let mut inventory = Inventory::new();
+/// inventory.add(Gear::TORCH.into(), 5);
+/// inventory.add_container(Container::BACKPACK);
+/// inventory
+///     .container_mut("Backpack")
+///     .unwrap()
+///     .store(Gear::RATIONS.into(), 5)
+///     .unwrap();
+///
+/// inventory.equipped.wield(Weapon::Longsword, Hand::Main).unwrap();
+/// inventory.equipped.don(Armor::ChainMail).unwrap();
+///
+/// // 5 (torches) + 15 (backpack and rations) + 3 (longsword) + 55 (chain mail)
+/// assert_eq!(inventory.weight(), Weight::from_pounds(78));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Inventory {
+    /// What the creature is wielding and wearing.
+    pub equipped: Equipped,
+
+    stacks: Vec<ItemStack>,
+    containers: Vec<Container>,
+}
+
+impl Inventory {
+    /// Creates a new, empty `Inventory`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            equipped: Equipped::new(),
+            stacks: Vec::new(),
+            containers: Vec::new(),
+        }
+    }
+
+    /// Returns the items carried outside of any container.
+    #[must_use]
+    pub fn stacks(&self) -> &[ItemStack] {
+        &self.stacks
+    }
+
+    /// Returns the containers carried.
+    #[must_use]
+    pub fn containers(&self) -> &[Container] {
+        &self.containers
+    }
+
+    /// Returns the first container with the given name.
+    pub fn container_mut(&mut self, name: &str) -> Option<&mut Container> {
+        self.containers
+            .iter_mut()
+            .find(|container| container.name == name)
+    }
+
+    /// Returns how many of `item` are carried, including inside containers.
+    #[must_use]
+    pub fn quantity(&self, item: &Item) -> u32 {
+        self.stacks
+            .iter()
+            .chain(self.containers.iter().flat_map(Container::contents))
+            .filter(|stack| stack.item == *item)
+            .map(|stack| stack.quantity)
+            .sum()
+    }
+
+    /// Adds `quantity` of `item` outside of any container.
+    pub fn add(&mut self, item: Item, quantity: u32) -> &mut Self {
+        add_to(&mut self.stacks, item, quantity);
+        self
+    }
+
+    /// Removes `quantity` of `item` from outside of any container.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the inventory unchanged, if fewer than `quantity` of the item
+    /// are carried outside of containers.
+    pub fn remove(&mut self, item: &Item, quantity: u32) -> Result<&mut Self, &'static str> {
+        remove_from(&mut self.stacks, item, quantity)?;
+        Ok(self)
+    }
+
+    /// Adds a container.
+    pub fn add_container(&mut self, container: Container) -> &mut Self {
+        self.containers.push(container);
+        self
+    }
+
+    /// Removes the first container with the given name, returning it with its contents.
+    pub fn remove_container(&mut self, name: &str) -> Option<Container> {
+        let index = self
+            .containers
+            .iter()
+            .position(|container| container.name == name)?;
+        Some(self.containers.remove(index))
+    }
+
+    /// Returns the total weight carried, including containers and equipped items.
+    #[must_use]
+    pub fn weight(&self) -> Weight {
+        self.stacks.iter().map(ItemStack::weight).sum::<Weight>()
+            + self.containers.iter().map(Container::total_weight).sum()
+            + self.equipped.weight()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_merges_stacks() {
+        let mut inventory = Inventory::new();
+        inventory
+            .add(Gear::TORCH.into(), 3)
+            .add(Gear::TORCH.into(), 2)
+            .add(Weapon::Dagger.into(), 0);
+        assert_eq!(inventory.stacks().len(), 1);
+        assert_eq!(inventory.quantity(&Gear::TORCH.into()), 5);
+    }
+
+    #[test]
+    fn remove() {
+        let mut inventory = Inventory::new();
+        inventory.add(Weapon::Dart.into(), 4);
+        assert_eq!(
+            inventory.remove(&Weapon::Dart.into(), 5),
+            Err("Not enough of this item")
+        );
+        inventory.remove(&Weapon::Dart.into(), 4).unwrap();
+        assert!(inventory.stacks().is_empty());
+    }
+
+    #[test]
+    fn quantity_includes_containers() {
+        let mut inventory = Inventory::new();
+        let mut pouch = Container::POUCH;
+        pouch.store(Weapon::Dart.into(), 10).unwrap();
+        inventory.add(Weapon::Dart.into(), 5).add_container(pouch);
+        assert_eq!(inventory.quantity(&Weapon::Dart.into()), 15);
+        assert_eq!(inventory.weight(), Weight::from_hundredths(475));
+    }
+
+    #[test]
+    fn container_capacity() {
+        let mut pouch = Container::POUCH;
+        pouch.store(Gear::RATIONS.into(), 3).unwrap();
+        assert_eq!(
+            pouch.store(Gear::TORCH.into(), 1),
+            Err("Container does not have enough capacity")
+        );
+        pouch.take(&Gear::RATIONS.into(), 1).unwrap();
+        pouch.store(Gear::TORCH.into(), 1).unwrap();
+        assert_eq!(
+            pouch.take(&Gear::TORCH.into(), 2),
+            Err("Not enough of this item")
+        );
+    }
+
+    #[test]
+    fn remove_container() {
+        let mut inventory = Inventory::new();
+        inventory.add_container(Container::CHEST);
+        assert_eq!(inventory.remove_container("Chest"), Some(Container::CHEST));
+        assert_eq!(inventory.remove_container("Chest"), None);
+    }
+
+    #[test]
+    fn magic_item_weight() {
+        assert_eq!(
+            Item::from(MagicItem::CLOAK_OF_PROTECTION).weight(),
+            Weight::from_pounds(1)
+        );
+        assert_eq!(
+            Item::from(MagicItem::HOLY_AVENGER).weight(),
+            Weapon::Longsword.weight()
+        );
+        assert_eq!(
+            Item::from(MagicItem::CLOAK_OF_PROTECTION).name(),
+            "Cloak of Protection"
+        );
+    }
+}
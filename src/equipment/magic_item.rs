@@ -0,0 +1,544 @@
+use crate::{
+    character::Class,
+    core::{Abilities, Ability, AbilityScore, Dice, Die, Recharge},
+    creature::Alignment,
+    equipment::{Attunement, AttunementRequirement, Charges, Regain, RegainAmount, Weapon, Weight},
+};
+use alloc::borrow::Cow;
+use core::{fmt::Display, str::FromStr};
+use enumflags2::BitFlags;
+
+/// How rare, and therefore how powerful, a magic item is.
+///
+/// Rarities are ordered from least to most rare.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Rarity {
+    /// Suitable for characters of any level.
+    Common,
+
+    /// Suitable for characters of 1st level or higher.
+    Uncommon,
+
+    /// Suitable for characters of 5th level or higher.
+    Rare,
+
+    /// Suitable for characters of 11th level or higher.
+    VeryRare,
+
+    /// Suitable for characters of 17th level or higher.
+    Legendary,
+
+    /// Unique items of incredible power.
+    Artifact,
+}
+
+impl Rarity {
+    /// Returns an array containing all possible [`Rarity`] variants, from least to most rare.
+    #[must_use]
+    pub const fn all() -> &'static [Rarity] {
+        &[
+            Rarity::Common,
+            Rarity::Uncommon,
+            Rarity::Rare,
+            Rarity::VeryRare,
+            Rarity::Legendary,
+            Rarity::Artifact,
+        ]
+    }
+
+    /// Returns the name of the rarity, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::VeryRare => "Very Rare",
+            Rarity::Legendary => "Legendary",
+            Rarity::Artifact => "Artifact",
+        }
+    }
+}
+
+impl Display for Rarity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Rarity {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rarity::all()
+            .iter()
+            .find(|rarity| rarity.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or("Unknown rarity")
+    }
+}
+
+/// The category of a magic item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum MagicItemKind {
+    /// Magic armor or shields.
+    Armor,
+
+    /// Potions and oils.
+    Potion,
+
+    /// Rings.
+    Ring,
+
+    /// Rods.
+    Rod,
+
+    /// Spell scrolls and other scrolls.
+    Scroll,
+
+    /// Staffs.
+    Staff,
+
+    /// Wands.
+    Wand,
+
+    /// Magic weapons and ammunition.
+    Weapon,
+
+    /// Any other item, such as boots, cloaks, and gauntlets.
+    WondrousItem,
+}
+
+impl MagicItemKind {
+    /// Returns the name of the category, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            MagicItemKind::Armor => "Armor",
+            MagicItemKind::Potion => "Potion",
+            MagicItemKind::Ring => "Ring",
+            MagicItemKind::Rod => "Rod",
+            MagicItemKind::Scroll => "Scroll",
+            MagicItemKind::Staff => "Staff",
+            MagicItemKind::Wand => "Wand",
+            MagicItemKind::Weapon => "Weapon",
+            MagicItemKind::WondrousItem => "Wondrous Item",
+        }
+    }
+}
+
+impl Display for MagicItemKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A change to a creature's statistics while it uses (and if required, is attuned to) an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MagicItemEffect {
+    /// Increases an ability score by an amount, but not above a maximum.
+    IncreaseAbilityScore {
+        /// The ability whose score is increased.
+        ability: Ability,
+
+        /// How much the score is increased by.
+        amount: u8,
+
+        /// The score cannot be increased above this value.
+        maximum: AbilityScore,
+    },
+
+    /// Sets an ability score, which has no effect if the score is already equal or higher.
+    SetAbilityScore(Ability, AbilityScore),
+
+    /// A bonus to saving throws using any of the given abilities.
+    SavingThrows(BitFlags<Ability>, i8),
+
+    /// A bonus to Armor Class.
+    ArmorClass(i8),
+
+    /// A bonus to attack rolls made with the item.
+    AttackRolls(i8),
+
+    /// A bonus to damage rolls made with the item.
+    DamageRolls(i8),
+
+    /// A bonus to spell attack rolls.
+    SpellAttackRolls(i8),
+
+    /// A bonus to the saving throw DC of spells.
+    SpellSaveDc(i8),
+}
+
+/// A magic item, such as a wand or a magic sword.
+///
+/// A handful of items from the System Reference Document are provided as constants; others can
+/// be described by constructing this type directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::Recharge;
+/// use dnd::equipment::{MagicItem, Rarity};
+///
+/// let wand = MagicItem::WAND_OF_MAGIC_MISSILES;
+/// assert_eq!(wand.rarity, Rarity::Uncommon);
+/// assert!(!wand.attunement.is_required());
+///
+/// let charges = wand.charges.unwrap();
+/// assert_eq!(charges.maximum, 7);
+/// assert_eq!(charges.regain.unwrap().to_string(), "1d6+1 at dawn");
+/// ```
+///
+/// # Effects
+///
+/// Effects can be applied to a creature's [`Abilities`]:
+///
+/// ```rust
+/// use dnd::core::{Abilities, Ability, AbilityScore};
+/// use dnd::equipment::MagicItem;
+///
+/// let base = Abilities::new();
+/// let items = [MagicItem::GAUNTLETS_OF_OGRE_POWER, MagicItem::RING_OF_PROTECTION];
+///
+/// let abilities = MagicItem::apply_ability_scores(&items, &base);
+/// assert_eq!(abilities[Ability::Strength], AbilityScore::new(19));
+/// assert_eq!(MagicItem::armor_class_bonus(&items), 1);
+/// assert_eq!(MagicItem::saving_throw_bonus(&items, Ability::Wisdom), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MagicItem {
+    /// The name of the item, in title case.
+    pub name: Cow<'static, str>,
+
+    /// The category of the item.
+    pub kind: MagicItemKind,
+
+    /// How rare the item is.
+    pub rarity: Rarity,
+
+    /// Whether, and by whom, the item must be attuned to.
+    pub attunement: Attunement,
+
+    /// The weight of one of the item, which for a magic weapon or armor is that of its base
+    /// item.
+    pub weight: Weight,
+
+    /// The charges of the item, if any.
+    pub charges: Option<Charges>,
+
+    /// Changes to the statistics of a creature using the item.
+    pub effects: Cow<'static, [MagicItemEffect]>,
+}
+
+impl MagicItem {
+    /// While wearing this cloak, you gain a +1 bonus to AC and saving throws.
+    pub const CLOAK_OF_PROTECTION: Self = Self {
+        name: Cow::Borrowed("Cloak of Protection"),
+        kind: MagicItemKind::WondrousItem,
+        rarity: Rarity::Uncommon,
+        attunement: Attunement::Required,
+        weight: Weight::from_pounds(1),
+        charges: None,
+        effects: Cow::Borrowed(&[
+            MagicItemEffect::ArmorClass(1),
+            MagicItemEffect::SavingThrows(BitFlags::ALL, 1),
+        ]),
+    };
+
+    /// While wearing this belt, your Strength score is 21.
+    pub const BELT_OF_HILL_GIANT_STRENGTH: Self = Self {
+        name: Cow::Borrowed("Belt of Hill Giant Strength"),
+        kind: MagicItemKind::WondrousItem,
+        rarity: Rarity::Rare,
+        attunement: Attunement::Required,
+        weight: Weight::from_pounds(1),
+        charges: None,
+        effects: Cow::Borrowed(&[MagicItemEffect::SetAbilityScore(
+            Ability::Strength,
+            AbilityScore::new(21),
+        )]),
+    };
+
+    /// A +3 warhammer that returns when thrown; only a dwarf can attune to it.
+    pub const DWARVEN_THROWER: Self = Self {
+        name: Cow::Borrowed("Dwarven Thrower"),
+        kind: MagicItemKind::Weapon,
+        rarity: Rarity::VeryRare,
+        attunement: Attunement::RequiredBy(Cow::Borrowed(&[AttunementRequirement::Species(
+            Cow::Borrowed("Dwarf"),
+        )])),
+        weight: Weapon::Warhammer.weight(),
+        charges: None,
+        effects: Cow::Borrowed(&[
+            MagicItemEffect::AttackRolls(3),
+            MagicItemEffect::DamageRolls(3),
+        ]),
+    };
+
+    /// While wearing these gauntlets, your Strength score is 19.
+    pub const GAUNTLETS_OF_OGRE_POWER: Self = Self {
+        name: Cow::Borrowed("Gauntlets of Ogre Power"),
+        kind: MagicItemKind::WondrousItem,
+        rarity: Rarity::Uncommon,
+        attunement: Attunement::Required,
+        weight: Weight::from_pounds(1),
+        charges: None,
+        effects: Cow::Borrowed(&[MagicItemEffect::SetAbilityScore(
+            Ability::Strength,
+            AbilityScore::new(19),
+        )]),
+    };
+
+    /// A +3 magic weapon that only a paladin can attune to.
+    pub const HOLY_AVENGER: Self = Self {
+        name: Cow::Borrowed("Holy Avenger"),
+        kind: MagicItemKind::Weapon,
+        rarity: Rarity::Legendary,
+        attunement: Attunement::RequiredBy(Cow::Borrowed(&[AttunementRequirement::Class(
+            Cow::Borrowed(&[Class::Paladin]),
+        )])),
+        weight: Weapon::Longsword.weight(),
+        charges: None,
+        effects: Cow::Borrowed(&[
+            MagicItemEffect::AttackRolls(3),
+            MagicItemEffect::DamageRolls(3),
+        ]),
+    };
+
+    /// While wearing this ring, you gain a +1 bonus to AC and saving throws.
+    pub const RING_OF_PROTECTION: Self = Self {
+        name: Cow::Borrowed("Ring of Protection"),
+        kind: MagicItemKind::Ring,
+        rarity: Rarity::Rare,
+        attunement: Attunement::Required,
+        weight: Weight::ZERO,
+        charges: None,
+        effects: Cow::Borrowed(&[
+            MagicItemEffect::ArmorClass(1),
+            MagicItemEffect::SavingThrows(BitFlags::ALL, 1),
+        ]),
+    };
+
+    /// A staff with 20 charges that grants +2 to AC, saving throws, and spell attack rolls.
+    pub const STAFF_OF_POWER: Self = Self {
+        name: Cow::Borrowed("Staff of Power"),
+        kind: MagicItemKind::Staff,
+        rarity: Rarity::VeryRare,
+        attunement: Attunement::RequiredBy(Cow::Borrowed(&[AttunementRequirement::Class(
+            Cow::Borrowed(&[Class::Sorcerer, Class::Warlock, Class::Wizard]),
+        )])),
+        weight: Weapon::Quarterstaff.weight(),
+        charges: Some(Charges::new(20).with_regain(Regain {
+            amount: RegainAmount::Dice(Dice::new(2, Die::D8).with_modifier(4)),
+            when: Recharge::Dawn,
+        })),
+        effects: Cow::Borrowed(&[
+            MagicItemEffect::AttackRolls(2),
+            MagicItemEffect::DamageRolls(2),
+            MagicItemEffect::ArmorClass(2),
+            MagicItemEffect::SavingThrows(BitFlags::ALL, 2),
+            MagicItemEffect::SpellAttackRolls(2),
+        ]),
+    };
+
+    /// A talisman with 7 charges that only a creature of good alignment can attune to.
+    pub const TALISMAN_OF_PURE_GOOD: Self = Self {
+        name: Cow::Borrowed("Talisman of Pure Good"),
+        kind: MagicItemKind::WondrousItem,
+        rarity: Rarity::Legendary,
+        attunement: Attunement::RequiredBy(Cow::Borrowed(&[AttunementRequirement::Alignment(
+            Cow::Borrowed(&[
+                Alignment::LawfulGood,
+                Alignment::NeutralGood,
+                Alignment::ChaoticGood,
+            ]),
+        )])),
+        weight: Weight::from_pounds(1),
+        charges: Some(Charges::new(7)),
+        effects: Cow::Borrowed(&[]),
+    };
+
+    /// A wand with 7 charges for casting *magic missile*.
+    pub const WAND_OF_MAGIC_MISSILES: Self = Self {
+        name: Cow::Borrowed("Wand of Magic Missiles"),
+        kind: MagicItemKind::Wand,
+        rarity: Rarity::Uncommon,
+        attunement: Attunement::NotRequired,
+        weight: Weight::from_pounds(1),
+        charges: Some(Charges::new(7).with_regain(Regain {
+            amount: RegainAmount::Dice(Dice::new(1, Die::D6).with_modifier(1)),
+            when: Recharge::Dawn,
+        })),
+        effects: Cow::Borrowed(&[]),
+    };
+
+    /// Returns `base` with the ability score effects of every item in `items` applied.
+    ///
+    /// Increases are applied first, and then each score is raised to the highest score set by
+    /// any item; an item never lowers a score.
+    #[must_use]
+    pub fn apply_ability_scores<'a>(
+        items: impl IntoIterator<Item = &'a MagicItem>,
+        base: &Abilities,
+    ) -> Abilities {
+        let mut abilities = base.clone();
+        let mut set = Abilities::with_uniform(AbilityScore::MIN);
+        for effect in items.into_iter().flat_map(|item| item.effects.iter()) {
+            match *effect {
+                MagicItemEffect::IncreaseAbilityScore {
+                    ability,
+                    amount,
+                    maximum,
+                } => {
+                    let score = abilities[ability].value().saturating_add(amount);
+                    abilities[ability] = AbilityScore::new_clamped(score.min(maximum.value()))
+                        .max(abilities[ability]);
+                }
+                MagicItemEffect::SetAbilityScore(ability, score) => {
+                    set[ability] = set[ability].max(score);
+                }
+                _ => {}
+            }
+        }
+        for &ability in Ability::all() {
+            abilities[ability] = abilities[ability].max(set[ability]);
+        }
+        abilities
+    }
+
+    /// Returns the total bonus to Armor Class granted by every item in `items`.
+    #[must_use]
+    pub fn armor_class_bonus<'a>(items: impl IntoIterator<Item = &'a MagicItem>) -> i8 {
+        Self::sum_effects(items, |effect| match effect {
+            MagicItemEffect::ArmorClass(bonus) => Some(*bonus),
+            _ => None,
+        })
+    }
+
+    /// Returns the total bonus to saving throws using `ability` granted by every item in `items`.
+    #[must_use]
+    pub fn saving_throw_bonus<'a>(
+        items: impl IntoIterator<Item = &'a MagicItem>,
+        ability: Ability,
+    ) -> i8 {
+        Self::sum_effects(items, |effect| match effect {
+            MagicItemEffect::SavingThrows(abilities, bonus) if abilities.contains(ability) => {
+                Some(*bonus)
+            }
+            _ => None,
+        })
+    }
+
+    fn sum_effects<'a>(
+        items: impl IntoIterator<Item = &'a MagicItem>,
+        bonus: impl Fn(&MagicItemEffect) -> Option<i8>,
+    ) -> i8 {
+        items
+            .into_iter()
+            .flat_map(|item| item.effects.iter())
+            .filter_map(bonus)
+            .fold(0, i8::saturating_add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+
+    #[test]
+    fn rarity_parse() {
+        for rarity in Rarity::all() {
+            assert_eq!(rarity.to_string().parse(), Ok(*rarity));
+        }
+        assert_eq!("very rare".parse(), Ok(Rarity::VeryRare));
+        assert!("Mythic".parse::<Rarity>().is_err());
+    }
+
+    #[test]
+    fn rarity_ordered() {
+        for pair in Rarity::all().windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
+    }
+
+    #[test]
+    fn set_ability_score() {
+        let base = Abilities::with_uniform(AbilityScore::new(20));
+        let abilities =
+            MagicItem::apply_ability_scores([&MagicItem::GAUNTLETS_OF_OGRE_POWER], &base);
+        assert_eq!(abilities, base);
+
+        let base = Abilities::new();
+        let abilities = MagicItem::apply_ability_scores(
+            [
+                &MagicItem::BELT_OF_HILL_GIANT_STRENGTH,
+                &MagicItem::GAUNTLETS_OF_OGRE_POWER,
+            ],
+            &base,
+        );
+        assert_eq!(abilities.strength, AbilityScore::new(21));
+        assert_eq!(abilities.dexterity, AbilityScore::new(10));
+    }
+
+    #[test]
+    fn increase_ability_score() {
+        let ioun_stone = MagicItem {
+            name: Cow::Borrowed("Ioun Stone of Agility"),
+            kind: MagicItemKind::WondrousItem,
+            rarity: Rarity::VeryRare,
+            attunement: Attunement::Required,
+            weight: Weight::ZERO,
+            charges: None,
+            effects: Cow::Owned(vec![MagicItemEffect::IncreaseAbilityScore {
+                ability: Ability::Dexterity,
+                amount: 2,
+                maximum: AbilityScore::new(20),
+            }]),
+        };
+        let cases = [(14, 16), (19, 20), (20, 20), (22, 22)];
+        for (dexterity, expected) in cases {
+            let base = Abilities {
+                dexterity: AbilityScore::new(dexterity),
+                ..Abilities::new()
+            };
+            let abilities = MagicItem::apply_ability_scores([&ioun_stone], &base);
+            assert_eq!(abilities.dexterity, AbilityScore::new(expected));
+        }
+    }
+
+    #[test]
+    fn armor_class_bonus() {
+        let items = [
+            MagicItem::CLOAK_OF_PROTECTION,
+            MagicItem::RING_OF_PROTECTION,
+            MagicItem::GAUNTLETS_OF_OGRE_POWER,
+        ];
+        assert_eq!(MagicItem::armor_class_bonus(&items), 2);
+        assert_eq!(MagicItem::armor_class_bonus([]), 0);
+    }
+
+    #[test]
+    fn saving_throw_bonus() {
+        let items = [MagicItem::STAFF_OF_POWER, MagicItem::CLOAK_OF_PROTECTION];
+        for &ability in Ability::all() {
+            assert_eq!(MagicItem::saving_throw_bonus(&items, ability), 3);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let item = MagicItem::STAFF_OF_POWER;
+        let serialized = serde_json::to_string(&item).unwrap();
+        let deserialized: MagicItem = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, item);
+    }
+}
@@ -0,0 +1,1009 @@
+use crate::{
+    core::{Abilities, Ability, DamageType, Dice, Die, ProficiencyBonus},
+    equipment::{Coin, Currency, Weight},
+};
+use core::{fmt::Display, str::FromStr};
+use enumflags2::{BitFlags, bitflags, make_bitflags};
+
+/// Whether a weapon requires special training to use effectively.
+#[bitflags]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum WeaponCategory {
+    /// Weapons most creatures can use with proficiency, such as clubs and daggers.
+    Simple,
+
+    /// Weapons that require specialized training, such as swords and longbows.
+    Martial,
+}
+
+/// Whether a weapon is used to attack a target within reach or at a distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum WeaponKind {
+    /// Used to attack a target within reach.
+    Melee,
+
+    /// Used to attack a target at a distance.
+    Ranged,
+}
+
+/// A special rule that applies to using a weapon.
+#[bitflags]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum WeaponProperty {
+    /// Requires ammunition to make a ranged attack; see [`Weapon::range`].
+    Ammunition,
+
+    /// Uses the higher of Strength or Dexterity for attack and damage rolls.
+    Finesse,
+
+    /// Small creatures have disadvantage on attack rolls with the weapon.
+    Heavy,
+
+    /// Small and easy to handle, ideal for fighting with two weapons.
+    Light,
+
+    /// Only one piece of ammunition can be fired per action, bonus action, or reaction.
+    Loading,
+
+    /// Adds 5 feet to your reach when you attack with it.
+    Reach,
+
+    /// Has unusual rules that are described alongside the weapon.
+    Special,
+
+    /// Can be thrown to make a ranged attack; see [`Weapon::range`].
+    Thrown,
+
+    /// Requires two hands when you attack with it.
+    TwoHanded,
+
+    /// Can be used with one or two hands; see [`Weapon::versatile_damage`].
+    Versatile,
+}
+
+impl WeaponProperty {
+    /// Returns the name of the property, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            WeaponProperty::Ammunition => "Ammunition",
+            WeaponProperty::Finesse => "Finesse",
+            WeaponProperty::Heavy => "Heavy",
+            WeaponProperty::Light => "Light",
+            WeaponProperty::Loading => "Loading",
+            WeaponProperty::Reach => "Reach",
+            WeaponProperty::Special => "Special",
+            WeaponProperty::Thrown => "Thrown",
+            WeaponProperty::TwoHanded => "Two-Handed",
+            WeaponProperty::Versatile => "Versatile",
+        }
+    }
+}
+
+impl Display for WeaponProperty {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A special attack benefit granted to characters with the Weapon Mastery feature (2024 rules).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum WeaponMastery {
+    /// On a hit, make a second attack against another creature within 5 feet of the first.
+    Cleave,
+
+    /// On a miss, deal damage equal to the ability modifier used for the attack.
+    Graze,
+
+    /// Make the extra attack of the Light property as part of the Attack action.
+    Nick,
+
+    /// On a hit, push a Large or smaller creature up to 10 feet straight away.
+    Push,
+
+    /// On a hit, the target has disadvantage on its next attack roll.
+    Sap,
+
+    /// On a hit that deals damage, reduce the target's speed by 10 feet.
+    Slow,
+
+    /// On a hit, force a Constitution saving throw or knock the target prone.
+    Topple,
+
+    /// On a hit that deals damage, have advantage on your next attack roll against the target.
+    Vex,
+}
+
+impl WeaponMastery {
+    /// Returns an array containing all possible [`WeaponMastery`] variants.
+    #[must_use]
+    pub const fn all() -> &'static [WeaponMastery] {
+        &[
+            WeaponMastery::Cleave,
+            WeaponMastery::Graze,
+            WeaponMastery::Nick,
+            WeaponMastery::Push,
+            WeaponMastery::Sap,
+            WeaponMastery::Slow,
+            WeaponMastery::Topple,
+            WeaponMastery::Vex,
+        ]
+    }
+
+    /// Returns the name of the mastery property, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            WeaponMastery::Cleave => "Cleave",
+            WeaponMastery::Graze => "Graze",
+            WeaponMastery::Nick => "Nick",
+            WeaponMastery::Push => "Push",
+            WeaponMastery::Sap => "Sap",
+            WeaponMastery::Slow => "Slow",
+            WeaponMastery::Topple => "Topple",
+            WeaponMastery::Vex => "Vex",
+        }
+    }
+}
+
+impl Display for WeaponMastery {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for WeaponMastery {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        WeaponMastery::all()
+            .iter()
+            .find(|mastery| mastery.name() == s)
+            .copied()
+            .ok_or("Unknown weapon mastery")
+    }
+}
+
+/// The normal and long range of a thrown or ammunition weapon, in feet.
+///
+/// Attacks beyond the normal range have disadvantage, and attacks beyond the long range fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeaponRange {
+    /// The distance, in feet, within which attacks are made normally.
+    pub normal: u16,
+
+    /// The maximum distance, in feet, at which attacks can be made.
+    pub long: u16,
+}
+
+/// How a weapon is held when attacking with it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Grip {
+    /// Held in one hand.
+    #[default]
+    OneHanded,
+
+    /// Held in both hands, using the versatile damage if the weapon has one.
+    TwoHanded,
+}
+
+/// A weapon from the System Reference Document.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{DamageType, Dice, Die};
+/// use dnd::equipment::{Weapon, WeaponCategory, WeaponKind, WeaponProperty};
+///
+/// let rapier = Weapon::Rapier;
+/// assert_eq!(rapier.category(), WeaponCategory::Martial);
+/// assert_eq!(rapier.kind(), WeaponKind::Melee);
+/// assert_eq!(rapier.damage(), Some(Dice::new(1, Die::D8)));
+/// assert_eq!(rapier.damage_type(), Some(DamageType::Piercing));
+/// assert!(rapier.has_property(WeaponProperty::Finesse));
+/// ```
+///
+/// # Attacking
+///
+/// The ability used for attack and damage rolls is chosen from [`Abilities`]:
+///
+/// ```rust
+/// use dnd::core::{Abilities, Ability, AbilityScore, Dice, Die, ProficiencyBonus};
+/// use dnd::equipment::{Grip, Weapon};
+///
+/// let mut abilities = Abilities::new();
+/// abilities.strength = AbilityScore::new(12);
+/// abilities.dexterity = AbilityScore::new(16);
+///
+/// // Finesse weapons use the better of Strength and Dexterity.
+/// assert_eq!(Weapon::Rapier.attack_ability(&abilities), Ability::Dexterity);
+/// assert_eq!(Weapon::Rapier.attack_bonus(&abilities, ProficiencyBonus::new(2), true), 5);
+/// assert_eq!(Weapon::Rapier.attack_bonus(&abilities, ProficiencyBonus::new(2), false), 3);
+///
+/// // Other melee weapons use Strength, even when two-handed.
+/// assert_eq!(
+///     Weapon::Longsword.damage_roll(&abilities, Grip::TwoHanded),
+///     Some(Dice::new(1, Die::D10).with_modifier(1)),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::unsafe_derive_deserialize)] // `make_bitflags!` expands to an `unsafe` block.
+#[repr(u8)]
+pub enum Weapon {
+    /// Simple melee weapon.
+    Club,
+
+    /// Simple melee weapon.
+    Dagger,
+
+    /// Simple melee weapon.
+    Greatclub,
+
+    /// Simple melee weapon.
+    Handaxe,
+
+    /// Simple melee weapon.
+    Javelin,
+
+    /// Simple melee weapon.
+    LightHammer,
+
+    /// Simple melee weapon.
+    Mace,
+
+    /// Simple melee weapon.
+    Quarterstaff,
+
+    /// Simple melee weapon.
+    Sickle,
+
+    /// Simple melee weapon.
+    Spear,
+
+    /// Simple ranged weapon.
+    LightCrossbow,
+
+    /// Simple ranged weapon.
+    Dart,
+
+    /// Simple ranged weapon.
+    Shortbow,
+
+    /// Simple ranged weapon.
+    Sling,
+
+    /// Martial melee weapon.
+    Battleaxe,
+
+    /// Martial melee weapon.
+    Flail,
+
+    /// Martial melee weapon.
+    Glaive,
+
+    /// Martial melee weapon.
+    Greataxe,
+
+    /// Martial melee weapon.
+    Greatsword,
+
+    /// Martial melee weapon.
+    Halberd,
+
+    /// Martial melee weapon.
+    Lance,
+
+    /// Martial melee weapon.
+    Longsword,
+
+    /// Martial melee weapon.
+    Maul,
+
+    /// Martial melee weapon.
+    Morningstar,
+
+    /// Martial melee weapon.
+    Pike,
+
+    /// Martial melee weapon.
+    Rapier,
+
+    /// Martial melee weapon.
+    Scimitar,
+
+    /// Martial melee weapon.
+    Shortsword,
+
+    /// Martial melee weapon.
+    Trident,
+
+    /// Martial melee weapon.
+    WarPick,
+
+    /// Martial melee weapon.
+    Warhammer,
+
+    /// Martial melee weapon.
+    Whip,
+
+    /// Martial ranged weapon.
+    Blowgun,
+
+    /// Martial ranged weapon.
+    HandCrossbow,
+
+    /// Martial ranged weapon.
+    HeavyCrossbow,
+
+    /// Martial ranged weapon.
+    Longbow,
+
+    /// Martial ranged weapon.
+    Net,
+}
+
+impl Weapon {
+    /// Returns an array containing all possible [`Weapon`] variants.
+    ///
+    /// The elements are ordered as in the weapons table: simple melee, simple ranged, martial
+    /// melee, and then martial ranged weapons.
+    #[must_use]
+    pub const fn all() -> &'static [Weapon] {
+        &[
+            Weapon::Club,
+            Weapon::Dagger,
+            Weapon::Greatclub,
+            Weapon::Handaxe,
+            Weapon::Javelin,
+            Weapon::LightHammer,
+            Weapon::Mace,
+            Weapon::Quarterstaff,
+            Weapon::Sickle,
+            Weapon::Spear,
+            Weapon::LightCrossbow,
+            Weapon::Dart,
+            Weapon::Shortbow,
+            Weapon::Sling,
+            Weapon::Battleaxe,
+            Weapon::Flail,
+            Weapon::Glaive,
+            Weapon::Greataxe,
+            Weapon::Greatsword,
+            Weapon::Halberd,
+            Weapon::Lance,
+            Weapon::Longsword,
+            Weapon::Maul,
+            Weapon::Morningstar,
+            Weapon::Pike,
+            Weapon::Rapier,
+            Weapon::Scimitar,
+            Weapon::Shortsword,
+            Weapon::Trident,
+            Weapon::WarPick,
+            Weapon::Warhammer,
+            Weapon::Whip,
+            Weapon::Blowgun,
+            Weapon::HandCrossbow,
+            Weapon::HeavyCrossbow,
+            Weapon::Longbow,
+            Weapon::Net,
+        ]
+    }
+
+    /// Returns the name of the weapon, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Weapon::Club => "Club",
+            Weapon::Dagger => "Dagger",
+            Weapon::Greatclub => "Greatclub",
+            Weapon::Handaxe => "Handaxe",
+            Weapon::Javelin => "Javelin",
+            Weapon::LightHammer => "Light Hammer",
+            Weapon::Mace => "Mace",
+            Weapon::Quarterstaff => "Quarterstaff",
+            Weapon::Sickle => "Sickle",
+            Weapon::Spear => "Spear",
+            Weapon::LightCrossbow => "Light Crossbow",
+            Weapon::Dart => "Dart",
+            Weapon::Shortbow => "Shortbow",
+            Weapon::Sling => "Sling",
+            Weapon::Battleaxe => "Battleaxe",
+            Weapon::Flail => "Flail",
+            Weapon::Glaive => "Glaive",
+            Weapon::Greataxe => "Greataxe",
+            Weapon::Greatsword => "Greatsword",
+            Weapon::Halberd => "Halberd",
+            Weapon::Lance => "Lance",
+            Weapon::Longsword => "Longsword",
+            Weapon::Maul => "Maul",
+            Weapon::Morningstar => "Morningstar",
+            Weapon::Pike => "Pike",
+            Weapon::Rapier => "Rapier",
+            Weapon::Scimitar => "Scimitar",
+            Weapon::Shortsword => "Shortsword",
+            Weapon::Trident => "Trident",
+            Weapon::WarPick => "War Pick",
+            Weapon::Warhammer => "Warhammer",
+            Weapon::Whip => "Whip",
+            Weapon::Blowgun => "Blowgun",
+            Weapon::HandCrossbow => "Hand Crossbow",
+            Weapon::HeavyCrossbow => "Heavy Crossbow",
+            Weapon::Longbow => "Longbow",
+            Weapon::Net => "Net",
+        }
+    }
+
+    /// Returns whether the weapon is simple or martial.
+    #[must_use]
+    pub const fn category(&self) -> WeaponCategory {
+        if (*self as u8) < Weapon::Battleaxe as u8 {
+            WeaponCategory::Simple
+        } else {
+            WeaponCategory::Martial
+        }
+    }
+
+    /// Returns whether the weapon is a melee or ranged weapon.
+    ///
+    /// Melee weapons with the [`WeaponProperty::Thrown`] property are still melee weapons.
+    #[must_use]
+    pub const fn kind(&self) -> WeaponKind {
+        match self {
+            Weapon::LightCrossbow
+            | Weapon::Dart
+            | Weapon::Shortbow
+            | Weapon::Sling
+            | Weapon::Blowgun
+            | Weapon::HandCrossbow
+            | Weapon::HeavyCrossbow
+            | Weapon::Longbow
+            | Weapon::Net => WeaponKind::Ranged,
+            _ => WeaponKind::Melee,
+        }
+    }
+
+    /// Returns the damage dealt on a hit, before any modifiers.
+    ///
+    /// Returns `None` for weapons that deal no damage, such as the [`Weapon::Net`].
+    #[must_use]
+    pub const fn damage(&self) -> Option<Dice> {
+        let (count, die) = match self {
+            Weapon::Blowgun => return Some(Dice::flat(1)),
+            Weapon::Net => return None,
+            Weapon::Club
+            | Weapon::Dagger
+            | Weapon::LightHammer
+            | Weapon::Sickle
+            | Weapon::Dart
+            | Weapon::Sling
+            | Weapon::Whip => (1, Die::D4),
+            Weapon::Handaxe
+            | Weapon::Javelin
+            | Weapon::Mace
+            | Weapon::Quarterstaff
+            | Weapon::Spear
+            | Weapon::Shortbow
+            | Weapon::Scimitar
+            | Weapon::Shortsword
+            | Weapon::Trident
+            | Weapon::HandCrossbow => (1, Die::D6),
+            Weapon::Greatclub
+            | Weapon::LightCrossbow
+            | Weapon::Battleaxe
+            | Weapon::Flail
+            | Weapon::Longsword
+            | Weapon::Morningstar
+            | Weapon::Rapier
+            | Weapon::WarPick
+            | Weapon::Warhammer
+            | Weapon::Longbow => (1, Die::D8),
+            Weapon::Glaive | Weapon::Halberd | Weapon::Pike | Weapon::HeavyCrossbow => {
+                (1, Die::D10)
+            }
+            Weapon::Greataxe | Weapon::Lance => (1, Die::D12),
+            Weapon::Greatsword | Weapon::Maul => (2, Die::D6),
+        };
+        Some(Dice::new(count, die))
+    }
+
+    /// Returns the type of damage dealt on a hit.
+    ///
+    /// Returns `None` for weapons that deal no damage, such as the [`Weapon::Net`].
+    #[must_use]
+    pub const fn damage_type(&self) -> Option<DamageType> {
+        Some(match self {
+            Weapon::Net => return None,
+            Weapon::Club
+            | Weapon::Greatclub
+            | Weapon::LightHammer
+            | Weapon::Mace
+            | Weapon::Quarterstaff
+            | Weapon::Sling
+            | Weapon::Flail
+            | Weapon::Maul
+            | Weapon::Warhammer => DamageType::Bludgeoning,
+            Weapon::Handaxe
+            | Weapon::Sickle
+            | Weapon::Battleaxe
+            | Weapon::Glaive
+            | Weapon::Greataxe
+            | Weapon::Greatsword
+            | Weapon::Halberd
+            | Weapon::Longsword
+            | Weapon::Scimitar
+            | Weapon::Whip => DamageType::Slashing,
+            _ => DamageType::Piercing,
+        })
+    }
+
+    /// Returns the properties of the weapon.
+    #[must_use]
+    pub const fn properties(&self) -> BitFlags<WeaponProperty> {
+        match self {
+            Weapon::Club | Weapon::Sickle => make_bitflags!(WeaponProperty::{Light}),
+            Weapon::Dagger => make_bitflags!(WeaponProperty::{Finesse | Light | Thrown}),
+            Weapon::Greatclub => make_bitflags!(WeaponProperty::{TwoHanded}),
+            Weapon::Handaxe | Weapon::LightHammer => {
+                make_bitflags!(WeaponProperty::{Light | Thrown})
+            }
+            Weapon::Javelin => make_bitflags!(WeaponProperty::{Thrown}),
+            Weapon::Mace | Weapon::Flail | Weapon::Morningstar | Weapon::WarPick => BitFlags::EMPTY,
+            Weapon::Quarterstaff | Weapon::Battleaxe | Weapon::Longsword | Weapon::Warhammer => {
+                make_bitflags!(WeaponProperty::{Versatile})
+            }
+            Weapon::Spear | Weapon::Trident => {
+                make_bitflags!(WeaponProperty::{Thrown | Versatile})
+            }
+            Weapon::LightCrossbow => {
+                make_bitflags!(WeaponProperty::{Ammunition | Loading | TwoHanded})
+            }
+            Weapon::Dart => make_bitflags!(WeaponProperty::{Finesse | Thrown}),
+            Weapon::Shortbow => make_bitflags!(WeaponProperty::{Ammunition | TwoHanded}),
+            Weapon::Sling => make_bitflags!(WeaponProperty::{Ammunition}),
+            Weapon::Glaive | Weapon::Halberd | Weapon::Pike => {
+                make_bitflags!(WeaponProperty::{Heavy | Reach | TwoHanded})
+            }
+            Weapon::Greataxe | Weapon::Greatsword | Weapon::Maul => {
+                make_bitflags!(WeaponProperty::{Heavy | TwoHanded})
+            }
+            Weapon::Lance => make_bitflags!(WeaponProperty::{Reach | Special}),
+            Weapon::Rapier => make_bitflags!(WeaponProperty::{Finesse}),
+            Weapon::Scimitar | Weapon::Shortsword => {
+                make_bitflags!(WeaponProperty::{Finesse | Light})
+            }
+            Weapon::Whip => make_bitflags!(WeaponProperty::{Finesse | Reach}),
+            Weapon::Blowgun => make_bitflags!(WeaponProperty::{Ammunition | Loading}),
+            Weapon::HandCrossbow => make_bitflags!(WeaponProperty::{Ammunition | Light | Loading}),
+            Weapon::HeavyCrossbow => {
+                make_bitflags!(WeaponProperty::{Ammunition | Heavy | Loading | TwoHanded})
+            }
+            Weapon::Longbow => make_bitflags!(WeaponProperty::{Ammunition | Heavy | TwoHanded}),
+            Weapon::Net => make_bitflags!(WeaponProperty::{Special | Thrown}),
+        }
+    }
+
+    /// Returns whether the weapon has the given property.
+    #[must_use]
+    pub const fn has_property(&self, property: WeaponProperty) -> bool {
+        self.properties().bits_c() & property as u16 != 0
+    }
+
+    /// Returns the range of a weapon with the [`WeaponProperty::Ammunition`] or
+    /// [`WeaponProperty::Thrown`] property.
+    #[must_use]
+    pub const fn range(&self) -> Option<WeaponRange> {
+        let (normal, long) = match self {
+            Weapon::Net => (5, 15),
+            Weapon::Dagger
+            | Weapon::Handaxe
+            | Weapon::LightHammer
+            | Weapon::Spear
+            | Weapon::Dart
+            | Weapon::Trident => (20, 60),
+            Weapon::Blowgun => (25, 100),
+            Weapon::Javelin | Weapon::Sling | Weapon::HandCrossbow => (30, 120),
+            Weapon::LightCrossbow | Weapon::Shortbow => (80, 320),
+            Weapon::HeavyCrossbow => (100, 400),
+            Weapon::Longbow => (150, 600),
+            _ => return None,
+        };
+        Some(WeaponRange { normal, long })
+    }
+
+    /// Returns the reach of the weapon, in feet.
+    #[must_use]
+    pub const fn reach(&self) -> u8 {
+        if self.has_property(WeaponProperty::Reach) {
+            10
+        } else {
+            5
+        }
+    }
+
+    /// Returns the damage dealt when a [`WeaponProperty::Versatile`] weapon is used two-handed.
+    #[must_use]
+    pub const fn versatile_damage(&self) -> Option<Dice> {
+        match self {
+            Weapon::Quarterstaff | Weapon::Spear | Weapon::Trident => Some(Dice::new(1, Die::D8)),
+            Weapon::Battleaxe | Weapon::Longsword | Weapon::Warhammer => {
+                Some(Dice::new(1, Die::D10))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the mastery property of the weapon (2024 rules).
+    ///
+    /// Returns `None` for weapons without a mastery property, such as the [`Weapon::Net`].
+    #[must_use]
+    pub const fn mastery(&self) -> Option<WeaponMastery> {
+        Some(match self {
+            Weapon::Net => return None,
+            Weapon::Greataxe | Weapon::Halberd => WeaponMastery::Cleave,
+            Weapon::Glaive | Weapon::Greatsword => WeaponMastery::Graze,
+            Weapon::Dagger | Weapon::LightHammer | Weapon::Sickle | Weapon::Scimitar => {
+                WeaponMastery::Nick
+            }
+            Weapon::Greatclub | Weapon::Pike | Weapon::Warhammer | Weapon::HeavyCrossbow => {
+                WeaponMastery::Push
+            }
+            Weapon::Mace
+            | Weapon::Spear
+            | Weapon::Flail
+            | Weapon::Longsword
+            | Weapon::Morningstar
+            | Weapon::WarPick => WeaponMastery::Sap,
+            Weapon::Club
+            | Weapon::Javelin
+            | Weapon::LightCrossbow
+            | Weapon::Sling
+            | Weapon::Whip
+            | Weapon::Longbow => WeaponMastery::Slow,
+            Weapon::Quarterstaff
+            | Weapon::Battleaxe
+            | Weapon::Lance
+            | Weapon::Maul
+            | Weapon::Trident => WeaponMastery::Topple,
+            Weapon::Handaxe
+            | Weapon::Dart
+            | Weapon::Shortbow
+            | Weapon::Rapier
+            | Weapon::Shortsword
+            | Weapon::Blowgun
+            | Weapon::HandCrossbow => WeaponMastery::Vex,
+        })
+    }
+
+    /// Returns the cost of the weapon.
+    #[must_use]
+    pub const fn cost(&self) -> Currency {
+        let (count, coin) = match self {
+            Weapon::Dart => (5, Coin::Copper),
+            Weapon::Club | Weapon::Sling => (1, Coin::Silver),
+            Weapon::Greatclub | Weapon::Quarterstaff => (2, Coin::Silver),
+            Weapon::Javelin => (5, Coin::Silver),
+            Weapon::Sickle | Weapon::Spear | Weapon::Net => (1, Coin::Gold),
+            Weapon::Dagger | Weapon::LightHammer | Weapon::Whip => (2, Coin::Gold),
+            Weapon::Handaxe | Weapon::Mace | Weapon::Pike | Weapon::Trident | Weapon::WarPick => {
+                (5, Coin::Gold)
+            }
+            Weapon::Battleaxe
+            | Weapon::Flail
+            | Weapon::Lance
+            | Weapon::Maul
+            | Weapon::Shortsword
+            | Weapon::Blowgun => (10, Coin::Gold),
+            Weapon::Longsword | Weapon::Morningstar | Weapon::Warhammer => (15, Coin::Gold),
+            Weapon::Glaive | Weapon::Halberd => (20, Coin::Gold),
+            Weapon::LightCrossbow | Weapon::Shortbow | Weapon::Rapier | Weapon::Scimitar => {
+                (25, Coin::Gold)
+            }
+            Weapon::Greataxe => (30, Coin::Gold),
+            Weapon::Greatsword | Weapon::HeavyCrossbow | Weapon::Longbow => (50, Coin::Gold),
+            Weapon::HandCrossbow => (75, Coin::Gold),
+        };
+        Currency::new(count, coin)
+    }
+
+    /// Returns the weight of the weapon.
+    #[must_use]
+    pub const fn weight(&self) -> Weight {
+        Weight::from_pounds(match self {
+            Weapon::Sling => 0,
+            Weapon::Dart => return Weight::from_hundredths(25),
+            Weapon::Dagger | Weapon::Blowgun => 1,
+            Weapon::Club
+            | Weapon::Handaxe
+            | Weapon::Javelin
+            | Weapon::LightHammer
+            | Weapon::Sickle
+            | Weapon::Shortbow
+            | Weapon::Flail
+            | Weapon::Rapier
+            | Weapon::Shortsword
+            | Weapon::WarPick
+            | Weapon::Warhammer
+            | Weapon::Longbow => 2,
+            Weapon::Spear
+            | Weapon::Longsword
+            | Weapon::Scimitar
+            | Weapon::Whip
+            | Weapon::HandCrossbow
+            | Weapon::Net => 3,
+            Weapon::Mace
+            | Weapon::Quarterstaff
+            | Weapon::Battleaxe
+            | Weapon::Morningstar
+            | Weapon::Trident => 4,
+            Weapon::LightCrossbow => 5,
+            Weapon::Glaive | Weapon::Greatsword | Weapon::Halberd | Weapon::Lance => 6,
+            Weapon::Greataxe => 7,
+            Weapon::Greatclub | Weapon::Maul => 10,
+            Weapon::Pike | Weapon::HeavyCrossbow => 18,
+        })
+    }
+
+    /// Returns the ability used for attack and damage rolls with this weapon.
+    ///
+    /// - [`WeaponProperty::Finesse`] weapons use the higher of Strength and Dexterity.
+    /// - Ranged weapons use Dexterity.
+    /// - Melee weapons use Strength, including when thrown.
+    #[must_use]
+    pub fn attack_ability(&self, abilities: &Abilities) -> Ability {
+        if self.has_property(WeaponProperty::Finesse) {
+            if abilities.dexterity > abilities.strength {
+                Ability::Dexterity
+            } else {
+                Ability::Strength
+            }
+        } else {
+            match self.kind() {
+                WeaponKind::Melee => Ability::Strength,
+                WeaponKind::Ranged => Ability::Dexterity,
+            }
+        }
+    }
+
+    /// Returns the bonus added to attack rolls with this weapon.
+    ///
+    /// The proficiency bonus is only added if the wielder is `proficient` with the weapon.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn attack_bonus(
+        &self,
+        abilities: &Abilities,
+        proficiency_bonus: ProficiencyBonus,
+        proficient: bool,
+    ) -> i8 {
+        let modifier = abilities[self.attack_ability(abilities)].modifier().value();
+        if proficient {
+            modifier + proficiency_bonus.value() as i8
+        } else {
+            modifier
+        }
+    }
+
+    /// Returns the damage rolled on a hit, including the ability modifier.
+    ///
+    /// When held with [`Grip::TwoHanded`], a [`WeaponProperty::Versatile`] weapon uses its
+    /// [`Weapon::versatile_damage`] instead.
+    ///
+    /// Returns `None` for weapons that deal no damage, such as the [`Weapon::Net`].
+    #[must_use]
+    pub fn damage_roll(&self, abilities: &Abilities, grip: Grip) -> Option<Dice> {
+        let dice = match (grip, self.versatile_damage()) {
+            (Grip::TwoHanded, Some(versatile)) => versatile,
+            _ => self.damage()?,
+        };
+        let modifier = abilities[self.attack_ability(abilities)].modifier().value();
+        Some(dice.with_modifier(dice.modifier() + modifier))
+    }
+}
+
+impl Display for Weapon {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl AsRef<str> for Weapon {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl FromStr for Weapon {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Weapon::all()
+            .iter()
+            .find(|weapon| weapon.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or("Unknown weapon")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use crate::core::AbilityScore;
+    use alloc::string::ToString;
+
+    fn abilities(strength: u8, dexterity: u8) -> Abilities {
+        Abilities {
+            strength: AbilityScore::new(strength),
+            dexterity: AbilityScore::new(dexterity),
+            ..Abilities::new()
+        }
+    }
+
+    #[test]
+    fn category() {
+        let simple = Weapon::all()
+            .iter()
+            .filter(|weapon| weapon.category() == WeaponCategory::Simple)
+            .count();
+        assert_eq!(simple, 14);
+        assert_eq!(Weapon::Sling.category(), WeaponCategory::Simple);
+        assert_eq!(Weapon::Battleaxe.category(), WeaponCategory::Martial);
+    }
+
+    #[test]
+    fn ranged_weapons_have_range() {
+        for weapon in Weapon::all() {
+            let needs_range = weapon.has_property(WeaponProperty::Ammunition)
+                || weapon.has_property(WeaponProperty::Thrown);
+            assert_eq!(weapon.range().is_some(), needs_range, "{weapon}");
+        }
+    }
+
+    #[test]
+    fn versatile_weapons_have_versatile_damage() {
+        for weapon in Weapon::all() {
+            assert_eq!(
+                weapon.versatile_damage().is_some(),
+                weapon.has_property(WeaponProperty::Versatile),
+                "{weapon}"
+            );
+        }
+    }
+
+    #[test]
+    fn damage_and_type() {
+        for weapon in Weapon::all() {
+            assert_eq!(
+                weapon.damage().is_some(),
+                weapon.damage_type().is_some(),
+                "{weapon}"
+            );
+        }
+        assert_eq!(Weapon::Greatsword.damage(), Some(Dice::new(2, Die::D6)));
+        assert_eq!(Weapon::Blowgun.damage(), Some(Dice::flat(1)));
+        assert_eq!(Weapon::Net.damage(), None);
+        assert_eq!(Weapon::Maul.damage_type(), Some(DamageType::Bludgeoning));
+    }
+
+    #[test]
+    fn reach() {
+        assert_eq!(Weapon::Glaive.reach(), 10);
+        assert_eq!(Weapon::Longsword.reach(), 5);
+    }
+
+    #[test]
+    fn cost() {
+        assert_eq!(Weapon::Dart.cost().to_string(), "5 cp");
+        assert_eq!(Weapon::Club.cost().to_string(), "1 sp");
+        assert_eq!(Weapon::HandCrossbow.cost().to_string(), "75 gp");
+    }
+
+    #[test]
+    fn weight() {
+        assert_eq!(Weapon::Dart.weight(), Weight::from_hundredths(25));
+        assert_eq!(Weapon::Sling.weight(), Weight::ZERO);
+        assert_eq!(Weapon::HeavyCrossbow.weight(), Weight::from_pounds(18));
+    }
+
+    #[test]
+    fn mastery() {
+        assert_eq!(Weapon::Greataxe.mastery(), Some(WeaponMastery::Cleave));
+        assert_eq!(Weapon::Dagger.mastery(), Some(WeaponMastery::Nick));
+        assert_eq!(Weapon::Net.mastery(), None);
+    }
+
+    #[test]
+    fn attack_ability_finesse() {
+        assert_eq!(
+            Weapon::Dagger.attack_ability(&abilities(14, 16)),
+            Ability::Dexterity
+        );
+        assert_eq!(
+            Weapon::Dagger.attack_ability(&abilities(16, 14)),
+            Ability::Strength
+        );
+    }
+
+    #[test]
+    fn attack_ability_kind() {
+        let abilities = abilities(16, 14);
+        assert_eq!(
+            Weapon::Longbow.attack_ability(&abilities),
+            Ability::Dexterity
+        );
+        assert_eq!(
+            Weapon::Javelin.attack_ability(&abilities),
+            Ability::Strength
+        );
+    }
+
+    #[test]
+    fn attack_bonus() {
+        let abilities = abilities(18, 10);
+        let bonus = ProficiencyBonus::new(3);
+        assert_eq!(Weapon::Greataxe.attack_bonus(&abilities, bonus, true), 7);
+        assert_eq!(Weapon::Greataxe.attack_bonus(&abilities, bonus, false), 4);
+        assert_eq!(Weapon::Longbow.attack_bonus(&abilities, bonus, true), 3);
+    }
+
+    #[test]
+    fn damage_roll() {
+        let abilities = abilities(16, 8);
+        assert_eq!(
+            Weapon::Longsword.damage_roll(&abilities, Grip::OneHanded),
+            Some(Dice::new(1, Die::D8).with_modifier(3))
+        );
+        assert_eq!(
+            Weapon::Longsword.damage_roll(&abilities, Grip::TwoHanded),
+            Some(Dice::new(1, Die::D10).with_modifier(3))
+        );
+        assert_eq!(
+            Weapon::Greatsword.damage_roll(&abilities, Grip::TwoHanded),
+            Some(Dice::new(2, Die::D6).with_modifier(3))
+        );
+        assert_eq!(
+            Weapon::Blowgun.damage_roll(&abilities, Grip::OneHanded),
+            Some(Dice::flat(0))
+        );
+        assert_eq!(Weapon::Net.damage_roll(&abilities, Grip::OneHanded), None);
+    }
+
+    #[test]
+    fn parse() {
+        for weapon in Weapon::all() {
+            assert_eq!(weapon.to_string().parse(), Ok(*weapon));
+        }
+        assert_eq!("war pick".parse(), Ok(Weapon::WarPick));
+        assert!("Lightsaber".parse::<Weapon>().is_err());
+    }
+
+    #[test]
+    fn parse_mastery() {
+        for mastery in WeaponMastery::all() {
+            assert_eq!(mastery.to_string().parse(), Ok(*mastery));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let serialized = serde_json::to_string(&Weapon::HandCrossbow).unwrap();
+        assert_eq!(serialized, "\"HandCrossbow\"");
+
+        let deserialized: Weapon = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, Weapon::HandCrossbow);
+    }
+}
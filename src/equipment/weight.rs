@@ -0,0 +1,161 @@
+use core::{
+    fmt::Display,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul},
+};
+
+/// A weight, stored exactly in hundredths of a pound.
+///
+/// Hundredths are precise enough for every weight in the equipment tables, such as a dart
+/// (¼ lb.) or a single coin (50 to the pound), without using floating point numbers.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::equipment::Weight;
+///
+/// let darts = Weight::from_hundredths(25) * 20;
+/// assert_eq!(darts, Weight::from_pounds(5));
+/// assert_eq!(Weight::from_hundredths(250).to_string(), "2.5 lb.");
+/// ```
+///
+/// Weights can be summed, such as to find the total weight carried:
+///
+/// ```rust
+/// use dnd::equipment::{Weapon, Weight};
+///
+/// let total: Weight = [Weapon::Longsword, Weapon::Shortbow]
+///     .iter()
+///     .map(Weapon::weight)
+///     .sum();
+/// assert_eq!(total, Weight::from_pounds(5));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Weight(u32);
+
+impl Weight {
+    /// No weight at all.
+    pub const ZERO: Self = Self(0);
+
+    /// Creates a new `Weight` of the given number of whole pounds.
+    #[must_use]
+    pub const fn from_pounds(pounds: u32) -> Self {
+        Self(pounds.saturating_mul(100))
+    }
+
+    /// Creates a new `Weight` of the given number of hundredths of a pound.
+    #[must_use]
+    pub const fn from_hundredths(hundredths: u32) -> Self {
+        Self(hundredths)
+    }
+
+    /// Returns the weight in hundredths of a pound.
+    #[must_use]
+    pub const fn hundredths(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the weight in whole pounds, rounded down.
+    #[must_use]
+    pub const fn pounds(&self) -> u32 {
+        self.0 / 100
+    }
+}
+
+impl Add for Weight {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl AddAssign for Weight {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Mul<u32> for Weight {
+    type Output = Self;
+
+    fn mul(self, rhs: u32) -> Self::Output {
+        Self(self.0.saturating_mul(rhs))
+    }
+}
+
+impl Sum for Weight {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Weight> for Weight {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Display for Weight {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (pounds, hundredths) = (self.0 / 100, self.0 % 100);
+        if hundredths == 0 {
+            write!(f, "{pounds} lb.")
+        } else if hundredths % 10 == 0 {
+            write!(f, "{pounds}.{} lb.", hundredths / 10)
+        } else {
+            write!(f, "{pounds}.{hundredths:02} lb.")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn from_pounds() {
+        assert_eq!(Weight::from_pounds(3).hundredths(), 300);
+        assert_eq!(Weight::from_pounds(3).pounds(), 3);
+    }
+
+    #[test]
+    fn pounds_rounds_down() {
+        assert_eq!(Weight::from_hundredths(199).pounds(), 1);
+    }
+
+    #[test]
+    fn add() {
+        let mut weight = Weight::from_pounds(1) + Weight::from_hundredths(50);
+        weight += Weight::from_hundredths(50);
+        assert_eq!(weight, Weight::from_pounds(2));
+    }
+
+    #[test]
+    fn saturates() {
+        assert_eq!(
+            Weight::from_hundredths(u32::MAX) + Weight::from_pounds(1),
+            Weight::from_hundredths(u32::MAX)
+        );
+        assert_eq!(Weight::from_pounds(u32::MAX).hundredths(), u32::MAX);
+    }
+
+    #[test]
+    fn sum() {
+        let weights = [Weight::from_pounds(1), Weight::from_hundredths(25)];
+        assert_eq!(weights.iter().sum::<Weight>(), Weight::from_hundredths(125));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Weight::ZERO.to_string(), "0 lb.");
+        assert_eq!(Weight::from_pounds(18).to_string(), "18 lb.");
+        assert_eq!(Weight::from_hundredths(50).to_string(), "0.5 lb.");
+        assert_eq!(Weight::from_hundredths(25).to_string(), "0.25 lb.");
+        assert_eq!(Weight::from_hundredths(2).to_string(), "0.02 lb.");
+    }
+}
@@ -36,7 +36,26 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 /// A dependency-free[^1] foundation of `dnd`.
 ///
 /// [^1]: Optionally includes `serde` for serialization and deserialization features.
 pub mod core;
+
+/// Classes and other building blocks of player characters.
+pub mod character;
+
+/// Traits shared by all creatures, such as size and alignment.
+pub mod creature;
+
+/// Bonuses, penalties, and other effects from items, spells, features, and conditions.
+#[cfg(feature = "alloc")]
+pub mod effect;
+
+/// Weapons and other adventuring equipment.
+pub mod equipment;
+
+/// Monster stat blocks and the rules for challenging a party with them.
+pub mod monster;
@@ -0,0 +1,12 @@
+mod challenge_rating_calculator;
+pub use challenge_rating_calculator::{
+    ChallengeRatingCalculator, ChallengeRatingEstimate, MonsterTrait, Offense,
+};
+
+mod encounter;
+pub use encounter::{Difficulty, Encounter, EncounterBudget};
+
+#[cfg(feature = "alloc")]
+mod stat_block;
+#[cfg(feature = "alloc")]
+pub use stat_block::{ArmorClass, Feature, HitPoints, Inconsistency, LegendaryActions, StatBlock};
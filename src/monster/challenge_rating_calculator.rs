@@ -0,0 +1,542 @@
+use crate::core::{ChallengeRating, DamageType};
+#[cfg(feature = "alloc")]
+use crate::monster::StatBlock;
+use enumflags2::BitFlags;
+
+/// A special trait that makes a monster more dangerous than its statistics alone suggest.
+///
+/// Each trait adjusts the effective statistics used to estimate a challenge rating, as in the
+/// "Monster Features" table of the 2014 Dungeon Master's Guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MonsterTrait {
+    /// Increases effective damage per round by 2.
+    Aggressive,
+
+    /// Increases effective Armor Class by 1.
+    Avoidance,
+
+    /// Increases effective attack bonus by 4.
+    BloodFrenzy,
+
+    /// Doubles effective hit points, and adds a third of its hit points to damage per round.
+    DamageTransfer,
+
+    /// Increases effective hit points for each use per day, based on the expected challenge
+    /// rating.
+    LegendaryResistance(u8),
+
+    /// Increases effective Armor Class by 2.
+    MagicResistance,
+
+    /// Increases effective Armor Class and attack bonus by 4.
+    NimbleEscape,
+
+    /// Increases effective attack bonus by 1.
+    PackTactics,
+
+    /// Increases effective Armor Class by 1.
+    Parry,
+
+    /// Increases effective hit points by 3 times the hit points regained each turn.
+    Regeneration(u16),
+
+    /// Increases effective hit points based on the expected challenge rating.
+    Relentless,
+
+    /// Increases effective Armor Class by 2.
+    SuperiorInvisibility,
+
+    /// Increases effective hit points based on the expected challenge rating.
+    UndeadFortitude,
+}
+
+/// How a monster's attacks are resolved, which is compared to the expected value for a
+/// challenge rating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Offense {
+    /// The monster makes attack rolls with the given bonus.
+    AttackBonus(i8),
+
+    /// The monster forces saving throws against the given DC.
+    SaveDc(u8),
+}
+
+/// The result of estimating a challenge rating; see [`ChallengeRatingCalculator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChallengeRatingEstimate {
+    /// The challenge rating from effective hit points, adjusted by effective Armor Class.
+    pub defensive: ChallengeRating,
+
+    /// The challenge rating from effective damage per round, adjusted by effective attack
+    /// bonus or save DC.
+    pub offensive: ChallengeRating,
+
+    /// The average of the defensive and offensive challenge ratings.
+    pub challenge_rating: ChallengeRating,
+
+    /// The hit points after adjusting for resistances, immunities, and traits.
+    pub effective_hit_points: u32,
+
+    /// The Armor Class after adjusting for flying, saving throws, and traits.
+    pub effective_armor_class: u8,
+
+    /// The damage per round after adjusting for traits.
+    pub effective_damage_per_round: u32,
+
+    /// The attack bonus or save DC after adjusting for traits.
+    pub effective_offense: Offense,
+}
+
+/// The expected statistics of a monster, by challenge rating: the largest hit points, Armor
+/// Class, attack bonus, largest damage per round, and save DC.
+const STATISTICS: [(u32, u8, i8, u32, u8); 34] = [
+    (6, 13, 3, 1, 13),
+    (35, 13, 3, 3, 13),
+    (49, 13, 3, 5, 13),
+    (70, 13, 3, 8, 13),
+    (85, 13, 3, 14, 13),
+    (100, 13, 3, 20, 13),
+    (115, 13, 4, 26, 13),
+    (130, 14, 5, 32, 14),
+    (145, 15, 6, 38, 15),
+    (160, 15, 6, 44, 15),
+    (175, 15, 6, 50, 15),
+    (190, 16, 7, 56, 16),
+    (205, 16, 7, 62, 16),
+    (220, 17, 7, 68, 16),
+    (235, 17, 8, 74, 17),
+    (250, 17, 8, 80, 17),
+    (265, 18, 8, 86, 18),
+    (280, 18, 8, 92, 18),
+    (295, 18, 8, 98, 18),
+    (310, 18, 9, 104, 18),
+    (325, 19, 10, 110, 19),
+    (340, 19, 10, 116, 19),
+    (355, 19, 10, 122, 19),
+    (400, 19, 10, 140, 19),
+    (445, 19, 11, 158, 20),
+    (490, 19, 11, 176, 20),
+    (535, 19, 11, 194, 20),
+    (580, 19, 12, 212, 21),
+    (625, 19, 12, 230, 21),
+    (670, 19, 12, 248, 21),
+    (715, 19, 13, 266, 22),
+    (760, 19, 13, 284, 22),
+    (805, 19, 13, 302, 22),
+    (850, 19, 14, 320, 23),
+];
+
+/// Estimates a monster's challenge rating from its offensive and defensive statistics, as in
+/// "Creating a Monster" of the 2014 Dungeon Master's Guide.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::ChallengeRating;
+/// use dnd::monster::{ChallengeRatingCalculator, MonsterTrait, Offense};
+///
+/// let mut calculator = ChallengeRatingCalculator::new(59, 11, Offense::AttackBonus(6), 13);
+/// let estimate = calculator.calculate();
+///
+/// // 59 hit points is CR 1/2, and an Armor Class 2 below the expected 13 lowers it by one.
+/// assert_eq!(estimate.defensive, ChallengeRating::ONE_QUARTER);
+///
+/// // 13 damage per round is CR 1, and an attack bonus 3 above the expected +3 raises it by one.
+/// assert_eq!(estimate.offensive, ChallengeRating::new(2));
+///
+/// assert_eq!(estimate.challenge_rating, ChallengeRating::new(1));
+///
+/// // Magic Resistance makes the monster harder to hurt.
+/// calculator.traits = &[MonsterTrait::MagicResistance];
+/// assert_eq!(calculator.calculate().defensive, ChallengeRating::ONE_HALF);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChallengeRatingCalculator<'a> {
+    /// The monster's average hit points.
+    pub hit_points: u16,
+
+    /// The monster's Armor Class.
+    pub armor_class: u8,
+
+    /// The monster's best attack bonus or save DC.
+    pub offense: Offense,
+
+    /// The average damage the monster deals each round over the first three rounds of combat.
+    pub damage_per_round: u16,
+
+    /// The damage types the monster resists.
+    pub resistances: BitFlags<DamageType>,
+
+    /// The damage types the monster is immune to.
+    pub immunities: BitFlags<DamageType>,
+
+    /// Whether the monster can fly and deal damage at range.
+    pub flies_with_ranged_damage: bool,
+
+    /// The number of saving throws the monster is proficient in.
+    pub saving_throws: u8,
+
+    /// The monster's special traits.
+    pub traits: &'a [MonsterTrait],
+
+    /// The challenge rating the monster is meant to have, which scales some adjustments.
+    ///
+    /// If `None`, the challenge rating for the monster's unadjusted hit points is used.
+    pub expected: Option<ChallengeRating>,
+}
+
+impl ChallengeRatingCalculator<'_> {
+    /// Creates a new `ChallengeRatingCalculator` with no defenses or traits.
+    #[must_use]
+    pub const fn new(
+        hit_points: u16,
+        armor_class: u8,
+        offense: Offense,
+        damage_per_round: u16,
+    ) -> Self {
+        Self {
+            hit_points,
+            armor_class,
+            offense,
+            damage_per_round,
+            resistances: BitFlags::EMPTY,
+            immunities: BitFlags::EMPTY,
+            flies_with_ranged_damage: false,
+            saving_throws: 0,
+            traits: &[],
+            expected: None,
+        }
+    }
+
+    /// Creates a new `ChallengeRatingCalculator` from the defenses of `stat_block`.
+    ///
+    /// The hit points, Armor Class, resistances, immunities, and saving throws are taken from
+    /// the stat block. Flying, traits, and the expected challenge rating are left for the caller
+    /// to fill in.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_stat_block(
+        stat_block: &StatBlock,
+        offense: Offense,
+        damage_per_round: u16,
+    ) -> Self {
+        Self {
+            resistances: stat_block.resistances,
+            immunities: stat_block.immunities,
+            saving_throws: stat_block.saving_throws.len() as u8,
+            ..Self::new(
+                stat_block.hit_points.average,
+                stat_block.armor_class.value,
+                offense,
+                damage_per_round,
+            )
+        }
+    }
+
+    /// Returns the expected challenge rating, as a whole number.
+    fn expected(&self) -> u8 {
+        let expected = self.expected.unwrap_or_else(|| {
+            ChallengeRating::all()[row(u32::from(self.hit_points), |stats| stats.0)]
+        });
+        expected.eighths() / 8
+    }
+
+    /// Returns the effective hit points.
+    fn effective_hit_points(&self) -> u32 {
+        let expected = self.expected();
+        let covers = |types: BitFlags<DamageType>| {
+            types.len() >= 3
+                || types
+                    .contains(DamageType::Bludgeoning | DamageType::Piercing | DamageType::Slashing)
+        };
+        // Multipliers in quarters, for resistances and immunities.
+        let (resistance, immunity) = match expected {
+            0..=4 => (8, 8),
+            5..=10 => (6, 8),
+            11..=16 => (5, 6),
+            _ => (4, 5),
+        };
+        let multiplier = if covers(self.immunities) {
+            immunity
+        } else if covers(self.resistances | self.immunities) {
+            resistance
+        } else {
+            4
+        };
+        let mut hit_points = u32::from(self.hit_points) * multiplier / 4;
+
+        let fortitude = match expected {
+            0..=4 => 7,
+            5..=10 => 14,
+            11..=16 => 21,
+            _ => 28,
+        };
+        let legendary_resistance = match expected {
+            0..=4 => 10,
+            5..=10 => 20,
+            _ => 30,
+        };
+        for &monster_trait in self.traits {
+            match monster_trait {
+                MonsterTrait::DamageTransfer => hit_points *= 2,
+                MonsterTrait::LegendaryResistance(uses) => {
+                    hit_points += legendary_resistance * u32::from(uses);
+                }
+                MonsterTrait::Regeneration(regained) => hit_points += 3 * u32::from(regained),
+                MonsterTrait::Relentless | MonsterTrait::UndeadFortitude => {
+                    hit_points += fortitude;
+                }
+                _ => {}
+            }
+        }
+        hit_points
+    }
+
+    /// Returns the effective Armor Class.
+    fn effective_armor_class(&self) -> u8 {
+        let mut armor_class = self.armor_class;
+        if self.flies_with_ranged_damage && self.expected() <= 10 {
+            armor_class = armor_class.saturating_add(2);
+        }
+        armor_class = armor_class.saturating_add(match self.saving_throws {
+            0..=2 => 0,
+            3 | 4 => 2,
+            _ => 4,
+        });
+        for &monster_trait in self.traits {
+            armor_class = armor_class.saturating_add(match monster_trait {
+                MonsterTrait::Avoidance | MonsterTrait::Parry => 1,
+                MonsterTrait::MagicResistance | MonsterTrait::SuperiorInvisibility => 2,
+                MonsterTrait::NimbleEscape => 4,
+                _ => 0,
+            });
+        }
+        armor_class
+    }
+
+    /// Returns the effective damage per round.
+    fn effective_damage_per_round(&self) -> u32 {
+        let mut damage = u32::from(self.damage_per_round);
+        for &monster_trait in self.traits {
+            match monster_trait {
+                MonsterTrait::Aggressive => damage += 2,
+                MonsterTrait::DamageTransfer => damage += u32::from(self.hit_points) / 3,
+                _ => {}
+            }
+        }
+        damage
+    }
+
+    /// Returns the effective attack bonus or save DC.
+    fn effective_offense(&self) -> Offense {
+        let bonus: i8 = self
+            .traits
+            .iter()
+            .map(|monster_trait| match monster_trait {
+                MonsterTrait::BloodFrenzy | MonsterTrait::NimbleEscape => 4,
+                MonsterTrait::PackTactics => 1,
+                _ => 0,
+            })
+            .fold(0, i8::saturating_add);
+        match self.offense {
+            Offense::AttackBonus(attack) => Offense::AttackBonus(attack.saturating_add(bonus)),
+            Offense::SaveDc(dc) => Offense::SaveDc(dc),
+        }
+    }
+
+    /// Estimates the challenge rating.
+    ///
+    /// The defensive challenge rating is found from the effective hit points, then moved one
+    /// step for every 2 points the effective Armor Class differs from the expected Armor Class.
+    /// The offensive challenge rating is found the same way from the effective damage per round
+    /// and attack bonus or save DC. The final challenge rating is their average, rounded to the
+    /// nearest challenge rating, with ties rounding up.
+    #[must_use]
+    pub fn calculate(&self) -> ChallengeRatingEstimate {
+        let effective_hit_points = self.effective_hit_points();
+        let effective_armor_class = self.effective_armor_class();
+        let effective_damage_per_round = self.effective_damage_per_round();
+        let effective_offense = self.effective_offense();
+
+        let defensive = row(effective_hit_points, |stats| stats.0);
+        let defensive = adjust(
+            defensive,
+            i16::from(effective_armor_class) - i16::from(STATISTICS[defensive].1),
+        );
+
+        let offensive = row(effective_damage_per_round, |stats| stats.3);
+        let difference = match effective_offense {
+            Offense::AttackBonus(attack) => i16::from(attack) - i16::from(STATISTICS[offensive].2),
+            Offense::SaveDc(dc) => i16::from(dc) - i16::from(STATISTICS[offensive].4),
+        };
+        let offensive = adjust(offensive, difference);
+
+        let all = ChallengeRating::all();
+        let (defensive, offensive) = (all[defensive], all[offensive]);
+        let sum = i16::from(defensive.eighths()) + i16::from(offensive.eighths());
+        let challenge_rating = all
+            .iter()
+            .copied()
+            .min_by_key(|cr| {
+                (
+                    (i16::from(cr.eighths()) * 2 - sum).abs(),
+                    core::cmp::Reverse(*cr),
+                )
+            })
+            .unwrap_or_default();
+
+        ChallengeRatingEstimate {
+            defensive,
+            offensive,
+            challenge_rating,
+            effective_hit_points,
+            effective_armor_class,
+            effective_damage_per_round,
+            effective_offense,
+        }
+    }
+}
+
+/// Returns the first row of [`STATISTICS`] whose `column` is at least `value`.
+fn row(value: u32, column: impl Fn(&(u32, u8, i8, u32, u8)) -> u32) -> usize {
+    STATISTICS
+        .iter()
+        .position(|stats| value <= column(stats))
+        .unwrap_or(STATISTICS.len() - 1)
+}
+
+/// Moves `row` one step for every 2 points of `difference`, staying within the table.
+fn adjust(row: usize, difference: i16) -> usize {
+    let steps = difference / 2;
+    row.saturating_add_signed(isize::from(steps))
+        .min(STATISTICS.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_lookup() {
+        let estimate =
+            ChallengeRatingCalculator::new(100, 13, Offense::AttackBonus(3), 20).calculate();
+        assert_eq!(estimate.defensive, ChallengeRating::new(2));
+        assert_eq!(estimate.offensive, ChallengeRating::new(2));
+        assert_eq!(estimate.challenge_rating, ChallengeRating::new(2));
+    }
+
+    #[test]
+    fn save_dc() {
+        let estimate = ChallengeRatingCalculator::new(200, 16, Offense::SaveDc(18), 60).calculate();
+        assert_eq!(estimate.defensive, ChallengeRating::new(9));
+        assert_eq!(estimate.offensive, ChallengeRating::new(10));
+        assert_eq!(estimate.challenge_rating, ChallengeRating::new(10));
+    }
+
+    #[test]
+    fn resistances_and_immunities() {
+        let mut calculator = ChallengeRatingCalculator::new(60, 13, Offense::AttackBonus(3), 5);
+        calculator.resistances = DamageType::Fire.into();
+        assert_eq!(calculator.calculate().effective_hit_points, 60);
+
+        calculator.resistances =
+            DamageType::Bludgeoning | DamageType::Piercing | DamageType::Slashing;
+        assert_eq!(calculator.calculate().effective_hit_points, 120);
+
+        calculator.expected = Some(ChallengeRating::new(12));
+        assert_eq!(calculator.calculate().effective_hit_points, 75);
+        calculator.immunities = calculator.resistances;
+        assert_eq!(calculator.calculate().effective_hit_points, 90);
+    }
+
+    #[test]
+    fn flying_and_saving_throws() {
+        let mut calculator = ChallengeRatingCalculator::new(60, 13, Offense::AttackBonus(3), 5);
+        calculator.flies_with_ranged_damage = true;
+        calculator.saving_throws = 3;
+        assert_eq!(calculator.calculate().effective_armor_class, 17);
+
+        calculator.expected = Some(ChallengeRating::new(11));
+        calculator.saving_throws = 5;
+        assert_eq!(calculator.calculate().effective_armor_class, 17);
+    }
+
+    #[test]
+    fn traits() {
+        let mut calculator = ChallengeRatingCalculator::new(60, 13, Offense::AttackBonus(3), 5);
+        calculator.traits = &[
+            MonsterTrait::LegendaryResistance(3),
+            MonsterTrait::Regeneration(10),
+            MonsterTrait::UndeadFortitude,
+            MonsterTrait::PackTactics,
+            MonsterTrait::Aggressive,
+            MonsterTrait::Parry,
+        ];
+        let estimate = calculator.calculate();
+        assert_eq!(estimate.effective_hit_points, 60 + 30 + 30 + 7);
+        assert_eq!(estimate.effective_armor_class, 14);
+        assert_eq!(estimate.effective_damage_per_round, 7);
+        assert_eq!(estimate.effective_offense, Offense::AttackBonus(4));
+    }
+
+    #[test]
+    fn saturates() {
+        let mut calculator = ChallengeRatingCalculator::new(60, 254, Offense::AttackBonus(126), 5);
+        calculator.saving_throws = 5;
+        calculator.traits = &[MonsterTrait::NimbleEscape, MonsterTrait::PackTactics];
+        let estimate = calculator.calculate();
+        assert_eq!(estimate.effective_armor_class, u8::MAX);
+        assert_eq!(estimate.effective_offense, Offense::AttackBonus(i8::MAX));
+    }
+
+    #[test]
+    fn average_rounds_to_nearest() {
+        // CR 0 and CR 1 average to CR 1/2.
+        let estimate =
+            ChallengeRatingCalculator::new(5, 13, Offense::AttackBonus(3), 10).calculate();
+        assert_eq!(estimate.defensive, ChallengeRating::ZERO);
+        assert_eq!(estimate.offensive, ChallengeRating::new(1));
+        assert_eq!(estimate.challenge_rating, ChallengeRating::ONE_HALF);
+
+        // CR 1 and CR 2 tie between them, and round up.
+        let estimate =
+            ChallengeRatingCalculator::new(80, 13, Offense::AttackBonus(3), 20).calculate();
+        assert_eq!(estimate.challenge_rating, ChallengeRating::new(2));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn from_stat_block() {
+        let mut stat_block = StatBlock::new(
+            "Ogre",
+            crate::creature::Size::Large,
+            crate::creature::CreatureType::Giant,
+            ChallengeRating::new(2),
+        );
+        stat_block.hit_points.average = 59;
+        stat_block.armor_class.value = 11;
+        stat_block.immunities = DamageType::Poison.into();
+        let calculator =
+            ChallengeRatingCalculator::from_stat_block(&stat_block, Offense::AttackBonus(6), 13);
+        assert_eq!(calculator.hit_points, 59);
+        assert_eq!(calculator.immunities, DamageType::Poison);
+        assert_eq!(
+            calculator.calculate().challenge_rating,
+            ChallengeRating::new(1)
+        );
+    }
+
+    #[test]
+    fn beyond_the_table() {
+        let estimate =
+            ChallengeRatingCalculator::new(1_000, 25, Offense::AttackBonus(20), 500).calculate();
+        assert_eq!(estimate.challenge_rating, ChallengeRating::MAX);
+        let estimate =
+            ChallengeRatingCalculator::new(1, 5, Offense::AttackBonus(-2), 0).calculate();
+        assert_eq!(estimate.challenge_rating, ChallengeRating::ZERO);
+    }
+}
@@ -0,0 +1,398 @@
+use crate::core::{ChallengeRating, Level};
+use core::fmt::Display;
+
+/// How difficult an encounter is, by the 2014 Dungeon Master's Guide.
+///
+/// Ordered from least to most difficult.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Difficulty {
+    /// Below the easy threshold, and not worth tracking.
+    Trivial,
+
+    /// Doesn't tax the characters' resources or put them in serious peril.
+    Easy,
+
+    /// Could cost a few hit points, but the characters should emerge victorious.
+    Medium,
+
+    /// Could go badly, and weaker characters might be taken out of the fight.
+    Hard,
+
+    /// Could be lethal for one or more player characters.
+    Deadly,
+}
+
+impl Difficulty {
+    /// Returns the name of the difficulty, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Trivial => "Trivial",
+            Difficulty::Easy => "Easy",
+            Difficulty::Medium => "Medium",
+            Difficulty::Hard => "Hard",
+            Difficulty::Deadly => "Deadly",
+        }
+    }
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An XP budget for an encounter, by the 2024 Dungeon Master's Guide.
+///
+/// Ordered from least to most difficult.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum EncounterBudget {
+    /// Likely to cost the characters some resources, with little risk of death.
+    Low,
+
+    /// Could go badly without healing and other resources.
+    Moderate,
+
+    /// Could be lethal for one or more characters.
+    High,
+}
+
+impl EncounterBudget {
+    /// Returns the name of the budget, in title case.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            EncounterBudget::Low => "Low",
+            EncounterBudget::Moderate => "Moderate",
+            EncounterBudget::High => "High",
+        }
+    }
+}
+
+impl Display for EncounterBudget {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// XP thresholds by character level for easy, medium, hard, and deadly encounters (2014).
+const THRESHOLDS: [[u32; 4]; 20] = [
+    [25, 50, 75, 100],
+    [50, 100, 150, 200],
+    [75, 150, 225, 400],
+    [125, 250, 375, 500],
+    [250, 500, 750, 1_100],
+    [300, 600, 900, 1_400],
+    [350, 750, 1_100, 1_700],
+    [450, 900, 1_400, 2_100],
+    [550, 1_100, 1_600, 2_400],
+    [600, 1_200, 1_900, 2_800],
+    [800, 1_600, 2_400, 3_600],
+    [1_000, 2_000, 3_000, 4_500],
+    [1_100, 2_200, 3_400, 5_100],
+    [1_250, 2_500, 3_800, 5_700],
+    [1_400, 2_800, 4_300, 6_400],
+    [1_600, 3_200, 4_800, 7_200],
+    [2_000, 3_900, 5_900, 8_800],
+    [2_100, 4_200, 6_300, 9_500],
+    [2_400, 4_900, 7_300, 10_900],
+    [2_800, 5_700, 8_500, 12_700],
+];
+
+/// XP budgets by character level for low, moderate, and high encounters (2024).
+const BUDGETS: [[u32; 3]; 20] = [
+    [50, 75, 100],
+    [100, 150, 200],
+    [150, 225, 400],
+    [250, 375, 500],
+    [500, 750, 1_100],
+    [600, 1_000, 1_400],
+    [750, 1_300, 1_700],
+    [1_000, 1_700, 2_100],
+    [1_300, 2_000, 2_600],
+    [1_600, 2_300, 3_100],
+    [1_900, 2_900, 4_100],
+    [2_200, 3_700, 4_700],
+    [2_600, 4_200, 5_400],
+    [2_900, 4_900, 6_200],
+    [3_300, 5_400, 7_800],
+    [3_800, 6_100, 9_800],
+    [4_500, 7_200, 11_700],
+    [5_000, 8_700, 14_200],
+    [5_500, 10_700, 17_200],
+    [6_400, 13_200, 22_000],
+];
+
+/// Adjusted XP per character for an adventuring day, by character level (2014).
+const ADVENTURING_DAY: [u32; 20] = [
+    300, 600, 1_200, 1_700, 3_500, 4_000, 5_000, 6_000, 7_500, 9_000, 10_500, 11_500, 13_500,
+    15_000, 18_000, 20_000, 25_000, 27_000, 30_000, 40_000,
+];
+
+/// Encounter multipliers in halves, from ×0.5 to ×5 (2014).
+const MULTIPLIERS: [u32; 8] = [1, 2, 3, 4, 5, 6, 8, 10];
+
+/// A party of characters facing a group of monsters.
+///
+/// # Examples
+///
+/// Using the 2014 Dungeon Master's Guide, monster XP is multiplied by the number of monsters:
+///
+/// ```rust
+/// use dnd::core::{ChallengeRating, Level};
+/// use dnd::monster::{Difficulty, Encounter};
+///
+/// let party = [Level::new(3); 4];
+/// let monsters = [ChallengeRating::ONE_HALF; 4];
+/// let encounter = Encounter::new(&party, &monsters);
+///
+/// assert_eq!(encounter.xp(), 400);
+/// assert_eq!(encounter.adjusted_xp(), 800);
+/// assert_eq!(encounter.threshold(Difficulty::Hard), 900);
+/// assert_eq!(encounter.difficulty(), Difficulty::Medium);
+/// assert_eq!(encounter.daily_budget(), 4_800);
+/// ```
+///
+/// Using the 2024 Dungeon Master's Guide, monster XP is compared to a budget instead:
+///
+/// ```rust
+/// use dnd::core::{ChallengeRating, Level};
+/// use dnd::monster::{Encounter, EncounterBudget};
+///
+/// let party = [Level::new(3); 4];
+/// let monsters = [ChallengeRating::new(1); 4];
+/// let encounter = Encounter::new(&party, &monsters);
+///
+/// assert_eq!(encounter.xp(), 800);
+/// assert_eq!(encounter.budget(EncounterBudget::Moderate), 900);
+/// assert_eq!(encounter.fits_budget(), Some(EncounterBudget::Moderate));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Encounter<'a> {
+    /// The level of each character in the party.
+    pub party: &'a [Level],
+
+    /// The challenge rating of each monster.
+    pub monsters: &'a [ChallengeRating],
+}
+
+impl<'a> Encounter<'a> {
+    /// Creates a new `Encounter`.
+    #[must_use]
+    pub const fn new(party: &'a [Level], monsters: &'a [ChallengeRating]) -> Self {
+        Self { party, monsters }
+    }
+
+    /// Returns the total XP of the monsters, before any multiplier.
+    #[must_use]
+    pub fn xp(&self) -> u32 {
+        self.monsters.iter().map(ChallengeRating::xp).sum()
+    }
+
+    /// Returns the position of the multiplier in [`MULTIPLIERS`], adjusted for party size.
+    fn multiplier_index(&self) -> usize {
+        let index = match self.monsters.len() {
+            0 | 1 => 1,
+            2 => 2,
+            3..=6 => 3,
+            7..=10 => 4,
+            11..=14 => 5,
+            _ => 6,
+        };
+        match self.party.len() {
+            0..=2 => index + 1,
+            3..=5 => index,
+            _ => index - 1,
+        }
+    }
+
+    /// Returns the multiplier applied to monster XP for the number of monsters (2014).
+    ///
+    /// Parties of fewer than three characters use the next higher multiplier, and parties of
+    /// six or more use the next lower one.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn multiplier(&self) -> f32 {
+        MULTIPLIERS[self.multiplier_index()] as f32 / 2.0
+    }
+
+    /// Returns the monster XP after applying the [`multiplier`](Self::multiplier) (2014).
+    #[must_use]
+    pub fn adjusted_xp(&self) -> u32 {
+        self.xp()
+            .saturating_mul(MULTIPLIERS[self.multiplier_index()])
+            / 2
+    }
+
+    /// Returns the party's XP threshold for the given difficulty (2014).
+    ///
+    /// The threshold for [`Difficulty::Trivial`] is always 0.
+    #[must_use]
+    pub fn threshold(&self, difficulty: Difficulty) -> u32 {
+        let Some(column) = (difficulty as usize).checked_sub(1) else {
+            return 0;
+        };
+        self.party
+            .iter()
+            .map(|level| THRESHOLDS[usize::from(level.value()) - 1][column])
+            .sum()
+    }
+
+    /// Returns the difficulty of the encounter, comparing the adjusted XP to the party's
+    /// thresholds (2014).
+    #[must_use]
+    pub fn difficulty(&self) -> Difficulty {
+        let xp = self.adjusted_xp();
+        [
+            Difficulty::Deadly,
+            Difficulty::Hard,
+            Difficulty::Medium,
+            Difficulty::Easy,
+        ]
+        .into_iter()
+        .find(|&difficulty| xp >= self.threshold(difficulty))
+        .unwrap_or(Difficulty::Trivial)
+    }
+
+    /// Returns the party's XP budget for the given difficulty (2024).
+    #[must_use]
+    pub fn budget(&self, budget: EncounterBudget) -> u32 {
+        self.party
+            .iter()
+            .map(|level| BUDGETS[usize::from(level.value()) - 1][budget as usize])
+            .sum()
+    }
+
+    /// Returns the smallest budget that the monster XP fits within, or `None` if it exceeds
+    /// even the high budget (2024).
+    #[must_use]
+    pub fn fits_budget(&self) -> Option<EncounterBudget> {
+        let xp = self.xp();
+        [
+            EncounterBudget::Low,
+            EncounterBudget::Moderate,
+            EncounterBudget::High,
+        ]
+        .into_iter()
+        .find(|&budget| xp <= self.budget(budget))
+    }
+
+    /// Returns the adjusted XP the party can face in an adventuring day (2014).
+    #[must_use]
+    pub fn daily_budget(&self) -> u32 {
+        self.party
+            .iter()
+            .map(|level| ADVENTURING_DAY[usize::from(level.value()) - 1])
+            .sum()
+    }
+
+    /// Returns the fraction of the adventuring day's budget that the adjusted XP uses (2014).
+    ///
+    /// Returns 0 for an empty party.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn daily_budget_used(&self) -> f32 {
+        match self.daily_budget() {
+            0 => 0.0,
+            budget => self.adjusted_xp() as f32 / budget as f32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multiplier() {
+        let party = [Level::new(1); 4];
+        let expected = [
+            (1, 1.0),
+            (2, 1.5),
+            (3, 2.0),
+            (6, 2.0),
+            (7, 2.5),
+            (11, 3.0),
+            (15, 4.0),
+        ];
+        for (count, multiplier) in expected {
+            let monsters = [ChallengeRating::ZERO; 15];
+            let encounter = Encounter::new(&party, &monsters[..count]);
+            assert!(
+                (encounter.multiplier() - multiplier).abs() < f32::EPSILON,
+                "{count}"
+            );
+        }
+    }
+
+    #[test]
+    fn multiplier_party_size() {
+        let monsters = [ChallengeRating::new(1)];
+        let small = [Level::new(5); 2];
+        let large = [Level::new(5); 6];
+        assert!((Encounter::new(&small, &monsters).multiplier() - 1.5).abs() < f32::EPSILON);
+        assert!((Encounter::new(&large, &monsters).multiplier() - 0.5).abs() < f32::EPSILON);
+
+        let monsters = [ChallengeRating::new(1); 15];
+        assert!((Encounter::new(&small, &monsters).multiplier() - 5.0).abs() < f32::EPSILON);
+        assert_eq!(Encounter::new(&small, &monsters).adjusted_xp(), 15_000);
+    }
+
+    #[test]
+    fn difficulty() {
+        // The DMG example: a party of three 3rd-level and one 2nd-level characters.
+        let party = [Level::new(3), Level::new(3), Level::new(3), Level::new(2)];
+        assert_eq!(Encounter::new(&party, &[]).threshold(Difficulty::Hard), 825);
+
+        let cases = [
+            (&[][..], Difficulty::Trivial),
+            (&[ChallengeRating::ONE_QUARTER; 2][..], Difficulty::Trivial),
+            (&[ChallengeRating::new(1)][..], Difficulty::Trivial),
+            (&[ChallengeRating::new(2)][..], Difficulty::Easy),
+            (&[ChallengeRating::new(3)][..], Difficulty::Medium),
+            (&[ChallengeRating::new(2); 2][..], Difficulty::Hard),
+            (&[ChallengeRating::new(5)][..], Difficulty::Deadly),
+        ];
+        for (monsters, difficulty) in cases {
+            assert_eq!(
+                Encounter::new(&party, monsters).difficulty(),
+                difficulty,
+                "{monsters:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn fits_budget() {
+        let party = [Level::new(1); 4];
+        let cases = [
+            (&[ChallengeRating::new(1)][..], Some(EncounterBudget::Low)),
+            (
+                &[ChallengeRating::ONE_HALF; 3][..],
+                Some(EncounterBudget::Moderate),
+            ),
+            (
+                &[ChallengeRating::new(1); 2][..],
+                Some(EncounterBudget::High),
+            ),
+            (&[ChallengeRating::new(2)][..], None),
+        ];
+        for (monsters, budget) in cases {
+            assert_eq!(Encounter::new(&party, monsters).fits_budget(), budget);
+        }
+    }
+
+    #[test]
+    fn daily_budget_used() {
+        let party = [Level::new(1); 4];
+        let monsters = [ChallengeRating::new(1)];
+        let encounter = Encounter::new(&party, &monsters);
+        assert_eq!(encounter.daily_budget(), 1_200);
+        assert!((encounter.daily_budget_used() - 200.0 / 1_200.0).abs() < f32::EPSILON);
+        assert!(Encounter::new(&[], &monsters).daily_budget_used().abs() < f32::EPSILON);
+    }
+}
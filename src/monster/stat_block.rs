@@ -0,0 +1,637 @@
+use crate::{
+    core::{
+        Abilities, Ability, ChallengeRating, Condition, DamageType, Die, PassiveScores,
+        ProficiencyBonus, RollMode, Skill, SkillProficiencies,
+    },
+    creature::{Alignment, CreatureKind, Senses, Size, Speeds},
+};
+use alloc::{borrow::Cow, vec::Vec};
+use core::fmt::Display;
+use enumflags2::BitFlags;
+
+/// A monster's Armor Class, and what grants it, such as "15 (natural armor)".
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArmorClass {
+    /// The Armor Class.
+    pub value: u8,
+
+    /// What grants the Armor Class, such as "natural armor" or "leather armor, shield".
+    pub source: Option<Cow<'static, str>>,
+}
+
+impl ArmorClass {
+    /// Creates a new `ArmorClass` with no listed source.
+    #[must_use]
+    pub const fn new(value: u8) -> Self {
+        Self {
+            value,
+            source: None,
+        }
+    }
+
+    /// Returns a copy with the given source.
+    #[must_use]
+    pub fn with_source(self, source: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            source: Some(source.into()),
+            ..self
+        }
+    }
+}
+
+impl Display for ArmorClass {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.source {
+            Some(source) => write!(f, "{} ({source})", self.value),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
+
+/// A monster's hit points, listed as an average and the formula it is rolled from.
+///
+/// The modifier can be far larger than a [`Dice`](crate::core::Dice) allows, such as the
+/// `33d20 + 330` of a tarrasque.
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::Die;
+/// use dnd::monster::HitPoints;
+///
+/// let hit_points = HitPoints::from_formula(8, Die::D10, 16);
+/// assert_eq!(hit_points.average, 60);
+/// assert_eq!(hit_points.to_string(), "60 (8d10 + 16)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HitPoints {
+    /// The listed average hit points.
+    pub average: u16,
+
+    /// The number of hit dice.
+    pub count: u8,
+
+    /// The hit die, which usually depends on the monster's size.
+    pub die: Die,
+
+    /// The sum of the Constitution modifier for each hit die.
+    pub modifier: i16,
+}
+
+impl HitPoints {
+    /// Creates a new `HitPoints` whose average is computed from the formula.
+    #[must_use]
+    pub fn from_formula(count: u8, die: Die, modifier: i16) -> Self {
+        let mut hit_points = Self {
+            average: 0,
+            count,
+            die,
+            modifier,
+        };
+        hit_points.average = hit_points.formula_average();
+        hit_points
+    }
+
+    /// Returns the average of the formula, rounded down and never less than 1.
+    #[must_use]
+    pub fn formula_average(&self) -> u16 {
+        let dice = i32::from(self.count) * (i32::from(self.die.sides()) + 1) / 2;
+        u16::try_from((dice + i32::from(self.modifier)).max(1)).unwrap_or(u16::MAX)
+    }
+}
+
+impl Display for HitPoints {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} ({}{}", self.average, self.count, self.die)?;
+        match self.modifier {
+            0 => write!(f, ")"),
+            modifier if modifier < 0 => write!(f, " - {})", -modifier),
+            modifier => write!(f, " + {modifier})"),
+        }
+    }
+}
+
+/// A named trait, action, or reaction in a stat block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Feature {
+    /// The name of the feature, such as "Multiattack".
+    pub name: Cow<'static, str>,
+
+    /// The rules text of the feature.
+    pub description: Cow<'static, str>,
+}
+
+impl Feature {
+    /// Creates a new `Feature`.
+    #[must_use]
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        description: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+        }
+    }
+}
+
+/// The legendary actions a monster can take at the end of other creatures' turns.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LegendaryActions {
+    /// How many legendary actions can be taken each round.
+    pub per_round: u8,
+
+    /// The legendary action options.
+    pub actions: Vec<Feature>,
+}
+
+/// A difference between a value listed in a [`StatBlock`] and the value the rules derive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Inconsistency {
+    /// A listed skill bonus is not the ability modifier plus any proficiency.
+    SkillBonus {
+        /// The skill.
+        skill: Skill,
+        /// The bonus listed in the stat block.
+        listed: i8,
+        /// The bonus derived from abilities and proficiencies.
+        expected: i8,
+    },
+
+    /// A listed saving throw bonus is not the ability modifier plus any proficiency.
+    SavingThrow {
+        /// The ability of the saving throw.
+        ability: Ability,
+        /// The bonus listed in the stat block.
+        listed: i8,
+        /// The bonus derived from abilities and proficiencies.
+        expected: i8,
+    },
+
+    /// The listed average hit points are not the average of the hit point formula.
+    HitPointAverage {
+        /// The average listed in the stat block.
+        listed: u16,
+        /// The average of the formula.
+        expected: u16,
+    },
+
+    /// The hit point modifier is not the Constitution modifier times the number of hit dice.
+    HitPointModifier {
+        /// The modifier listed in the stat block.
+        listed: i16,
+        /// The modifier derived from Constitution.
+        expected: i16,
+    },
+
+    /// The listed hit die does not match the monster's size.
+    HitDie {
+        /// The hit die listed in the stat block.
+        listed: Die,
+        /// The hit die for the monster's size.
+        expected: Die,
+    },
+
+    /// The listed passive Perception is not 10 plus the Perception bonus.
+    PassivePerception {
+        /// The passive Perception listed in the stat block.
+        listed: u8,
+        /// The passive Perception derived from the Perception bonus.
+        expected: u8,
+    },
+}
+
+impl Display for Inconsistency {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Inconsistency::SkillBonus {
+                skill,
+                listed,
+                expected,
+            } => write!(f, "{skill} is listed as {listed:+}, expected {expected:+}"),
+            Inconsistency::SavingThrow {
+                ability,
+                listed,
+                expected,
+            } => write!(
+                f,
+                "{ability} saving throw is listed as {listed:+}, expected {expected:+}"
+            ),
+            Inconsistency::HitPointAverage { listed, expected } => {
+                write!(f, "Hit points are listed as {listed}, expected {expected}")
+            }
+            Inconsistency::HitPointModifier { listed, expected } => write!(
+                f,
+                "Hit point modifier is listed as {listed:+}, expected {expected:+}"
+            ),
+            Inconsistency::HitDie { listed, expected } => {
+                write!(f, "Hit die is listed as {listed}, expected {expected}")
+            }
+            Inconsistency::PassivePerception { listed, expected } => write!(
+                f,
+                "Passive Perception is listed as {listed}, expected {expected}"
+            ),
+        }
+    }
+}
+
+/// The game statistics of a monster.
+///
+/// Values such as skill bonuses are derived from [`Abilities`], proficiencies, and the
+/// [`ChallengeRating`]. Values copied from a published stat block can also be recorded as
+/// listed, and then checked with [`StatBlock::validate`].
+///
+/// # Examples
+///
+/// ```rust
+/// use dnd::core::{Abilities, AbilityScore, ChallengeRating, Die, Skill};
+/// use dnd::creature::{Alignment, CreatureKind, CreatureType, Senses, Size, Speeds};
+/// use dnd::monster::{ArmorClass, HitPoints, StatBlock};
+///
+/// let humanoid = CreatureKind::new(CreatureType::Humanoid).with_tag("Goblinoid");
+/// let mut goblin = StatBlock::new("Goblin", Size::Small, humanoid, ChallengeRating::ONE_QUARTER);
+/// goblin.alignment = Alignment::NeutralEvil;
+/// goblin.armor_class = ArmorClass::new(15).with_source("leather armor, shield");
+/// goblin.hit_points = HitPoints::from_formula(2, Die::D6, 0);
+/// goblin.speeds = Speeds::walking(30);
+/// goblin.abilities = Abilities {
+///     strength: AbilityScore::new(8),
+///     dexterity: AbilityScore::new(14),
+///     constitution: AbilityScore::new(10),
+///     intelligence: AbilityScore::new(10),
+///     wisdom: AbilityScore::new(8),
+///     charisma: AbilityScore::new(8),
+/// };
+/// goblin.skills.set_expertise(Skill::Stealth);
+/// goblin.senses.darkvision = 60;
+///
+/// assert_eq!(goblin.skill_bonus(Skill::Stealth), 6);
+/// assert_eq!(goblin.passive_perception(), 9);
+///
+/// // A listed value that doesn't follow from the statistics is flagged.
+/// goblin.listed_skills.push((Skill::Stealth, 4));
+/// assert_eq!(goblin.validate().len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatBlock {
+    /// The name of the monster.
+    pub name: Cow<'static, str>,
+
+    /// The size of the monster.
+    pub size: Size,
+
+    /// The creature type of the monster, along with any tags.
+    pub kind: CreatureKind,
+
+    /// The alignment of the monster.
+    pub alignment: Alignment,
+
+    /// The Armor Class of the monster.
+    pub armor_class: ArmorClass,
+
+    /// The hit points of the monster.
+    pub hit_points: HitPoints,
+
+    /// The speeds of the monster.
+    pub speeds: Speeds,
+
+    /// The ability scores of the monster.
+    pub abilities: Abilities,
+
+    /// The saving throws the monster is proficient in.
+    pub saving_throws: BitFlags<Ability>,
+
+    /// The skills the monster is proficient or has expertise in.
+    pub skills: SkillProficiencies,
+
+    /// Damage types the monster takes double damage from.
+    pub vulnerabilities: BitFlags<DamageType>,
+
+    /// Damage types the monster takes half damage from.
+    pub resistances: BitFlags<DamageType>,
+
+    /// Damage types the monster takes no damage from.
+    pub immunities: BitFlags<DamageType>,
+
+    /// Conditions that cannot affect the monster.
+    pub condition_immunities: BitFlags<Condition>,
+
+    /// The special senses of the monster.
+    pub senses: Senses,
+
+    /// The languages the monster speaks or understands.
+    pub languages: Vec<Cow<'static, str>>,
+
+    /// The challenge rating of the monster.
+    pub challenge_rating: ChallengeRating,
+
+    /// Special traits, such as "Magic Resistance".
+    pub traits: Vec<Feature>,
+
+    /// Actions, such as "Multiattack".
+    pub actions: Vec<Feature>,
+
+    /// Bonus actions.
+    pub bonus_actions: Vec<Feature>,
+
+    /// Reactions, such as "Parry".
+    pub reactions: Vec<Feature>,
+
+    /// Legendary actions, if the monster is legendary.
+    pub legendary_actions: Option<LegendaryActions>,
+
+    /// Lair actions the monster can take on initiative count 20 while in its lair.
+    pub lair_actions: Vec<Feature>,
+
+    /// Skill bonuses as listed in a published stat block, checked by [`StatBlock::validate`].
+    pub listed_skills: Vec<(Skill, i8)>,
+
+    /// Saving throw bonuses as listed in a published stat block, checked by
+    /// [`StatBlock::validate`].
+    pub listed_saving_throws: Vec<(Ability, i8)>,
+
+    /// Passive Perception as listed in a published stat block, checked by
+    /// [`StatBlock::validate`].
+    pub listed_passive_perception: Option<u8>,
+}
+
+impl StatBlock {
+    /// Creates a new `StatBlock` with the given name, size, creature type, and challenge rating.
+    ///
+    /// The monster starts unaligned, with an Armor Class of 10, a single hit die for its size,
+    /// a walking speed of 30 feet, average ability scores, and nothing else.
+    #[must_use]
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        size: Size,
+        kind: impl Into<CreatureKind>,
+        challenge_rating: ChallengeRating,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            size,
+            kind: kind.into(),
+            alignment: Alignment::Unaligned,
+            armor_class: ArmorClass::new(10),
+            hit_points: HitPoints::from_formula(1, size.hit_die(), 0),
+            speeds: Speeds::walking(30),
+            abilities: Abilities::new(),
+            saving_throws: BitFlags::EMPTY,
+            skills: SkillProficiencies::new(),
+            vulnerabilities: BitFlags::EMPTY,
+            resistances: BitFlags::EMPTY,
+            immunities: BitFlags::EMPTY,
+            condition_immunities: BitFlags::EMPTY,
+            senses: Senses::default(),
+            languages: Vec::new(),
+            challenge_rating,
+            traits: Vec::new(),
+            actions: Vec::new(),
+            bonus_actions: Vec::new(),
+            reactions: Vec::new(),
+            legendary_actions: None,
+            lair_actions: Vec::new(),
+            listed_skills: Vec::new(),
+            listed_saving_throws: Vec::new(),
+            listed_passive_perception: None,
+        }
+    }
+
+    /// Returns the proficiency bonus, which is determined by the challenge rating.
+    #[must_use]
+    pub const fn proficiency_bonus(&self) -> ProficiencyBonus {
+        self.challenge_rating.proficiency_bonus()
+    }
+
+    /// Returns the bonus added to ability checks with the given skill.
+    #[must_use]
+    pub fn skill_bonus(&self, skill: Skill) -> i8 {
+        self.skills
+            .bonus(skill, &self.abilities, self.proficiency_bonus())
+    }
+
+    /// Returns the bonus added to saving throws with the given ability.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn saving_throw_bonus(&self, ability: Ability) -> i8 {
+        let modifier = self.abilities[ability].modifier().value();
+        if self.saving_throws.contains(ability) {
+            modifier + self.proficiency_bonus().value() as i8
+        } else {
+            modifier
+        }
+    }
+
+    /// Returns the passive Wisdom (Perception) score.
+    #[must_use]
+    pub fn passive_perception(&self) -> u8 {
+        PassiveScores::score(
+            Skill::Perception,
+            &self.skills,
+            &self.abilities,
+            self.proficiency_bonus(),
+            RollMode::Normal,
+        )
+    }
+
+    /// Returns the experience points awarded for defeating the monster.
+    #[must_use]
+    pub const fn xp(&self) -> u32 {
+        self.challenge_rating.xp()
+    }
+
+    /// Returns every listed or derived value that is inconsistent with the rules.
+    ///
+    /// The following are checked:
+    ///
+    /// - Each listed skill bonus and saving throw bonus.
+    /// - The listed passive Perception.
+    /// - The hit point average, the Constitution modifier in the hit point formula, and the hit
+    ///   die for the monster's size.
+    #[must_use]
+    pub fn validate(&self) -> Vec<Inconsistency> {
+        let mut inconsistencies = Vec::new();
+        for &(skill, listed) in &self.listed_skills {
+            let expected = self.skill_bonus(skill);
+            if listed != expected {
+                inconsistencies.push(Inconsistency::SkillBonus {
+                    skill,
+                    listed,
+                    expected,
+                });
+            }
+        }
+        for &(ability, listed) in &self.listed_saving_throws {
+            let expected = self.saving_throw_bonus(ability);
+            if listed != expected {
+                inconsistencies.push(Inconsistency::SavingThrow {
+                    ability,
+                    listed,
+                    expected,
+                });
+            }
+        }
+        if let Some(listed) = self.listed_passive_perception {
+            let expected = self.passive_perception();
+            if listed != expected {
+                inconsistencies.push(Inconsistency::PassivePerception { listed, expected });
+            }
+        }
+
+        let hit_points = &self.hit_points;
+        let expected = self.size.hit_die();
+        if hit_points.die != expected {
+            inconsistencies.push(Inconsistency::HitDie {
+                listed: hit_points.die,
+                expected,
+            });
+        }
+        let expected =
+            i16::from(self.abilities.constitution.modifier().value()) * i16::from(hit_points.count);
+        if hit_points.modifier != expected {
+            inconsistencies.push(Inconsistency::HitPointModifier {
+                listed: hit_points.modifier,
+                expected,
+            });
+        }
+        let expected = hit_points.formula_average();
+        if hit_points.average != expected {
+            inconsistencies.push(Inconsistency::HitPointAverage {
+                listed: hit_points.average,
+                expected,
+            });
+        }
+        inconsistencies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{core::AbilityScore, creature::CreatureType};
+    use alloc::string::ToString;
+
+    fn ogre() -> StatBlock {
+        let mut ogre = StatBlock::new(
+            "Ogre",
+            Size::Large,
+            CreatureType::Giant,
+            ChallengeRating::new(2),
+        );
+        ogre.armor_class = ArmorClass::new(11).with_source("hide armor");
+        ogre.hit_points = HitPoints::from_formula(7, Die::D10, 21);
+        ogre.speeds = Speeds::walking(40);
+        ogre.abilities = Abilities {
+            strength: AbilityScore::new(19),
+            dexterity: AbilityScore::new(8),
+            constitution: AbilityScore::new(16),
+            intelligence: AbilityScore::new(5),
+            wisdom: AbilityScore::new(7),
+            charisma: AbilityScore::new(7),
+        };
+        ogre.senses.darkvision = 60;
+        ogre.languages = alloc::vec!["Common".into(), "Giant".into()];
+        ogre.actions.push(Feature::new(
+            "Greatclub",
+            "Melee Weapon Attack: +6 to hit, reach 5 ft., one target.",
+        ));
+        ogre.listed_passive_perception = Some(8);
+        ogre
+    }
+
+    #[test]
+    fn ogre_is_consistent() {
+        let ogre = ogre();
+        assert_eq!(ogre.hit_points.average, 59);
+        assert_eq!(ogre.xp(), 450);
+        assert_eq!(ogre.validate(), []);
+    }
+
+    #[test]
+    fn saving_throw_bonus() {
+        let mut ogre = ogre();
+        ogre.saving_throws = Ability::Strength | Ability::Constitution;
+        assert_eq!(ogre.saving_throw_bonus(Ability::Strength), 6);
+        assert_eq!(ogre.saving_throw_bonus(Ability::Dexterity), -1);
+
+        ogre.listed_saving_throws.push((Ability::Constitution, 4));
+        assert_eq!(
+            ogre.validate(),
+            [Inconsistency::SavingThrow {
+                ability: Ability::Constitution,
+                listed: 4,
+                expected: 5,
+            }]
+        );
+    }
+
+    #[test]
+    fn hit_point_inconsistencies() {
+        let mut ogre = ogre();
+        ogre.hit_points = HitPoints {
+            average: 60,
+            count: 7,
+            die: Die::D8,
+            modifier: 14,
+        };
+        assert_eq!(
+            ogre.validate(),
+            [
+                Inconsistency::HitDie {
+                    listed: Die::D8,
+                    expected: Die::D10,
+                },
+                Inconsistency::HitPointModifier {
+                    listed: 14,
+                    expected: 21,
+                },
+                Inconsistency::HitPointAverage {
+                    listed: 60,
+                    expected: 45,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn passive_perception_inconsistency() {
+        let mut ogre = ogre();
+        ogre.listed_passive_perception = Some(10);
+        assert_eq!(
+            ogre.validate()[0].to_string(),
+            "Passive Perception is listed as 10, expected 8"
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ArmorClass::new(11).with_source("hide armor").to_string(),
+            "11 (hide armor)"
+        );
+        assert_eq!(ArmorClass::new(11).to_string(), "11");
+        assert_eq!(
+            HitPoints::from_formula(2, Die::D6, 0).to_string(),
+            "7 (2d6)"
+        );
+        assert_eq!(
+            HitPoints::from_formula(1, Die::D4, -1).to_string(),
+            "1 (1d4 - 1)"
+        );
+        assert_eq!(
+            Inconsistency::SkillBonus {
+                skill: Skill::Stealth,
+                listed: 4,
+                expected: 6,
+            }
+            .to_string(),
+            "Stealth is listed as +4, expected +6"
+        );
+    }
+}